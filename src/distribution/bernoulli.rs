@@ -1,5 +1,6 @@
 use rand::Rng;
 use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
 use result::Result;
 use super::*;
 
@@ -76,6 +77,37 @@ impl Bernoulli {
     pub fn n(&self) -> i64 {
         1
     }
+
+    /// Fits a bernoulli distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty or any element is not `0` or `1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p = Σ x_i / n
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    ///
+    /// let (n, _) = Bernoulli::fit(&[0.0, 1.0, 1.0, 1.0]).unwrap();
+    /// assert_eq!(n.p(), 0.75);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Bernoulli, f64)> {
+        if data.iter().any(|&x| x != 0.0 && x != 1.0) {
+            return Err(StatsError::BadParams);
+        }
+        Binomial::fit(1, data).map(|(b, ln_likelihood)| (Bernoulli { b: b }, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Bernoulli {
@@ -141,6 +173,48 @@ impl Univariate<i64, f64> for Bernoulli {
         self.b.cdf(x)
     }
 
+    /// Calculates the survival function for the bernoulli
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `1.0` if `x < 0.0` and `0.0` if `x >= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 { 1 }
+    /// else if x >= 1 { 0 }
+    /// else { p }
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        self.b.sf(x)
+    }
+
+    /// Calculates the natural logarithm of the cumulative distribution
+    /// function for the bernoulli distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(cdf(x))
+    /// ```
+    fn ln_cdf(&self, x: f64) -> f64 {
+        self.b.ln_cdf(x)
+    }
+
+    /// Calculates the natural logarithm of the survival function for
+    /// the bernoulli distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sf(x))
+    /// ```
+    fn ln_sf(&self, x: f64) -> f64 {
+        self.b.ln_sf(x)
+    }
+
     /// Returns the minimum value in the domain of the
     /// bernoulli distribution representable by a 64-
     /// bit integer
@@ -168,6 +242,28 @@ impl Univariate<i64, f64> for Bernoulli {
     }
 }
 
+impl InverseCdf<i64, f64> for Bernoulli {
+    /// Calculates the inverse cumulative distribution function for the
+    /// bernoulli distribution at `p` i.e. the smallest `x` such that
+    /// `cdf(x) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if p <= 1 - q { 0 }
+    /// else { 1 }
+    /// ```
+    ///
+    /// where `q` is the probability of success
+    fn inverse_cdf(&self, p: f64) -> i64 {
+        self.b.inverse_cdf(p)
+    }
+}
+
 impl Mean<f64, f64> for Bernoulli {
     /// Returns the mean of the bernoulli
     /// distribution
@@ -4,6 +4,8 @@ use rand::distributions::{Sample, IndependentSample};
 use error::StatsError;
 use function::{beta, gamma};
 use result::Result;
+use statistics::Statistics;
+use prec;
 use super::*;
 
 /// Implements the [Beta](https://en.wikipedia.org/wiki/Beta_distribution) distribution
@@ -82,6 +84,80 @@ impl Beta {
     pub fn shape_b(&self) -> f64 {
         self.shape_b
     }
+
+    /// Fits a beta distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two elements, any element
+    /// is not in the open interval `(0, 1)` or all of its elements are equal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ψ(α) - ψ(α + β) = Σ ln(x_i) / n
+    /// ψ(β) - ψ(α + β) = Σ ln(1 - x_i) / n
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, `ψ` is the digamma function and
+    /// `n` is the number of samples. The system is solved using Newton's
+    /// method starting from the method of moments estimates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Beta;
+    ///
+    /// let (n, _) = Beta::fit(&[0.12, 0.45, 0.33, 0.71, 0.25]).unwrap();
+    /// assert!(n.shape_a() > 0.0);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Beta, f64)> {
+        if data.len() < 2 || data.iter().any(|&x| x <= 0.0 || x >= 1.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let n = data.len() as f64;
+        let g1 = data.iter().fold(0.0, |acc, x| acc + x.ln()) / n;
+        let g2 = data.iter().fold(0.0, |acc, x| acc + (-x).ln_1p()) / n;
+        let mean = data.mean();
+        let var = data.population_variance();
+        if var <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+
+        let common = mean * (1.0 - mean) / var - 1.0;
+        let (mut a, mut b) = if common > 0.0 {
+            (mean * common, (1.0 - mean) * common)
+        } else {
+            (1.0, 1.0)
+        };
+        for _ in 0..internal::MAX_FIT_ITERATIONS {
+            let dab = gamma::digamma(a + b);
+            let tab = gamma::trigamma(a + b);
+            let f1 = gamma::digamma(a) - dab - g1;
+            let f2 = gamma::digamma(b) - dab - g2;
+            let j11 = gamma::trigamma(a) - tab;
+            let j22 = gamma::trigamma(b) - tab;
+            let j12 = -tab;
+            let det = j11 * j22 - j12 * j12;
+            let da = (j22 * f1 - j12 * f2) / det;
+            let db = (j11 * f2 - j12 * f1) / det;
+            let next_a = if a - da <= 0.0 { a / 2.0 } else { a - da };
+            let next_b = if b - db <= 0.0 { b / 2.0 } else { b - db };
+            let done = (next_a - a).abs() <= prec::F64_PREC * next_a &&
+                       (next_b - b).abs() <= prec::F64_PREC * next_b;
+            a = next_a;
+            b = next_b;
+            if done {
+                break;
+            }
+        }
+
+        let dist = Beta::new(a, b)?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Beta {
@@ -170,6 +246,42 @@ impl Univariate<f64, f64> for Beta {
         }
     }
 
+    /// Calculates the survival function for the beta
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(1 - x)(β, α)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I_x` is the regularized
+    /// lower incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0 && x <= 1.0, "{}", StatsError::ArgIntervalIncl("x", 0.0, 1.0));
+        if x == 1.0 {
+            0.0
+        } else if self.shape_a == f64::INFINITY && self.shape_b == f64::INFINITY {
+            if x < 0.5 {
+                1.0
+            } else {
+                0.0
+            }
+        } else if self.shape_a == f64::INFINITY {
+            1.0
+        } else if self.shape_b == f64::INFINITY {
+            0.0
+        } else if self.shape_a == 1.0 && self.shape_b == 1.0 {
+            1.0 - x
+        } else {
+            beta::beta_reg(self.shape_b, self.shape_a, 1.0 - x)
+        }
+    }
+
     /// Returns the minimum value in the domain of the
     /// beta distribution representable by a double precision
     /// float
@@ -197,6 +309,43 @@ impl Univariate<f64, f64> for Beta {
     }
 }
 
+impl InverseCdf<f64, f64> for Beta {
+    /// Calculates the inverse cumulative distribution function for the
+    /// beta distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.5` if both `shape_a` and `shape_b` are `f64::INFINITY`,
+    /// `1.0` if only `shape_a` is `f64::INFINITY`, and `0.0` if only
+    /// `shape_b` is `f64::INFINITY`. Otherwise there is no closed form
+    /// solution so the cumulative distribution function is inverted
+    /// numerically using a bracketed root finder
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        if self.shape_a == f64::INFINITY && self.shape_b == f64::INFINITY {
+            0.5
+        } else if self.shape_a == f64::INFINITY {
+            1.0
+        } else if self.shape_b == f64::INFINITY {
+            0.0
+        } else if self.shape_a == 1.0 && self.shape_b == 1.0 {
+            p
+        } else {
+            internal::inverse_cdf(p,
+                                  0.0,
+                                  1.0,
+                                  self.shape_a / (self.shape_a + self.shape_b),
+                                  |x| beta::beta_reg(self.shape_a, self.shape_b, x))
+        }
+    }
+}
+
 impl Mean<f64, f64> for Beta {
     /// Returns the mean of the beta distribution
     ///
@@ -704,4 +853,67 @@ mod test {
     fn test_cdf_input_gt_zero() {
         get_value(1.0, 1.0, |x| x.cdf(2.0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(1.0, 1.0, 0.25, |x| x.inverse_cdf(0.25));
+        test_case(1.0, 1.0, 1.0, |x| x.inverse_cdf(1.0));
+        test_almost(2.0, 5.0, 0.0082554927877466775991, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(2.0, 5.0, 0.092595258913128752116, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(2.0, 5.0, 0.26444998329565996232, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(2.0, 5.0, 0.51031630655149166906, 1e-15, |x| x.inverse_cdf(0.9));
+        test_almost(2.0, 5.0, 0.81861386691913393942, 1e-14, |x| x.inverse_cdf(0.999));
+        test_almost(0.5, 0.5, 2.4673990709169441803e-6, 1e-18, |x| x.inverse_cdf(0.001));
+        test_almost(0.5, 0.5, 0.5, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(0.5, 0.5, 0.99999753260092908305, 1e-14, |x| x.inverse_cdf(0.999));
+        test_almost(5.0, 1.0, 0.25118864315095801215, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(5.0, 1.0, 0.87055056329612413914, 1e-14, |x| x.inverse_cdf(0.5));
+        test_case(5.0, 100.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(5.0, 100.0, 1.0, |x| x.inverse_cdf(1.0));
+        test_case(1.0, f64::INFINITY, 0.0, |x| x.inverse_cdf(0.5));
+        test_case(f64::INFINITY, 1.0, 1.0, |x| x.inverse_cdf(0.5));
+        test_case(f64::INFINITY, f64::INFINITY, 0.5, |x| x.inverse_cdf(0.5));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(2.0, 3.0, 0.3125, 1e-15, |x| x.sf(0.5));
+        test_almost(2.0, 3.0, 3.997e-9, 1e-21, |x| x.sf(0.999));
+        test_case(1.0, 1.0, 0.75, |x| x.sf(0.25));
+        test_case(2.0, 3.0, 0.0, |x| x.sf(1.0));
+        test_case(f64::INFINITY, 1.0, 1.0, |x| x.sf(0.5));
+        test_case(1.0, f64::INFINITY, 0.0, |x| x.sf(0.5));
+        test_case(f64::INFINITY, f64::INFINITY, 1.0, |x| x.sf(0.25));
+        test_case(f64::INFINITY, f64::INFINITY, 0.0, |x| x.sf(0.75));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(2.0, 3.0, 0.3125f64.ln(), 1e-15, |x| x.ln_sf(0.5));
+        test_almost(2.0, 3.0, 3.997e-9f64.ln(), 1e-10, |x| x.ln_sf(0.999));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sf_input_gt_1() {
+        get_value(1.0, 1.0, |x| x.sf(1.5));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Beta::fit(&[0.12, 0.45, 0.33, 0.71, 0.25, 0.58, 0.39, 0.82, 0.15, 0.47]).unwrap();
+        assert_almost_eq!(n.shape_a(), 1.9450315257865331293, 1e-12);
+        assert_almost_eq!(n.shape_b(), 2.5838281075539338284, 1e-12);
+        assert_almost_eq!(ln_likelihood, 1.834564695687023892, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Beta::fit(&[]).is_err());
+        assert!(Beta::fit(&[0.5]).is_err());
+        assert!(Beta::fit(&[0.5, 0.5]).is_err());
+        assert!(Beta::fit(&[0.0, 0.5]).is_err());
+        assert!(Beta::fit(&[0.5, 1.0]).is_err());
+    }
+}
@@ -4,6 +4,7 @@ use rand::distributions::{Sample, IndependentSample};
 use error::StatsError;
 use function::{beta, factorial};
 use result::Result;
+use statistics::Statistics;
 use super::*;
 
 /// Implements the [Binomial](https://en.wikipedia.org/wiki/Binomial_distribution)
@@ -84,6 +85,42 @@ impl Binomial {
     pub fn n(&self) -> i64 {
         self.n
     }
+
+    /// Fits a binomial distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n <= 0`, `data` is empty or any element is not an
+    /// integer in the interval `[0, n]`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p = Σ x_i / (n * m)
+    /// ```
+    ///
+    /// where `m` is the number of samples. The number of trials `n` is
+    /// assumed to be known
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Binomial;
+    ///
+    /// let (n, _) = Binomial::fit(10, &[3.0, 4.0, 5.0]).unwrap();
+    /// assert_eq!(n.p(), 0.4);
+    /// ```
+    pub fn fit(n: i64, data: &[f64]) -> Result<(Binomial, f64)> {
+        if n <= 0 || data.is_empty() ||
+           data.iter().any(|&x| x < 0.0 || x > n as f64 || x.floor() != x) {
+            return Err(StatsError::BadParams);
+        }
+        let dist = Binomial::new(data.mean() / n as f64, n)?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pmf(x as i64));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Binomial {
@@ -157,6 +194,31 @@ impl Univariate<i64, f64> for Binomial {
         }
     }
 
+    /// Calculates the survival function for the binomial
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `1.0` if `x < 0.0` and `0.0` if `x >= n`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(p)(x + 1, n - x)
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else if x >= self.n as f64 {
+            0.0
+        } else {
+            let k = x.floor();
+            beta::beta_reg(k + 1.0, self.n as f64 - k, self.p)
+        }
+    }
+
     /// Returns the minimum value in the domain of the
     /// binomial distribution representable by a 64-bit
     /// integer
@@ -184,6 +246,31 @@ impl Univariate<i64, f64> for Binomial {
     }
 }
 
+impl InverseCdf<i64, f64> for Binomial {
+    /// Calculates the inverse cumulative distribution function for the
+    /// binomial distribution at `p` i.e. the smallest `x` such that
+    /// `cdf(x) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the Cornish-Fisher normal approximation
+    /// of the quantile and is then corrected by evaluating the exact
+    /// cumulative distribution function. Returns `0` if `p == 0.0`
+    /// and `0` or `n` if `p == 1.0` and the probability of success is
+    /// `0.0` or not respectively
+    fn inverse_cdf(&self, p: f64) -> i64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        let guess = internal::normal_approx_quantile(p, self.mean(), self.std_dev(), self.skewness());
+        internal::integral_inverse_cdf(p, 0, self.n, guess, |k| self.cdf(k as f64))
+    }
+}
+
 impl Mean<f64, f64> for Binomial {
     /// Returns the mean of the binomial distribution
     ///
@@ -546,4 +633,97 @@ mod test {
         test_case(1.0, 10, 0.0, |x| x.cdf(1.0));
         test_case(1.0, 10, 1.0, |x| x.cdf(10.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(0.0, 10, 0, |x| x.inverse_cdf(0.0));
+        test_case(0.0, 10, 0, |x| x.inverse_cdf(0.5));
+        test_case(0.0, 10, 0, |x| x.inverse_cdf(1.0));
+        test_case(1.0, 10, 0, |x| x.inverse_cdf(0.0));
+        test_case(1.0, 10, 10, |x| x.inverse_cdf(0.5));
+        test_case(1.0, 10, 10, |x| x.inverse_cdf(1.0));
+        test_case(0.3, 10, 0, |x| x.inverse_cdf(0.0));
+        test_case(0.3, 10, 0, |x| x.inverse_cdf(0.0282475248));
+        test_case(0.3, 10, 1, |x| x.inverse_cdf(0.0282475250));
+        test_case(0.3, 10, 10, |x| x.inverse_cdf(1.0));
+        test_case(0.3, 10, 0, |x| x.inverse_cdf(1e-10));
+        test_case(0.3, 10, 0, |x| x.inverse_cdf(0.01));
+        test_case(0.3, 10, 1, |x| x.inverse_cdf(0.1));
+        test_case(0.3, 10, 3, |x| x.inverse_cdf(0.5));
+        test_case(0.3, 10, 5, |x| x.inverse_cdf(0.9));
+        test_case(0.3, 10, 7, |x| x.inverse_cdf(0.99));
+        test_case(0.3, 10, 10, |x| x.inverse_cdf(0.999999));
+        test_case(0.5, 1000, 400, |x| x.inverse_cdf(1e-10));
+        test_case(0.5, 1000, 463, |x| x.inverse_cdf(0.01));
+        test_case(0.5, 1000, 480, |x| x.inverse_cdf(0.1));
+        test_case(0.5, 1000, 500, |x| x.inverse_cdf(0.5));
+        test_case(0.5, 1000, 520, |x| x.inverse_cdf(0.9));
+        test_case(0.5, 1000, 537, |x| x.inverse_cdf(0.99));
+        test_case(0.5, 1000, 575, |x| x.inverse_cdf(0.999999));
+        test_case(0.5, 1000000, 496819, |x| x.inverse_cdf(1e-10));
+        test_case(0.5, 1000000, 498837, |x| x.inverse_cdf(0.01));
+        test_case(0.5, 1000000, 499359, |x| x.inverse_cdf(0.1));
+        test_case(0.5, 1000000, 500641, |x| x.inverse_cdf(0.9));
+        test_case(0.5, 1000000, 501163, |x| x.inverse_cdf(0.99));
+        test_case(0.5, 1000000, 502377, |x| x.inverse_cdf(0.999999));
+        test_case(0.01, 10000000, 98005, |x| x.inverse_cdf(1e-10));
+        test_case(0.01, 10000000, 99269, |x| x.inverse_cdf(0.01));
+        test_case(0.01, 10000000, 99597, |x| x.inverse_cdf(0.1));
+        test_case(0.01, 10000000, 100000, |x| x.inverse_cdf(0.5));
+        test_case(0.01, 10000000, 100403, |x| x.inverse_cdf(0.9));
+        test_case(0.01, 10000000, 100733, |x| x.inverse_cdf(0.99));
+        test_case(0.01, 10000000, 101499, |x| x.inverse_cdf(0.999999));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_p_lt_0() {
+        get_value(0.3, 10, |x| x.inverse_cdf(-0.1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_p_gt_1() {
+        get_value(0.3, 10, |x| x.inverse_cdf(1.1));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(0.3, 10, 1.0, |x| x.sf(-1.0));
+        test_almost(0.3, 10, 0.9717524751, 1e-15, |x| x.sf(0.0));
+        test_almost(0.3, 10, 0.0015903864, 1e-15, |x| x.sf(7.0));
+        test_almost(0.3, 10, 0.0015903864, 1e-15, |x| x.sf(7.5));
+        test_case(0.3, 10, 0.0, |x| x.sf(10.0));
+        test_almost(0.5, 1000, 3.7668507235258079483e-38, 1e-50, |x| x.sf(700.0));
+        test_case(0.0, 10, 0.0, |x| x.sf(0.0));
+        test_case(1.0, 10, 1.0, |x| x.sf(9.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(0.3, 10, 0.0015903864f64.ln(), 1e-13, |x| x.ln_sf(7.0));
+        test_almost(0.5, 1000, 3.7668507235258079483e-38f64.ln(), 1e-11, |x| x.ln_sf(700.0));
+        test_case(0.3, 10, f64::NEG_INFINITY, |x| x.ln_sf(10.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Binomial::fit(10, &[3.0, 4.0, 2.0, 5.0, 3.0, 6.0, 4.0, 3.0]).unwrap();
+        assert_eq!(n.n(), 10);
+        assert_almost_eq!(n.p(), 0.375, 1e-15);
+        assert_almost_eq!(ln_likelihood, -13.1851696548582777, 1e-12);
+
+        let (n, ln_likelihood) = Binomial::fit(1, &[1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0]).unwrap();
+        assert_almost_eq!(n.p(), 0.75, 1e-15);
+        assert_almost_eq!(ln_likelihood, -4.4986811569504668023, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Binomial::fit(10, &[]).is_err());
+        assert!(Binomial::fit(0, &[0.0]).is_err());
+        assert!(Binomial::fit(10, &[11.0]).is_err());
+        assert!(Binomial::fit(10, &[-1.0]).is_err());
+        assert!(Binomial::fit(10, &[2.5]).is_err());
+    }
 }
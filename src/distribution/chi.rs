@@ -137,6 +137,30 @@ impl Univariate<f64, f64> for Chi {
         }
     }
 
+    /// Calculates the survival function for the chi
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q(k / 2, x^2 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Q` is
+    /// the upper regularized Gamma function
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        if self.freedom == f64::INFINITY || x == f64::INFINITY {
+            0.0
+        } else {
+            gamma::gamma_ur(self.freedom / 2.0, x * x / 2.0)
+        }
+    }
+
     /// Returns the minimum value in the domain of the chi distribution
     /// representable by a double precision float
     ///
@@ -162,6 +186,26 @@ impl Univariate<f64, f64> for Chi {
     }
 }
 
+impl InverseCdf<f64, f64> for Chi {
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution so the cumulative distribution
+    /// function is inverted numerically using a bracketed root finder
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        internal::inverse_cdf(p, 0.0, f64::INFINITY, self.freedom.sqrt(), |x| self.cdf(x))
+    }
+}
+
 impl Mean<f64, f64> for Chi {
     /// Returns the mean of the chi distribution
     ///
@@ -536,4 +580,32 @@ mod test {
     fn test_neg_cdf() {
         get_value(1.0, |x| x.cdf(-1.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(1.0, 0.0012533144654325545383, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(1.0, 0.6744897501960817432, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(1.0, 3.2905267314918945433, 1e-13, |x| x.inverse_cdf(0.999));
+        test_almost(2.0, 0.45904360502642079045, 1e-14, |x| x.inverse_cdf(0.1));
+        test_almost(2.0, 2.1459660262893473431, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(10.0, 1.2160359632164112278, 1e-14, |x| x.inverse_cdf(0.001));
+        test_almost(10.0, 3.0564387390543209513, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(10.0, 5.4395127029058785374, 1e-13, |x| x.inverse_cdf(0.999));
+        test_case(1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(3.0, 1.5541594313896049214e-21, 1e-33, |x| x.sf(10.0));
+        test_almost(2.0, (-0.5f64).exp(), 1e-15, |x| x.sf(1.0));
+        test_case(1.0, 1.0, |x| x.sf(0.0));
+        test_case(1.0, 0.0, |x| x.sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(3.0, 1.5541594313896049214e-21f64.ln(), 1e-12, |x| x.ln_sf(10.0));
+        test_almost(2.0, -0.5, 1e-15, |x| x.ln_sf(1.0));
+    }
 }
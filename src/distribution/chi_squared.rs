@@ -159,6 +159,57 @@ impl Univariate<f64, f64> for ChiSquared {
         self.g.cdf(x)
     }
 
+    /// Calculates the survival function for the chi-squared
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(k / 2)) * Γ(k / 2, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Γ` is the gamma function
+    /// and upper incomplete gamma function respectively
+    fn sf(&self, x: f64) -> f64 {
+        self.g.sf(x)
+    }
+
+    /// Calculates the natural logarithm of the cumulative distribution
+    /// function for the chi-squared distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(cdf(x))
+    /// ```
+    fn ln_cdf(&self, x: f64) -> f64 {
+        self.g.ln_cdf(x)
+    }
+
+    /// Calculates the natural logarithm of the survival function for
+    /// the chi-squared distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sf(x))
+    /// ```
+    fn ln_sf(&self, x: f64) -> f64 {
+        self.g.ln_sf(x)
+    }
+
     /// Returns the minimum value in the domain of the
     /// chi-squared distribution representable by a double precision
     /// float
@@ -186,6 +237,23 @@ impl Univariate<f64, f64> for ChiSquared {
     }
 }
 
+impl InverseCdf<f64, f64> for ChiSquared {
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the inverse cumulative distribution function of the
+    /// underlying gamma distribution
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.g.inverse_cdf(p)
+    }
+}
+
 impl Mean<f64, f64> for ChiSquared {
     /// Returns the mean of the chi-squared distribution
     ///
@@ -367,4 +435,30 @@ mod test {
         test_case(2.5, 2.5 - 2.0 / 3.0, |x| x.median());
         test_case(3.0, 3.0 - 2.0 / 3.0, |x| x.median());
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(1.0, 1.5707971492624899447e-6, 1e-18, |x| x.inverse_cdf(0.001));
+        test_almost(1.0, 0.45493642311957275194, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(1.0, 10.827566170662730649, 1e-12, |x| x.inverse_cdf(0.999));
+        test_almost(2.0, 0.21072103131565261479, 1e-14, |x| x.inverse_cdf(0.1));
+        test_almost(2.0, 4.6051701859880918121, 1e-13, |x| x.inverse_cdf(0.9));
+        test_almost(10.0, 1.4787434638356650407, 1e-13, |x| x.inverse_cdf(0.001));
+        test_almost(10.0, 9.3418177655919674406, 1e-13, |x| x.inverse_cdf(0.5));
+        test_almost(10.0, 29.588298445074416426, 1e-12, |x| x.inverse_cdf(0.999));
+        test_case(10.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(4.0, 9.8366242246159806934e-21, 1e-33, |x| x.sf(100.0));
+        test_almost(2.0, (-1.0f64).exp(), 1e-15, |x| x.sf(2.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(4.0, -46.068174367275674228, 1e-12, |x| x.ln_sf(100.0));
+        test_almost(2.0, -1.0, 1e-14, |x| x.ln_sf(2.0));
+    }
 }
@@ -0,0 +1,325 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
+use function::gamma;
+use result::Result;
+use super::*;
+
+/// Implements the [Dirichlet](https://en.wikipedia.org/wiki/Dirichlet_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Dirichlet, Mean};
+/// use statrs::prec;
+///
+/// let n = Dirichlet::new(&[1.0, 2.0, 1.0]).unwrap();
+/// assert_eq!(n.mean(), vec![0.25, 0.5, 0.25]);
+/// assert!(prec::almost_eq(n.pdf(&[0.25, 0.5, 0.25]), 3.0, 1e-14));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+}
+
+impl Dirichlet {
+    /// Constructs a new dirichlet distribution with the concentration
+    /// parameters `alpha`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` has fewer than two elements or any
+    /// element is `NaN`, infinite or not positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dirichlet;
+    ///
+    /// let mut result = Dirichlet::new(&[1.0, 2.0, 3.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Dirichlet::new(&[1.0, 0.0]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(alpha: &[f64]) -> Result<Dirichlet> {
+        if alpha.len() < 2 || alpha.iter().any(|&a| a <= 0.0 || !a.is_finite()) {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Dirichlet { alpha: alpha.to_vec() })
+        }
+    }
+
+    /// Returns the concentration parameters of the dirichlet distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dirichlet;
+    ///
+    /// let n = Dirichlet::new(&[1.0, 2.0, 3.0]).unwrap();
+    /// assert_eq!(n.alpha(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn alpha(&self) -> &[f64] {
+        &self.alpha
+    }
+
+    /// Returns the covariance matrix of the dirichlet distribution as
+    /// a vector of rows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// α_i * (α_0 * δ_ij - α_j) / (α_0^2 * (α_0 + 1))
+    /// ```
+    ///
+    /// where `α_0` is the sum of the concentration parameters and `δ_ij`
+    /// is the Kronecker delta
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dirichlet;
+    ///
+    /// let n = Dirichlet::new(&[1.0, 1.0]).unwrap();
+    /// assert_eq!(n.covariance(), vec![vec![1.0 / 12.0, -1.0 / 12.0], vec![-1.0 / 12.0, 1.0 / 12.0]]);
+    /// ```
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        let sum = self.alpha_sum();
+        let denom = sum * sum * (sum + 1.0);
+        self.alpha
+            .iter()
+            .enumerate()
+            .map(|(i, &ai)| {
+                self.alpha
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &aj)| if i == j { ai * (sum - ai) / denom } else { -ai * aj / denom })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Calculates the probability density function for the dirichlet
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.alpha().len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` does not lie on the probability simplex i.e. any
+    /// element is outside `[0, 1]` or the elements do not sum to `1` within
+    /// `1e-12`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (Γ(α_0) / Π Γ(α_i)) * Π x_i^(α_i - 1)
+    /// ```
+    ///
+    /// where `α_0` is the sum of the concentration parameters and `Γ` is
+    /// the gamma function
+    pub fn pdf(&self, x: &[f64]) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the dirichlet
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.alpha().len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NEG_INFINITY` if `x` does not lie on the probability
+    /// simplex i.e. any element is outside `[0, 1]` or the elements do not
+    /// sum to `1` within `1e-12`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Γ(α_0)) - Σ ln(Γ(α_i)) + Σ (α_i - 1) * ln(x_i)
+    /// ```
+    ///
+    /// where `α_0` is the sum of the concentration parameters and `Γ` is
+    /// the gamma function
+    pub fn ln_pdf(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.alpha.len(),
+                "{}",
+                StatsError::ContainersMustBeSameLength);
+        if x.iter().any(|&x| x < 0.0 || x > 1.0 || x.is_nan()) ||
+           (x.iter().sum::<f64>() - 1.0).abs() > 1e-12 {
+            return f64::NEG_INFINITY;
+        }
+        let norm = self.alpha.iter().fold(gamma::ln_gamma(self.alpha_sum()),
+                                          |acc, &a| acc - gamma::ln_gamma(a));
+        x.iter().zip(self.alpha.iter()).fold(norm, |acc, (&x, &a)| {
+            // avoids 0 * ln(0) when x is on the boundary of the simplex
+            if a == 1.0 { acc } else { acc + (a - 1.0) * x.ln() }
+        })
+    }
+
+    fn alpha_sum(&self) -> f64 {
+        self.alpha.iter().sum()
+    }
+}
+
+impl Sample<Vec<f64>> for Dirichlet {
+    /// Generate a random sample from a dirichlet
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<Vec<f64>> for Dirichlet {
+    /// Generate a random independent sample from a dirichlet
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<Vec<f64>> for Dirichlet {
+    /// Generate a random sample from the dirichlet distribution
+    /// using `r` as the source of randomness. Draws independent gamma
+    /// samples with shapes `α_i` and a rate of `1` and normalizes them
+    /// to sum to `1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{Dirichlet, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = Dirichlet::new(&[1.0, 2.0, 3.0]).unwrap();
+    /// print!("{:?}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        let samples: Vec<f64> =
+            self.alpha.iter().map(|&a| super::gamma::sample_unchecked(r, a, 1.0)).collect();
+        let sum: f64 = samples.iter().sum();
+        samples.iter().map(|x| x / sum).collect()
+    }
+}
+
+impl Mean<Vec<f64>, Vec<f64>> for Dirichlet {
+    /// Returns the mean vector of the dirichlet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// α_i / α_0
+    /// ```
+    ///
+    /// where `α_0` is the sum of the concentration parameters
+    fn mean(&self) -> Vec<f64> {
+        let sum = self.alpha_sum();
+        self.alpha.iter().map(|a| a / sum).collect()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use distribution::*;
+
+    fn try_create(alpha: &[f64]) -> Dirichlet {
+        let n = Dirichlet::new(alpha);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(alpha: &[f64]) {
+        let n = Dirichlet::new(alpha);
+        assert!(n.is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(&[1.0, 2.0]).alpha(), &[1.0, 2.0]);
+        assert_eq!(try_create(&[0.001, 1000.0, 5.0]).alpha(), &[0.001, 1000.0, 5.0]);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(&[]);
+        bad_create_case(&[1.0]);
+        bad_create_case(&[1.0, 0.0]);
+        bad_create_case(&[1.0, -1.0]);
+        bad_create_case(&[1.0, f64::NAN]);
+        bad_create_case(&[1.0, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(try_create(&[1.0, 1.0]).mean(), vec![0.5, 0.5]);
+        assert_eq!(try_create(&[1.5, 2.0, 3.5]).mean(), vec![1.5 / 7.0, 2.0 / 7.0, 0.5]);
+    }
+
+    #[test]
+    fn test_covariance() {
+        let cov = try_create(&[1.5, 2.0, 3.5]).covariance();
+        assert_almost_eq!(cov[0][0], 0.021045918367346938776, 1e-16);
+        assert_almost_eq!(cov[1][1], 0.025510204081632653061, 1e-16);
+        assert_almost_eq!(cov[0][1], -0.0076530612244897959184, 1e-16);
+        assert_eq!(cov[0][1], cov[1][0]);
+        assert_almost_eq!(cov[1][2], -0.017857142857142857143, 1e-16);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = try_create(&[1.5, 2.0, 3.5]);
+        assert_almost_eq!(n.pdf(&[0.2, 0.3, 0.5]), 5.7979252344369064362, 1e-14);
+        assert_almost_eq!(try_create(&[1.0, 1.0, 1.0]).pdf(&[0.0, 0.0, 1.0]), 2.0, 1e-13);
+        assert_eq!(n.pdf(&[0.2, 0.3, 0.4]), 0.0);
+        assert_eq!(n.pdf(&[-0.2, 0.7, 0.5]), 0.0);
+        assert_eq!(n.pdf(&[0.0, 0.5, 0.5]), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = try_create(&[1.5, 2.0, 3.5]);
+        assert_almost_eq!(n.ln_pdf(&[0.2, 0.3, 0.5]), 1.7575001353554225391, 1e-14);
+        assert_eq!(n.ln_pdf(&[0.5, 0.5, 0.5]), f64::NEG_INFINITY);
+        assert_eq!(try_create(&[0.5, 1.0]).ln_pdf(&[0.0, 1.0]), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pdf_wrong_dim() {
+        try_create(&[1.0, 2.0]).pdf(&[1.0]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let n = try_create(&[1.5, 2.0, 3.5]);
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let count = 20000;
+        let mut sums = vec![0.0; 3];
+        for _ in 0..count {
+            let s = n.sample(&mut r);
+            assert_almost_eq!(s.iter().sum::<f64>(), 1.0, 1e-14);
+            for (sum, x) in sums.iter_mut().zip(s.iter()) {
+                *sum += *x;
+            }
+        }
+        for (sum, mean) in sums.iter().zip(n.mean().iter()) {
+            assert_almost_eq!(sum / count as f64, *mean, 0.01);
+        }
+    }
+}
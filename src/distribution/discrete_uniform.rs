@@ -122,6 +122,31 @@ impl Univariate<i64, f64> for DiscreteUniform {
         if ans > 1.0 { 1.0 } else { ans }
     }
 
+    /// Calculates the survival function for the discrete uniform
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `1.0` if `x < min` and `0.0` if `x >= max`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - floor(x)) / (max - min + 1)
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.min as f64 {
+            return 1.0;
+        }
+        if x >= self.max as f64 {
+            return 0.0;
+        }
+
+        let lower = self.min as f64;
+        let upper = self.max as f64;
+        (upper - x.floor()) / (upper - lower + 1.0)
+    }
+
     /// Returns the minimum value in the domain of the discrete uniform
     /// distribution
     ///
@@ -143,6 +168,35 @@ impl Univariate<i64, f64> for DiscreteUniform {
     }
 }
 
+impl InverseCdf<i64, f64> for DiscreteUniform {
+    /// Calculates the inverse cumulative distribution function for the
+    /// discrete uniform distribution at `p` i.e. the smallest `x` such
+    /// that `cdf(x) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The closed form below is corrected against the cumulative
+    /// distribution function to guard against rounding error.
+    /// Returns `min` if `p == 0.0` and `max` if `p == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + ceil(p * (max - min + 1)) - 1
+    /// ```
+    fn inverse_cdf(&self, p: f64) -> i64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        let guess = self.min as f64 + (p * (self.max as f64 - self.min as f64 + 1.0)).ceil() - 1.0;
+        internal::integral_inverse_cdf(p, self.min, self.max, guess, |k| self.cdf(k as f64))
+    }
+}
+
 impl Mean<f64, f64> for DiscreteUniform {
     /// Returns the mean of the discrete uniform distribution
     ///
@@ -412,4 +466,38 @@ mod test {
         test_case(-10, -10, 1.0, |x| x.cdf(-10.0));
         test_case(-10, -10, 0.0, |x| x.cdf(-11.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(-10, 10, -10, |x| x.inverse_cdf(0.0));
+        test_case(-10, 10, -10, |x| x.inverse_cdf(1.0 / 21.0));
+        test_case(-10, 10, -9, |x| x.inverse_cdf(0.05));
+        test_case(-10, 10, 0, |x| x.inverse_cdf(0.5));
+        test_case(-10, 10, 9, |x| x.inverse_cdf(20.0 / 21.0));
+        test_case(-10, 10, 10, |x| x.inverse_cdf(0.99));
+        test_case(-10, 10, 10, |x| x.inverse_cdf(1.0));
+        test_case(5, 5, 5, |x| x.inverse_cdf(0.0));
+        test_case(5, 5, 5, |x| x.inverse_cdf(0.5));
+        test_case(5, 5, 5, |x| x.inverse_cdf(1.0));
+        for k in 0..100 {
+            test_case(0, 99, k, |x| x.inverse_cdf(x.cdf(k as f64)));
+        }
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(-10, 10, 1.0, |x| x.sf(-11.0));
+        test_case(-10, 10, 20.0 / 21.0, |x| x.sf(-10.0));
+        test_case(-10, 10, 10.0 / 21.0, |x| x.sf(0.0));
+        test_case(-10, 10, 10.0 / 21.0, |x| x.sf(0.5));
+        test_case(-10, 10, 0.0, |x| x.sf(10.0));
+        test_case(5, 5, 0.0, |x| x.sf(5.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(-10, 10, 0.0, |x| x.ln_sf(-11.0));
+        test_case(-10, 10, (10.0f64 / 21.0).ln(), |x| x.ln_sf(0.0));
+        test_case(-10, 10, f64::NEG_INFINITY, |x| x.ln_sf(10.0));
+    }
 }
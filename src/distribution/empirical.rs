@@ -0,0 +1,321 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
+use result::Result;
+use statistics::Statistics;
+use super::*;
+
+/// Implements the [Empirical](https://en.wikipedia.org/wiki/Empirical_distribution_function)
+/// distribution of a data set, which assigns equal probability to each
+/// observed value
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Empirical, Mean, Univariate};
+///
+/// let n = Empirical::new(&[3.0, 1.0, 2.0, 2.0]).unwrap();
+/// assert_eq!(n.mean(), 2.0);
+/// assert_eq!(n.cdf(2.0), 0.75);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Empirical {
+    data: Vec<f64>,
+    mean: f64,
+    variance: f64,
+}
+
+impl Empirical {
+    /// Constructs a new empirical distribution from the observed values
+    /// `data`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty or any entry is `NaN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Empirical;
+    /// use std::f64;
+    ///
+    /// let mut result = Empirical::new(&[1.0, 2.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Empirical::new(&[1.0, f64::NAN]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(data: &[f64]) -> Result<Empirical> {
+        if data.is_empty() || data.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mut data = data.to_vec();
+        // NaN was ruled out above so the comparison never fails
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(Empirical {
+            mean: data.mean(),
+            variance: data.population_variance(),
+            data: data,
+        })
+    }
+
+    /// Returns the observed values in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Empirical;
+    ///
+    /// let n = Empirical::new(&[3.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!(n.data(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn data(&self) -> &[f64] {
+        &self.data
+    }
+
+    // returns the number of observed values less than or equal to x
+    fn count_less_or_equal(&self, x: f64) -> usize {
+        let (mut low, mut high) = (0, self.data.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.data[mid] <= x {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+}
+
+impl Sample<f64> for Empirical {
+    /// Generate a random sample from the empirical distribution
+    /// using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<f64> for Empirical {
+    /// Generate a random independent sample from the empirical
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<f64> for Empirical {
+    /// Generate a random sample from the empirical distribution using
+    /// `r` as the source of randomness, i.e. draws one of the observed
+    /// values uniformly at random. Repeated draws resample the data with
+    /// replacement, as in the bootstrap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{Empirical, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = Empirical::new(&[1.0, 2.0, 3.0]).unwrap();
+    /// print!("{}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> f64 {
+        self.data[r.gen_range(0, self.data.len())]
+    }
+}
+
+impl Univariate<f64, f64> for Empirical {
+    /// Calculates the cumulative distribution function for the empirical
+    /// distribution at `x`, the fraction of the observed values less than
+    /// or equal to `x`
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is a step function computed in `O(log(N))` time
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// #{i: x_i <= x} / N
+    /// ```
+    ///
+    /// where `x_i` are the observed values
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        self.count_less_or_equal(x) as f64 / self.data.len() as f64
+    }
+
+    /// Calculates the survival function for the empirical distribution
+    /// at `x`, the fraction of the observed values greater than `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// #{i: x_i > x} / N
+    /// ```
+    ///
+    /// where `x_i` are the observed values
+    fn sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        (self.data.len() - self.count_less_or_equal(x)) as f64 / self.data.len() as f64
+    }
+
+    /// Returns the minimum value in the domain of the empirical
+    /// distribution, the smallest observed value
+    fn min(&self) -> f64 {
+        self.data[0]
+    }
+
+    /// Returns the maximum value in the domain of the empirical
+    /// distribution, the largest observed value
+    fn max(&self) -> f64 {
+        self.data[self.data.len() - 1]
+    }
+}
+
+impl Mean<f64, f64> for Empirical {
+    /// Returns the mean of the empirical distribution, the sample mean of
+    /// the observed values
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σx_i / N
+    /// ```
+    ///
+    /// where `x_i` are the observed values
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+}
+
+impl Variance<f64, f64> for Empirical {
+    /// Returns the variance of the empirical distribution
+    ///
+    /// # Remarks
+    ///
+    /// This is the population variance of the observed values, using `N`
+    /// as a normalizer, rather than the unbiased estimate of the variance
+    /// of the distribution they were drawn from
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(x_i - μ)^2 / N
+    /// ```
+    ///
+    /// where `x_i` are the observed values and `μ` their mean
+    fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Returns the standard deviation of the empirical distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(Σ(x_i - μ)^2 / N)
+    /// ```
+    ///
+    /// where `x_i` are the observed values and `μ` their mean
+    fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use distribution::*;
+
+    const DATA: [f64; 8] = [2.0, -1.0, 5.0, 2.0, 0.5, 10.0, 2.0, -3.0];
+
+    fn try_create(data: &[f64]) -> Empirical {
+        let n = Empirical::new(data);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(&DATA);
+        assert_eq!(n.data(), &[-3.0, -1.0, 0.5, 2.0, 2.0, 2.0, 5.0, 10.0]);
+        assert_eq!(n.min(), -3.0);
+        assert_eq!(n.max(), 10.0);
+        try_create(&[f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(Empirical::new(&[]).is_err());
+        assert!(Empirical::new(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_mean_variance() {
+        let n = try_create(&DATA);
+        assert_eq!(n.mean(), 2.1875);
+        assert_almost_eq!(n.variance(), 13.62109375, 1e-14);
+        assert_almost_eq!(n.std_dev(), 13.62109375f64.sqrt(), 1e-14);
+        let n = try_create(&[4.0]);
+        assert_eq!(n.mean(), 4.0);
+        assert_eq!(n.variance(), 0.0);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = try_create(&DATA);
+        assert_eq!(n.cdf(-4.0), 0.0);
+        assert_eq!(n.cdf(-3.0), 0.125);
+        assert_eq!(n.cdf(1.9), 0.375);
+        assert_eq!(n.cdf(2.0), 0.75);
+        assert_eq!(n.cdf(10.0), 1.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+        assert!(n.cdf(f64::NAN).is_nan());
+        assert_eq!(n.ln_cdf(2.0), 0.75f64.ln());
+        assert_eq!(n.ln_cdf(-4.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_sf() {
+        let n = try_create(&DATA);
+        assert_eq!(n.sf(-4.0), 1.0);
+        assert_eq!(n.sf(2.0), 0.25);
+        assert_eq!(n.sf(10.0), 0.0);
+        assert!(n.sf(f64::NAN).is_nan());
+        assert_eq!(n.ln_sf(2.0), 0.25f64.ln());
+        for &x in &[-3.5, -1.0, 0.0, 2.0, 4.0, 10.0] {
+            assert_eq!(n.cdf(x) + n.sf(x), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let n = try_create(&DATA);
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let count = 20000;
+        let mut twos = 0;
+        for _ in 0..count {
+            let x = n.sample(&mut r);
+            assert!(DATA.contains(&x));
+            if x == 2.0 {
+                twos += 1;
+            }
+        }
+        assert_almost_eq!(twos as f64 / count as f64, 0.375, 0.01);
+    }
+}
@@ -3,6 +3,7 @@ use rand::Rng;
 use rand::distributions::{Sample, IndependentSample};
 use error::StatsError;
 use result::Result;
+use statistics::Statistics;
 use super::*;
 
 /// Implements the [Exponential](https://en.wikipedia.org/wiki/Exponential_distribution)
@@ -63,6 +64,44 @@ impl Exponential {
     pub fn rate(&self) -> f64 {
         self.rate
     }
+
+    /// Fits a exponential distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, any element is negative or
+    /// all of its elements are zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ = n / Σ x_i
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Exponential;
+    ///
+    /// let (n, _) = Exponential::fit(&[1.0, 2.0, 3.0]).unwrap();
+    /// assert_eq!(n.rate(), 0.5);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Exponential, f64)> {
+        if data.is_empty() || data.iter().any(|&x| x < 0.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mean = data.mean();
+        if mean == 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let dist = Exponential::new(1.0 / mean)?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Exponential {
@@ -130,6 +169,63 @@ impl Univariate<f64, f64> for Exponential {
         1.0 - (-self.rate * x).exp()
     }
 
+    /// Calculates the survival function for the exponential
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-λ * x)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        (-self.rate * x).exp()
+    }
+
+    /// Calculates the natural logarithm of the cumulative distribution
+    /// function for the exponential distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - e^(-λ * x))
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn ln_cdf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        (-(-self.rate * x).exp_m1()).ln()
+    }
+
+    /// Calculates the natural logarithm of the survival function for
+    /// the exponential distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -λ * x
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn ln_sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        -self.rate * x
+    }
+
     /// Returns the minimum value in the domain of the exponential
     /// distribution representable by a double precision float
     ///
@@ -155,6 +251,29 @@ impl Univariate<f64, f64> for Exponential {
     }
 }
 
+impl InverseCdf<f64, f64> for Exponential {
+    /// Calculates the inverse cumulative distribution function for the
+    /// exponential distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(1 - p) / λ
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        -(-p).ln_1p() / self.rate
+    }
+}
+
 impl Mean<f64, f64> for Exponential {
     /// Returns the mean of the exponential distribution
     ///
@@ -495,4 +614,61 @@ mod test {
     fn test_neg_cdf() {
         get_value(0.1, |x| x.cdf(-1.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(2.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(2.0, 5.00000000025e-11, 1e-24, |x| x.inverse_cdf(1e-10));
+        test_almost(2.0, 0.34657359027997265471, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(2.0, 1.151292546497022842, 1e-15, |x| x.inverse_cdf(0.9));
+        test_case(2.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        test_almost(0.1, 6.9314718055994530942, 1e-14, |x| x.inverse_cdf(0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_input_gt_one() {
+        get_value(1.0, |x| x.inverse_cdf(1.5));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(2.0, 1.0, |x| x.sf(0.0));
+        test_almost(2.0, 4.2483542552915889953e-18, 1e-31, |x| x.sf(20.0));
+        test_case(2.0, 0.0, |x| x.sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        test_case(2.0, f64::NEG_INFINITY, |x| x.ln_cdf(0.0));
+        test_almost(2.0, -45.358554679320968426, 1e-12, |x| x.ln_cdf(1e-20));
+        test_case(2.0, 0.0, |x| x.ln_cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(2.0, 0.0, |x| x.ln_sf(0.0));
+        test_case(2.0, -2000.0, |x| x.ln_sf(1000.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sf_lt_0() {
+        get_value(2.0, |x| x.sf(-1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Exponential::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2]).unwrap();
+        assert_almost_eq!(n.rate(), 0.53475935828877005348, 1e-15);
+        assert_almost_eq!(ln_likelihood, -16.259384308664952563, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Exponential::fit(&[]).is_err());
+        assert!(Exponential::fit(&[0.0, 0.0]).is_err());
+        assert!(Exponential::fit(&[-1.0, 1.0]).is_err());
+        assert!(Exponential::fit(&[f64::NAN]).is_err());
+    }
 }
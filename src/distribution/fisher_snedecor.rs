@@ -0,0 +1,677 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
+use function::{beta, gamma};
+use result::Result;
+use super::*;
+
+/// Implements the [Fisher-Snedecor](https://en.wikipedia.org/wiki/F-distribution)
+/// distribution, also known as the F-distribution, of the ratio of two
+/// independent chi-squared variables each divided by its degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{FisherSnedecor, Univariate};
+/// use statrs::prec;
+///
+/// let n = FisherSnedecor::new(3.0, 10.0).unwrap();
+/// assert!(prec::almost_eq(n.cdf(1.0), 0.56766279697830293492, 1e-13));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FisherSnedecor {
+    freedom_1: f64,
+    freedom_2: f64,
+}
+
+impl FisherSnedecor {
+    /// Constructs a new Fisher-Snedecor distribution with `freedom_1`
+    /// degrees of freedom in the numerator and `freedom_2` degrees of
+    /// freedom in the denominator
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either `freedom_1` or `freedom_2` is `NaN`,
+    /// infinite, or less than or equal to `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FisherSnedecor;
+    ///
+    /// let mut result = FisherSnedecor::new(2.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = FisherSnedecor::new(0.0, 3.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom_1: f64, freedom_2: f64) -> Result<FisherSnedecor> {
+        let is_valid = |freedom: f64| freedom > 0.0 && freedom.is_finite();
+        if !is_valid(freedom_1) || !is_valid(freedom_2) {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(FisherSnedecor {
+                freedom_1: freedom_1,
+                freedom_2: freedom_2,
+            })
+        }
+    }
+
+    /// Returns the degrees of freedom of the numerator of the
+    /// Fisher-Snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FisherSnedecor;
+    ///
+    /// let n = FisherSnedecor::new(2.0, 3.0).unwrap();
+    /// assert_eq!(n.freedom_1(), 2.0);
+    /// ```
+    pub fn freedom_1(&self) -> f64 {
+        self.freedom_1
+    }
+
+    /// Returns the degrees of freedom of the denominator of the
+    /// Fisher-Snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FisherSnedecor;
+    ///
+    /// let n = FisherSnedecor::new(2.0, 3.0).unwrap();
+    /// assert_eq!(n.freedom_2(), 3.0);
+    /// ```
+    pub fn freedom_2(&self) -> f64 {
+        self.freedom_2
+    }
+}
+
+impl Sample<f64> for FisherSnedecor {
+    /// Generate a random sample from a Fisher-Snedecor distribution
+    /// using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<f64> for FisherSnedecor {
+    /// Generate a random independent sample from a Fisher-Snedecor
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<f64> for FisherSnedecor {
+    /// Generate a random sample from the Fisher-Snedecor distribution
+    /// using `r` as the source of randomness, as the ratio of two
+    /// chi-squared samples each divided by its degrees of freedom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{FisherSnedecor, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = FisherSnedecor::new(2.0, 3.0).unwrap();
+    /// print!("{}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> f64 {
+        let x = super::gamma::sample_unchecked(r, self.freedom_1 / 2.0, 0.5);
+        let y = super::gamma::sample_unchecked(r, self.freedom_2 / 2.0, 0.5);
+        (x / self.freedom_1) / (y / self.freedom_2)
+    }
+}
+
+impl Univariate<f64, f64> for FisherSnedecor {
+    /// Calculates the cumulative distribution function for the
+    /// Fisher-Snedecor distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I(d1 * x / (d1 * x + d2), d1 / 2, d2 / 2)
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom, `d2` is the
+    /// denominator degrees of freedom, and `I` is the regularized
+    /// incomplete beta function
+    fn cdf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        if x == f64::INFINITY {
+            1.0
+        } else {
+            let t = self.freedom_1 * x;
+            beta::beta_reg(self.freedom_1 / 2.0,
+                           self.freedom_2 / 2.0,
+                           t / (t + self.freedom_2))
+        }
+    }
+
+    /// Calculates the survival function for the Fisher-Snedecor
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I(d2 / (d1 * x + d2), d2 / 2, d1 / 2)
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom, `d2` is the
+    /// denominator degrees of freedom, and `I` is the regularized
+    /// incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        if x == f64::INFINITY {
+            0.0
+        } else {
+            beta::beta_reg(self.freedom_2 / 2.0,
+                           self.freedom_1 / 2.0,
+                           self.freedom_2 / (self.freedom_1 * x + self.freedom_2))
+        }
+    }
+
+    /// Returns the minimum value in the domain of the
+    /// Fisher-Snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the maximum value in the domain of the
+    /// Fisher-Snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl InverseCdf<f64, f64> for FisherSnedecor {
+    /// Calculates the inverse cumulative distribution function for the
+    /// Fisher-Snedecor distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution so the cumulative distribution
+    /// function is inverted numerically using a bracketed root finder
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        internal::inverse_cdf(p, 0.0, f64::INFINITY, 1.0, |x| self.cdf(x))
+    }
+}
+
+impl Mean<f64, f64> for FisherSnedecor {
+    /// Returns the mean of the Fisher-Snedecor distribution
+    ///
+    /// # Panics
+    ///
+    /// If `freedom_2 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d2 / (d2 - 2)
+    /// ```
+    ///
+    /// where `d2` is the denominator degrees of freedom
+    fn mean(&self) -> f64 {
+        assert!(self.freedom_2 > 2.0,
+                "{}",
+                StatsError::ArgGt("freedom_2", 2.0));
+        self.freedom_2 / (self.freedom_2 - 2.0)
+    }
+}
+
+impl Variance<f64, f64> for FisherSnedecor {
+    /// Returns the variance of the Fisher-Snedecor distribution
+    ///
+    /// # Panics
+    ///
+    /// If `freedom_2 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if d2 > 4 {
+    ///     2 * d2^2 * (d1 + d2 - 2) / (d1 * (d2 - 2)^2 * (d2 - 4))
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom and `d2` is the
+    /// denominator degrees of freedom
+    fn variance(&self) -> f64 {
+        assert!(self.freedom_2 > 2.0,
+                "{}",
+                StatsError::ArgGt("freedom_2", 2.0));
+        if self.freedom_2 > 4.0 {
+            let d = self.freedom_2 - 2.0;
+            2.0 * self.freedom_2 * self.freedom_2 * (self.freedom_1 + d) /
+            (self.freedom_1 * d * d * (self.freedom_2 - 4.0))
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Returns the standard deviation of the Fisher-Snedecor distribution
+    ///
+    /// # Panics
+    ///
+    /// If `freedom_2 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(variance)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Entropy<f64> for FisherSnedecor {
+    /// Returns the entropy of the Fisher-Snedecor distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(d2 / d1 * B(d1 / 2, d2 / 2)) + (1 - d1 / 2) * ψ(d1 / 2) - (1 + d2 / 2) * ψ(d2 / 2) +
+    /// (d1 + d2) / 2 * ψ((d1 + d2) / 2)
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom, `d2` is the
+    /// denominator degrees of freedom, `B` is the beta function, and `ψ` is
+    /// the digamma function
+    fn entropy(&self) -> f64 {
+        let (a, b) = (self.freedom_1 / 2.0, self.freedom_2 / 2.0);
+        (self.freedom_2 / self.freedom_1).ln() + beta::ln_beta(a, b) +
+        (1.0 - a) * gamma::digamma(a) - (1.0 + b) * gamma::digamma(b) +
+        (a + b) * gamma::digamma(a + b)
+    }
+}
+
+impl Skewness<f64, f64> for FisherSnedecor {
+    /// Returns the skewness of the Fisher-Snedecor distribution
+    ///
+    /// # Panics
+    ///
+    /// If `freedom_2 <= 6.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 * d1 + d2 - 2) * sqrt(8 * (d2 - 4)) / ((d2 - 6) * sqrt(d1 * (d1 + d2 - 2)))
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom and `d2` is the
+    /// denominator degrees of freedom
+    fn skewness(&self) -> f64 {
+        assert!(self.freedom_2 > 6.0,
+                "{}",
+                StatsError::ArgGt("freedom_2", 6.0));
+        (2.0 * self.freedom_1 + self.freedom_2 - 2.0) * (8.0 * (self.freedom_2 - 4.0)).sqrt() /
+        ((self.freedom_2 - 6.0) *
+         (self.freedom_1 * (self.freedom_1 + self.freedom_2 - 2.0)).sqrt())
+    }
+}
+
+impl Median<f64> for FisherSnedecor {
+    /// Returns the median of the Fisher-Snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form, the value is the numerically evaluated
+    /// inverse cumulative distribution function at `0.5`
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<f64, f64> for FisherSnedecor {
+    /// Returns the mode of the Fisher-Snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// The density is unbounded or maximal at `0` for `freedom_1 <= 2.0`,
+    /// so the mode is only calculated for `freedom_1 > 2.0`
+    ///
+    /// # Panics
+    ///
+    /// If `freedom_1 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (d1 - 2) / d1 * d2 / (d2 + 2)
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom and `d2` is the
+    /// denominator degrees of freedom
+    fn mode(&self) -> f64 {
+        assert!(self.freedom_1 > 2.0,
+                "{}",
+                StatsError::ArgGt("freedom_1", 2.0));
+        (self.freedom_1 - 2.0) / self.freedom_1 * self.freedom_2 / (self.freedom_2 + 2.0)
+    }
+}
+
+impl Continuous<f64, f64> for FisherSnedecor {
+    /// Calculates the probability density function for the Fisher-Snedecor
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt((d1 * x)^d1 * d2^d2 / (d1 * x + d2)^(d1 + d2)) / (x * B(d1 / 2, d2 / 2))
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom, `d2` is the
+    /// denominator degrees of freedom, and `B` is the beta function
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the
+    /// Fisher-Snedecor distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sqrt((d1 * x)^d1 * d2^d2 / (d1 * x + d2)^(d1 + d2)) / (x * B(d1 / 2, d2 / 2)))
+    /// ```
+    ///
+    /// where `d1` is the numerator degrees of freedom, `d2` is the
+    /// denominator degrees of freedom, and `B` is the beta function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        let (d1, d2) = (self.freedom_1, self.freedom_2);
+        if x == 0.0 {
+            if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                0.0
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else if x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            // ln_1p keeps the density accurate for small x
+            0.5 * d1 * (d1 / d2).ln() + (0.5 * d1 - 1.0) * x.ln() -
+            0.5 * (d1 + d2) * (d1 * x / d2).ln_1p() - beta::ln_beta(d1 / 2.0, d2 / 2.0)
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use distribution::*;
+    use statistics::Statistics;
+
+    fn try_create(freedom_1: f64, freedom_2: f64) -> FisherSnedecor {
+        let n = FisherSnedecor::new(freedom_1, freedom_2);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_case<F>(freedom_1: f64, freedom_2: f64, expected: f64, eval: F)
+        where F: Fn(FisherSnedecor) -> f64
+    {
+        let n = try_create(freedom_1, freedom_2);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(freedom_1: f64, freedom_2: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(FisherSnedecor) -> f64
+    {
+        let n = try_create(freedom_1, freedom_2);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(0.1, 0.1);
+        try_create(1.0, 10.0);
+        try_create(100.0, 3.5);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(FisherSnedecor::new(f64::NAN, 1.0).is_err());
+        assert!(FisherSnedecor::new(1.0, f64::NAN).is_err());
+        assert!(FisherSnedecor::new(0.0, 1.0).is_err());
+        assert!(FisherSnedecor::new(1.0, -1.0).is_err());
+        assert!(FisherSnedecor::new(f64::INFINITY, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_mean() {
+        test_almost(3.0, 10.0, 1.25, 1e-15, |x| x.mean());
+        test_almost(1.0, 7.0, 1.4, 1e-15, |x| x.mean());
+        test_almost(100.0, 100.0, 1.0204081632653061224, 1e-15, |x| x.mean());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mean_freedom_2_lte_2() {
+        try_create(3.0, 2.0).mean();
+    }
+
+    #[test]
+    fn test_variance() {
+        test_almost(3.0, 10.0, 1.9097222222222222222, 1e-15, |x| x.variance());
+        test_almost(1.0, 7.0, 7.84, 1e-14, |x| x.variance());
+        test_almost(100.0, 100.0, 0.04295085381091211995, 1e-16, |x| x.variance());
+        test_almost(0.5, 12.5, 7.3362678404695211418, 1e-14, |x| x.variance());
+        test_case(3.0, 4.0, f64::INFINITY, |x| x.variance());
+        test_case(3.0, 2.5, f64::INFINITY, |x| x.variance());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_variance_freedom_2_lte_2() {
+        try_create(3.0, 1.5).variance();
+    }
+
+    #[test]
+    fn test_std_dev() {
+        test_almost(3.0, 10.0, 1.9097222222222222222f64.sqrt(), 1e-15, |x| x.std_dev());
+        test_case(3.0, 3.0, f64::INFINITY, |x| x.std_dev());
+    }
+
+    #[test]
+    fn test_entropy() {
+        test_almost(3.0, 10.0, 1.1976549872009678872, 1e-13, |x| x.entropy());
+        test_almost(1.0, 1.0, 2.531024246969290793, 1e-14, |x| x.entropy());
+        test_almost(2.0, 27.0, 1.0740740740740740741, 1e-12, |x| x.entropy());
+        test_almost(10.0, 5.0, 1.350387499362284379, 1e-14, |x| x.entropy());
+        test_almost(100.0, 100.0, -0.18549954586610643374, 1e-13, |x| x.entropy());
+        test_almost(0.5, 0.7, 2.0768261262932012473, 1e-14, |x| x.entropy());
+    }
+
+    #[test]
+    fn test_skewness() {
+        test_almost(3.0, 10.0, 4.2211588240886907171, 1e-14, |x| x.skewness());
+        test_almost(1.0, 7.0, 14.0, 1e-14, |x| x.skewness());
+        test_almost(100.0, 100.0, 0.6243619457334664571, 1e-15, |x| x.skewness());
+        test_almost(0.5, 12.5, 6.2209626704016149951, 1e-14, |x| x.skewness());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_skewness_freedom_2_lte_6() {
+        try_create(3.0, 6.0).skewness();
+    }
+
+    #[test]
+    fn test_median() {
+        test_almost(3.0, 10.0, 0.84508057659171366713, 1e-13, |x| x.median());
+        test_almost(1.0, 1.0, 1.0, 1e-14, |x| x.median());
+        test_almost(2.0, 27.0, 0.71125023562590168903, 1e-12, |x| x.median());
+        test_almost(10.0, 5.0, 1.0730383272195110393, 1e-14, |x| x.median());
+        test_almost(100.0, 100.0, 1.0, 1e-14, |x| x.median());
+        test_almost(0.5, 0.7, 0.60376009505244533001, 1e-14, |x| x.median());
+    }
+
+    #[test]
+    fn test_mode() {
+        test_almost(3.0, 10.0, 0.27777777777777777778, 1e-16, |x| x.mode());
+        test_almost(100.0, 100.0, 0.96078431372549019608, 1e-15, |x| x.mode());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mode_freedom_1_lte_2() {
+        try_create(2.0, 10.0).mode();
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(3.0, 10.0, 0.40412281148856268332, 1e-13, |x| x.pdf(1.0));
+        test_almost(1.0, 1.0, 0.15915494309189533577, 1e-15, |x| x.pdf(1.0));
+        test_almost(2.0, 27.0, 0.59017814883712188331, 1e-13, |x| x.pdf(0.5));
+        test_almost(10.0, 5.0, 0.047816304027858376842, 1e-15, |x| x.pdf(0.1));
+        test_almost(0.5, 0.7, 0.00035909409342428323638, 1e-17, |x| x.pdf(100.0));
+        test_almost(5.0, 20.0, 8.8649141760779814056e-15, 1e-27, |x| x.pdf(1e-10));
+        test_almost(100.0, 100.0, 1.0949817794544397055, 1e-13, |x| x.pdf(1.2));
+        test_case(3.0, 10.0, 0.0, |x| x.pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_pdf_at_zero() {
+        test_case(1.0, 10.0, f64::INFINITY, |x| x.pdf(0.0));
+        test_case(2.0, 10.0, 1.0, |x| x.pdf(0.0));
+        test_case(3.0, 10.0, 0.0, |x| x.pdf(0.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(3.0, 10.0, -0.90603645838315469594, 1e-13, |x| x.ln_pdf(1.0));
+        test_almost(1.0, 1.0, -1.8378770664093454836, 1e-15, |x| x.ln_pdf(1.0));
+        test_almost(0.5, 0.7, -7.9319261051429687464, 1e-14, |x| x.ln_pdf(100.0));
+        test_almost(100.0, 100.0, 0.090737723362386701686, 1e-13, |x| x.ln_pdf(1.2));
+        test_almost(3.0, 10.0, -344.58843268845131545, 1e-12, |x| x.ln_pdf(1e-300));
+        test_case(3.0, 10.0, f64::NEG_INFINITY, |x| x.ln_pdf(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pdf_negative() {
+        try_create(3.0, 10.0).pdf(-1.0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(3.0, 10.0, 0.0, |x| x.min());
+        test_case(3.0, 10.0, f64::INFINITY, |x| x.max());
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let n = try_create(5.0, 20.0);
+        let samples: Vec<f64> = (0..50000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert_almost_eq!(samples.mean(), n.mean(), 0.02);
+        assert_almost_eq!(samples.variance(), n.variance(), 0.05);
+        let below = samples.iter().filter(|&&x| x <= 1.0).count() as f64 / 50000.0;
+        assert_almost_eq!(below, n.cdf(1.0), 0.01);
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(3.0, 10.0, 0.56766279697830293492, 1e-13, |x| x.cdf(1.0));
+        test_almost(1.0, 1.0, 0.5, 1e-15, |x| x.cdf(1.0));
+        test_almost(2.0, 27.0, 0.98409004167437709746, 1e-14, |x| x.cdf(4.846087862380137));
+        test_almost(10.0, 5.0, 0.0012057806486995376189, 1e-17, |x| x.cdf(0.1));
+        test_almost(0.5, 0.7, 0.89676612979441198313, 1e-14, |x| x.cdf(100.0));
+        test_case(3.0, 10.0, 0.0, |x| x.cdf(0.0));
+        test_case(3.0, 10.0, 1.0, |x| x.cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(3.0, 10.0, 0.43233720302169706508, 1e-13, |x| x.sf(1.0));
+        test_almost(2.0, 27.0, 0.015909958325622902537, 1e-14, |x| x.sf(4.846087862380137));
+        test_almost(5.0, 20.0, 1.9050544262134500925e-13, 1e-25, |x| x.sf(100.0));
+        test_case(3.0, 10.0, 1.0, |x| x.sf(0.0));
+        test_case(3.0, 10.0, 0.0, |x| x.sf(f64::INFINITY));
+        test_almost(3.0, 10.0, 0.43233720302169706508f64.ln(), 1e-13, |x| x.ln_sf(1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cdf_negative() {
+        try_create(3.0, 10.0).cdf(-1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(3.0, 10.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(3.0, 10.0, 0.007737142554171553961, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(3.0, 10.0, 0.84508057659171366713, 1e-13, |x| x.inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 12.552745388943725041, 1e-12, |x| x.inverse_cdf(0.999));
+        test_almost(1.0, 1.0, 0.025085630936916601255, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(2.0, 27.0, 4.8460878623801354551, 1e-12, |x| x.inverse_cdf(0.98409004167437709746));
+        test_almost(10.0, 5.0, 3.297401668029934944, 1e-13, |x| x.inverse_cdf(0.9));
+        test_almost(0.5, 0.7, 0.031886741274107826887, 1e-15, |x| x.inverse_cdf(0.25));
+        test_almost(5.0, 20.0, 0.21938814195491560434, 1e-14, |x| x.inverse_cdf(0.05));
+        test_case(3.0, 10.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        try_create(3.0, 10.0).inverse_cdf(1.5);
+    }
+}
@@ -4,6 +4,8 @@ use rand::distributions::{Sample, IndependentSample};
 use error::StatsError;
 use function::gamma;
 use result::Result;
+use statistics::Statistics;
+use prec;
 use super::*;
 
 /// Implements the [Gamma](https://en.wikipedia.org/wiki/Gamma_distribution) distribution
@@ -85,6 +87,64 @@ impl Gamma {
     pub fn rate(&self) -> f64 {
         self.rate
     }
+
+    /// Fits a gamma distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two elements, any element
+    /// is not positive or all of its elements are equal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(α) - ψ(α) = ln(Σ x_i / n) - Σ ln(x_i) / n
+    /// β = α * n / Σ x_i
+    /// ```
+    ///
+    /// where `ψ` is the digamma function and `n` is the number of samples.
+    /// The shape `α` is found using Newton's method starting from the
+    /// approximation of Minka (2002)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let (n, _) = Gamma::fit(&[0.5, 1.2, 2.3, 0.8, 3.1]).unwrap();
+    /// assert!(n.shape() > 0.0);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Gamma, f64)> {
+        if data.len() < 2 || data.iter().any(|&x| x <= 0.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mean = data.mean();
+        let s = mean.ln() - data.iter().fold(0.0, |acc, x| acc + x.ln()) / data.len() as f64;
+        if s <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+
+        let mut shape = (3.0 - s + ((s - 3.0) * (s - 3.0) + 24.0 * s).sqrt()) / (12.0 * s);
+        for _ in 0..internal::MAX_FIT_ITERATIONS {
+            let f = shape.ln() - gamma::digamma(shape) - s;
+            let df = 1.0 / shape - gamma::trigamma(shape);
+            let mut next = shape - f / df;
+            if next <= 0.0 {
+                next = shape / 2.0;
+            }
+            let done = (next - shape).abs() <= prec::F64_PREC * next;
+            shape = next;
+            if done {
+                break;
+            }
+        }
+
+        let dist = Gamma::new(shape, shape / mean)?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Gamma {
@@ -164,6 +224,32 @@ impl Univariate<f64, f64> for Gamma {
         }
     }
 
+    /// Calculates the survival function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(α)) * Γ(α, β * x)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `Γ` is the gamma function
+    /// and upper incomplete gamma function respectively
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x > 0.0, "{}", StatsError::ArgMustBePositive("x"));
+        if x == self.shape && self.rate == f64::INFINITY {
+            0.0
+        } else if self.rate == f64::INFINITY {
+            1.0
+        } else {
+            gamma::gamma_ur(self.shape, x * self.rate)
+        }
+    }
+
     /// Returns the minimum value in the domain of the
     /// gamma distribution representable by a double precision
     /// float
@@ -191,6 +277,35 @@ impl Univariate<f64, f64> for Gamma {
     }
 }
 
+impl InverseCdf<f64, f64> for Gamma {
+    /// Calculates the inverse cumulative distribution function for the
+    /// gamma distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `shape` if `rate == f64::INFINITY`. There is no closed form
+    /// solution so the cumulative distribution function is inverted
+    /// numerically using a bracketed root finder
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        if self.rate == f64::INFINITY {
+            self.shape
+        } else {
+            internal::inverse_cdf(p,
+                                  0.0,
+                                  f64::INFINITY,
+                                  self.shape,
+                                  |x| gamma::gamma_lr(self.shape, x)) / self.rate
+        }
+    }
+}
+
 impl Mean<f64, f64> for Gamma {
     /// Returns the mean of the gamma distribution
     ///
@@ -414,7 +529,7 @@ pub fn sample_unchecked<R: Rng>(r: &mut R, shape: f64, rate: f64) -> f64 {
         if u < 1.0 - 0.0331 * x * x {
             return afix * d * v / rate;
         }
-        if u.ln() < 0.5 * x + d * (1.0 - v - v.ln()) {
+        if u.ln() < 0.5 * x + d * (1.0 - v + v.ln()) {
             return afix * d * v / rate;
         }
     }
@@ -424,6 +539,7 @@ pub fn sample_unchecked<R: Rng>(r: &mut R, shape: f64, rate: f64) -> f64 {
 #[cfg(test)]
 mod test {
     use std::f64;
+    use rand::{SeedableRng, StdRng};
     use distribution::*;
 
     fn try_create(shape: f64, rate: f64) -> Gamma {
@@ -610,4 +726,83 @@ mod test {
     fn test_non_positive_cdf() {
         get_value(1.0, 0.1, |x| x.cdf(0.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 0.001000500333583533521, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(1.0, 1.0, 0.69314718055994530942, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(1.0, 1.0, 6.9077552789821361639, 1e-13, |x| x.inverse_cdf(0.999));
+        test_case(1.0, 1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        test_almost(3.0, 1.0, 0.19053337756840319199, 1e-14, |x| x.inverse_cdf(0.001));
+        test_almost(3.0, 1.0, 1.1020653282493211016, 1e-14, |x| x.inverse_cdf(0.1));
+        test_almost(3.0, 1.0, 2.6740603137235603179, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(3.0, 1.0, 5.3223203378342102255, 1e-13, |x| x.inverse_cdf(0.9));
+        test_almost(3.0, 1.0, 11.22887224241266157, 1e-12, |x| x.inverse_cdf(0.999));
+        test_almost(10.0, 10.0, 0.29605203727437595784, 1e-14, |x| x.inverse_cdf(0.001));
+        test_almost(10.0, 10.0, 0.96687146147141311518, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(10.0, 10.0, 2.2657373309062929325, 1e-13, |x| x.inverse_cdf(0.999));
+        test_almost(0.5, 2.0, 3.9269928731562248618e-7, 1e-19, |x| x.inverse_cdf(0.001));
+        test_almost(0.5, 2.0, 0.0039476935233578066576, 1e-16, |x| x.inverse_cdf(0.1));
+        test_almost(0.5, 2.0, 0.11373410577989318799, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(0.5, 2.0, 2.7068915426656826622, 1e-13, |x| x.inverse_cdf(0.999));
+        test_case(10.0, f64::INFINITY, 10.0, |x| x.inverse_cdf(0.5));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(2.0, 1.0, 9.8366242246159806934e-21, 1e-33, |x| x.sf(50.0));
+        test_almost(1.0, 0.1, (-1.0f64).exp(), 1e-15, |x| x.sf(10.0));
+        test_case(10.0, f64::INFINITY, 0.0, |x| x.sf(10.0));
+        test_case(10.0, f64::INFINITY, 1.0, |x| x.sf(5.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        test_almost(2.0, 1.0, -46.744849040507525584, 1e-12, |x| x.ln_cdf(1e-10));
+        test_almost(1.0, 0.1, (-(-1.0f64).exp_m1()).ln(), 1e-15, |x| x.ln_cdf(10.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(2.0, 1.0, -46.068174367275674228, 1e-12, |x| x.ln_sf(50.0));
+        test_almost(1.0, 0.1, -1.0, 1e-14, |x| x.ln_sf(10.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Gamma::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2]).unwrap();
+        assert_almost_eq!(n.shape(), 2.8198375802654435745, 1e-12);
+        assert_almost_eq!(n.rate(), 1.5079345349013067243, 1e-12);
+        assert_almost_eq!(ln_likelihood, -13.975001202099922229, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Gamma::fit(&[]).is_err());
+        assert!(Gamma::fit(&[1.0]).is_err());
+        assert!(Gamma::fit(&[2.0, 2.0]).is_err());
+        assert!(Gamma::fit(&[0.0, 1.0]).is_err());
+        assert!(Gamma::fit(&[f64::NAN, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        for &(shape, rate) in [(0.5, 2.0), (2.0, 1.0), (10.0, 0.5)].iter() {
+            let n = try_create(shape, rate);
+            let count = 50000;
+            let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            let mean = samples.iter().sum::<f64>() / count as f64;
+            let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count as f64;
+            assert_almost_eq!(mean / n.mean(), 1.0, 0.02);
+            assert_almost_eq!(variance / n.variance(), 1.0, 0.05);
+            for &p in [0.1, 0.5, 0.9].iter() {
+                let x = n.inverse_cdf(p);
+                let below = samples.iter().filter(|&&s| s <= x).count() as f64 / count as f64;
+                assert_almost_eq!(below, p, 0.01);
+            }
+        }
+    }
 }
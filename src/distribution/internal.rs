@@ -0,0 +1,393 @@
+//! the `internal` module contains package-private functionality
+//! for the `distribution` module
+
+use std::f64;
+use prec;
+
+/// Maximum number of times a bracket is expanded or refined
+/// before a root search is abandoned
+const MAX_ITERATIONS: usize = 1100;
+
+/// Maximum number of Newton iterations used when fitting distribution
+/// parameters by maximum likelihood
+pub const MAX_FIT_ITERATIONS: usize = 100;
+
+/// Numerically inverts a monotonically non-decreasing `cdf` at the
+/// probability `p` for a distribution with the domain `[min, max]`.
+/// The root is bracketed by stepping outwards from `guess` with doubling
+/// step sizes and then refined using Brent's method.
+///
+/// # Remarks
+///
+/// `cdf` is never evaluated at a finite `min` or `max`, the cumulative
+/// distribution there is assumed to be `0` and `1` respectively.
+/// Returns `min` if `p == 0.0`, `max` if `p == 1.0` and `f64::NAN`
+/// if no bracket could be found.
+pub fn inverse_cdf<F>(p: f64, min: f64, max: f64, guess: f64, cdf: F) -> f64
+    where F: Fn(f64) -> f64
+{
+    if p == 0.0 {
+        return min;
+    }
+    if p == 1.0 {
+        return max;
+    }
+
+    let f = |x: f64| cdf(x) - p;
+    let guess = if guess > min && guess < max {
+        guess
+    } else if min.is_finite() && max.is_finite() {
+        min + (max - min) / 2.0
+    } else if min.is_finite() {
+        min + 1.0
+    } else if max.is_finite() {
+        max - 1.0
+    } else {
+        0.0
+    };
+
+    let mut step = guess.abs().max(1.0);
+    let f_guess = f(guess);
+    let (lower, f_lower, upper, f_upper) = if f_guess < 0.0 {
+        // root lies above the guess
+        let mut lower = guess;
+        let mut f_lower = f_guess;
+        let mut i = 0;
+        loop {
+            let upper = guess + step;
+            if upper >= max && max.is_finite() {
+                break (lower, f_lower, max, 1.0 - p);
+            }
+            let f_upper = f(upper);
+            if f_upper >= 0.0 {
+                break (lower, f_lower, upper, f_upper);
+            }
+            i += 1;
+            if i > MAX_ITERATIONS || !upper.is_finite() {
+                return f64::NAN;
+            }
+            lower = upper;
+            f_lower = f_upper;
+            step *= 2.0;
+        }
+    } else {
+        // root lies at or below the guess
+        let mut upper = guess;
+        let mut f_upper = f_guess;
+        let mut i = 0;
+        loop {
+            let lower = guess - step;
+            if lower <= min && min.is_finite() {
+                break (min, -p, upper, f_upper);
+            }
+            let f_lower = f(lower);
+            if f_lower < 0.0 {
+                break (lower, f_lower, upper, f_upper);
+            }
+            i += 1;
+            if i > MAX_ITERATIONS || !lower.is_finite() {
+                return f64::NAN;
+            }
+            upper = lower;
+            f_upper = f_lower;
+            step *= 2.0;
+        }
+    };
+    brent(f, lower, f_lower, upper, f_upper)
+}
+
+/// Numerically inverts the cumulative distribution function `cdf` of a
+/// discrete distribution with the integral domain `[min, max]` at the
+/// probability `p`, returning the smallest `k` such that `cdf(k) >= p`.
+/// The search starts at the rounded `guess` and gallops away from it with
+/// doubling step sizes until the answer is bracketed, after which the
+/// bracket is bisected.
+///
+/// # Remarks
+///
+/// `cdf` is never evaluated at `max`, the cumulative distribution there
+/// is assumed to be `1`. Returns `min` if `p == 0.0`. If `p == 1.0` the
+/// result is the smallest `k` with `cdf(k) == 1`, which may be below `max`
+pub fn integral_inverse_cdf<F>(p: f64, min: i64, max: i64, guess: f64, cdf: F) -> i64
+    where F: Fn(i64) -> f64
+{
+    if p == 0.0 {
+        return min;
+    }
+
+    let start = if guess.is_nan() || guess <= min as f64 {
+        min
+    } else if guess >= max as f64 {
+        max
+    } else {
+        guess.round() as i64
+    };
+
+    // invariant: cdf(lower) < p <= cdf(upper)
+    let mut step = 1i64;
+    let (mut lower, mut upper) = if start < max && cdf(start) < p {
+        let mut lower = start;
+        loop {
+            let upper = lower.saturating_add(step).min(max);
+            if upper == max || cdf(upper) >= p {
+                break (lower, upper);
+            }
+            lower = upper;
+            step = step.saturating_mul(2);
+        }
+    } else {
+        let mut upper = start;
+        loop {
+            if upper == min {
+                return min;
+            }
+            let lower = upper.saturating_sub(step).max(min);
+            if cdf(lower) < p {
+                break (lower, upper);
+            }
+            upper = lower;
+            step = step.saturating_mul(2);
+        }
+    };
+
+    while upper - lower > 1 {
+        let mid = lower + (upper - lower) / 2;
+        if cdf(mid) >= p {
+            upper = mid;
+        } else {
+            lower = mid;
+        }
+    }
+    upper
+}
+
+/// Approximates the quantile of a distribution with the given `mean`,
+/// `std_dev` and `skewness` at the probability `p` using the
+/// Cornish-Fisher expansion of the normal approximation. Intended as a
+/// starting point for an exact search.
+///
+/// # Remarks
+///
+/// Returns `mean` if the expansion is not finite e.g. for a degenerate
+/// distribution with a standard deviation of `0`
+pub fn normal_approx_quantile(p: f64, mean: f64, std_dev: f64, skewness: f64) -> f64 {
+    let z = super::normal::inverse_cdf_unchecked(p, 0.0, 1.0);
+    let approx = mean + std_dev * (z + skewness * (z * z - 1.0) / 6.0);
+    if approx.is_finite() { approx } else { mean }
+}
+
+/// Computes the Cholesky factorisation `A = L * L^T` of the symmetric
+/// `n × n` matrix `a` stored in row-major order, returning the lower
+/// triangular factor `L` in row-major order. Only the lower triangle of
+/// `a` is read.
+///
+/// # Remarks
+///
+/// Returns `None` if `a` is not positive definite
+pub fn cholesky(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut l = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..i + 1 {
+            let mut sum = a[i * n + j];
+            for k in 0..j {
+                sum -= l[i * n + k] * l[j * n + k];
+            }
+            if i == j {
+                if sum <= 0.0 || sum.is_nan() {
+                    return None;
+                }
+                l[i * n + i] = sum.sqrt();
+            } else {
+                l[i * n + j] = sum / l[j * n + j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Solves `L * x = b` for `x` where `l` is an `n × n` lower triangular
+/// matrix stored in row-major order
+pub fn forward_substitution(l: &[f64], n: usize, b: &[f64]) -> Vec<f64> {
+    let mut x = vec![0.0; n];
+    for i in 0..n {
+        let sum = (0..i).fold(b[i], |acc, k| acc - l[i * n + k] * x[k]);
+        x[i] = sum / l[i * n + i];
+    }
+    x
+}
+
+/// Solves `L^T * x = b` for `x` where `l` is an `n × n` lower triangular
+/// matrix stored in row-major order
+pub fn backward_substitution(l: &[f64], n: usize, b: &[f64]) -> Vec<f64> {
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum = (i + 1..n).fold(b[i], |acc, k| acc - l[k * n + i] * x[k]);
+        x[i] = sum / l[i * n + i];
+    }
+    x
+}
+
+/// Finds a root of `f` within the interval `[a, b]` using Brent's method
+/// where `fa` and `fb` are `f` evaluated at `a` and `b` respectively and
+/// are assumed to bracket the root. Implementation adapted from
+/// "Numerical Recipes in C", 2nd edition, section 9.3
+fn brent<F>(f: F, a: f64, fa: f64, b: f64, fb: f64) -> f64
+    where F: Fn(f64) -> f64
+{
+    let mut a = a;
+    let mut b = b;
+    let mut fa = fa;
+    let mut fb = fb;
+    let mut c = b;
+    let mut fc = fb;
+    let mut d = b - a;
+    let mut e = d;
+
+    for _ in 0..MAX_ITERATIONS {
+        if (fb > 0.0 && fc > 0.0) || (fb < 0.0 && fc < 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol = 2.0 * prec::F64_PREC * b.abs() + 0.5 * f64::MIN_POSITIVE;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol || fb == 0.0 {
+            return b;
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // attempt inverse quadratic interpolation
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                 (q - 1.0) * (r - 1.0) * (s - 1.0))
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0 * p < (3.0 * xm * q - (tol * q).abs()).min((e * q).abs()) {
+                // accept interpolation
+                e = d;
+                d = p / q;
+            } else {
+                // interpolation failed, use bisection
+                d = xm;
+                e = d;
+            }
+        } else {
+            // bounds decreasing too slowly, use bisection
+            d = xm;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol {
+            d
+        } else if xm > 0.0 {
+            tol
+        } else {
+            -tol
+        };
+        fb = f(b);
+    }
+    b
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use std::i64;
+
+    #[test]
+    fn test_inverse_cdf_unbounded() {
+        let cdf = |x: f64| 1.0 / (1.0 + (-x).exp());
+        let inv = |p: f64| (p / (1.0 - p)).ln();
+        for &p in &[1e-10, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999] {
+            assert_almost_eq!(super::inverse_cdf(p, f64::NEG_INFINITY, f64::INFINITY, 0.0, &cdf), inv(p), 1e-9);
+            assert_almost_eq!(super::inverse_cdf(p, f64::NEG_INFINITY, f64::INFINITY, 100.0, &cdf), inv(p), 1e-9);
+        }
+        assert_eq!(super::inverse_cdf(0.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, &cdf), f64::NEG_INFINITY);
+        assert_eq!(super::inverse_cdf(1.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, &cdf), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_cdf_bounded() {
+        let cdf = |x: f64| x * x;
+        for &p in &[1e-300, 1e-20, 0.01, 0.25, 0.5, 0.75, 0.99] {
+            assert_almost_eq!(super::inverse_cdf(p, 0.0, 1.0, 0.5, &cdf), p.sqrt(), 1e-15);
+        }
+        assert_eq!(super::inverse_cdf(0.0, 0.0, 1.0, 0.5, &cdf), 0.0);
+        assert_eq!(super::inverse_cdf(1.0, 0.0, 1.0, 0.5, &cdf), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_no_bracket() {
+        assert!(super::inverse_cdf(0.5, 0.0, f64::INFINITY, 1.0, |_| 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_integral_inverse_cdf() {
+        let cdf = |k: i64| if k < 0 { 0.0 } else if k >= 100 { 1.0 } else { (k + 1) as f64 / 101.0 };
+        for &guess in &[-1000.0, 0.0, 17.0, 50.0, 99.0, 1000.0, f64::NAN] {
+            assert_eq!(super::integral_inverse_cdf(0.0, 0, 100, guess, &cdf), 0);
+            assert_eq!(super::integral_inverse_cdf(1.0 / 101.0, 0, 100, guess, &cdf), 0);
+            assert_eq!(super::integral_inverse_cdf(0.01, 0, 100, guess, &cdf), 1);
+            assert_eq!(super::integral_inverse_cdf(0.5, 0, 100, guess, &cdf), 50);
+            assert_eq!(super::integral_inverse_cdf(0.995, 0, 100, guess, &cdf), 100);
+            assert_eq!(super::integral_inverse_cdf(1.0, 0, 100, guess, &cdf), 100);
+            assert_eq!(super::integral_inverse_cdf(1.0, 0, 100, guess, |k| if k < 0 { 0.0 } else { 1.0 }), 0);
+            assert_eq!(super::integral_inverse_cdf(1.0, 0, 100, guess, |k| if k < 40 { 0.5 } else { 1.0 }), 40);
+        }
+    }
+
+    #[test]
+    fn test_integral_inverse_cdf_unbounded() {
+        let cdf = |k: i64| 1.0 - 0.5f64.powi(k as i32 + 1);
+        assert_eq!(super::integral_inverse_cdf(0.5, 0, i64::MAX, 1e6, &cdf), 0);
+        assert_eq!(super::integral_inverse_cdf(0.75, 0, i64::MAX, 0.0, &cdf), 1);
+        assert_eq!(super::integral_inverse_cdf(1.0 - 1e-9, 0, i64::MAX, 0.0, &cdf), 29);
+        assert_eq!(super::integral_inverse_cdf(1.0, 0, i64::MAX, 0.0, &cdf), 53);
+    }
+
+    #[test]
+    fn test_normal_approx_quantile() {
+        assert_eq!(super::normal_approx_quantile(0.5, 3.0, 2.0, 0.0), 3.0);
+        assert_almost_eq!(super::normal_approx_quantile(0.975, 3.0, 2.0, 0.0), 3.0 + 2.0 * 1.9599639845400542355, 1e-14);
+        assert_eq!(super::normal_approx_quantile(0.3, 3.0, 0.0, f64::INFINITY), 3.0);
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let a = [4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0];
+        let l = super::cholesky(&a, 3).unwrap();
+        assert_eq!(l, vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+
+        assert!(super::cholesky(&[1.0, 2.0, 2.0, 1.0], 2).is_none());
+        assert!(super::cholesky(&[0.0], 1).is_none());
+        assert!(super::cholesky(&[f64::NAN], 1).is_none());
+    }
+
+    #[test]
+    fn test_substitution() {
+        let l = [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+        assert_eq!(super::forward_substitution(&l, 3, &[2.0, 7.0, 0.0]), vec![1.0, 1.0, 1.0]);
+        assert_eq!(super::backward_substitution(&l, 3, &[0.0, 6.0, 3.0]), vec![1.0, 1.0, 1.0]);
+    }
+}
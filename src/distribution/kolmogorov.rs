@@ -0,0 +1,422 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
+use super::*;
+
+/// Maximum number of series terms evaluated before giving up on convergence
+const MAX_TERMS: usize = 100;
+
+/// Implements the [Kolmogorov](https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test#Kolmogorov_distribution)
+/// distribution i.e. the limiting distribution of `sqrt(n) * D_n` where `D_n`
+/// is the Kolmogorov-Smirnov statistic of a sample of size `n`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Kolmogorov, Univariate};
+/// use statrs::prec;
+///
+/// let n = Kolmogorov;
+/// assert!(prec::almost_eq(n.cdf(1.0), 0.7300003283226454788, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Kolmogorov;
+
+impl Sample<f64> for Kolmogorov {
+    /// Generate a random sample from a kolmogorov
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<f64> for Kolmogorov {
+    /// Generate a random independent sample from a kolmogorov
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> f64 {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<f64> for Kolmogorov {
+    /// Generate a random sample from the kolmogorov distribution
+    /// using `r` as the source of randomness by inverting the
+    /// cumulative distribution function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{Kolmogorov, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = Kolmogorov;
+    /// print!("{}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> f64 {
+        self.inverse_cdf(r.next_f64())
+    }
+}
+
+impl Univariate<f64, f64> for Kolmogorov {
+    /// Calculates the cumulative distribution function for the
+    /// kolmogorov distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x <= 0.0`. The first series converges quickly for
+    /// `x < 1` and the second for `x >= 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2π) / x * Σ e^(-(2k - 1)^2 * π^2 / (8 * x^2))
+    /// 1 - 2 * Σ (-1)^(k - 1) * e^(-2 * k^2 * x^2)
+    /// ```
+    ///
+    /// where both sums run over `k = 1, 2, ...`
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x < 1.0 {
+            cdf_small(x)
+        } else {
+            1.0 - sf_large(x)
+        }
+    }
+
+    /// Calculates the survival function for the kolmogorov
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `1.0` if `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * Σ (-1)^(k - 1) * e^(-2 * k^2 * x^2)
+    /// ```
+    ///
+    /// where the sum runs over `k = 1, 2, ...`
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x < 1.0 {
+            1.0 - cdf_small(x)
+        } else {
+            sf_large(x)
+        }
+    }
+
+    /// Returns the minimum value in the domain of the
+    /// kolmogorov distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the maximum value in the domain of the
+    /// kolmogorov distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl InverseCdf<f64, f64> for Kolmogorov {
+    /// Calculates the inverse cumulative distribution function for the
+    /// kolmogorov distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form, the root of `cdf(x) - p` is found
+    /// numerically starting from the median
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        internal::inverse_cdf(p, 0.0, f64::INFINITY, self.median(), |x| self.cdf(x))
+    }
+}
+
+impl Mean<f64, f64> for Kolmogorov {
+    /// Returns the mean of the kolmogorov distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(π / 2) * ln(2)
+    /// ```
+    fn mean(&self) -> f64 {
+        (f64::consts::PI / 2.0).sqrt() * f64::consts::LN_2
+    }
+}
+
+impl Variance<f64, f64> for Kolmogorov {
+    /// Returns the variance of the kolmogorov distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// π^2 / 12 - π * ln(2)^2 / 2
+    /// ```
+    fn variance(&self) -> f64 {
+        let mean = self.mean();
+        f64::consts::PI * f64::consts::PI / 12.0 - mean * mean
+    }
+
+    /// Returns the standard deviation of the kolmogorov distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(π^2 / 12 - π * ln(2)^2 / 2)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Median<f64> for Kolmogorov {
+    /// Returns the median of the kolmogorov distribution
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form, the value is the numerically
+    /// evaluated root of `cdf(x) - 0.5`
+    fn median(&self) -> f64 {
+        0.82757355518990769011
+    }
+}
+
+impl Mode<f64, f64> for Kolmogorov {
+    /// Returns the mode of the kolmogorov distribution
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form, the value is the numerically
+    /// evaluated maximum of the probability density function
+    fn mode(&self) -> f64 {
+        0.73546790791657198206
+    }
+}
+
+impl Continuous<f64, f64> for Kolmogorov {
+    /// Calculates the probability density function for the kolmogorov
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2π) * Σ e^(-a_k / x^2) * (2 * a_k / x^4 - 1 / x^2)
+    /// 8 * x * Σ (-1)^(k - 1) * k^2 * e^(-2 * k^2 * x^2)
+    /// ```
+    ///
+    /// where `a_k = (2k - 1)^2 * π^2 / 8` and both sums run over
+    /// `k = 1, 2, ...`. The first series is used for `x < 1` and the
+    /// second for `x >= 1`
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x < 1.0 {
+            let x2 = x * x;
+            let sum = sum_series(|k| {
+                let odd = (2 * k - 1) as f64;
+                let a = odd * odd * f64::consts::PI * f64::consts::PI / 8.0;
+                (-a / x2).exp() * (2.0 * a / (x2 * x2) - 1.0 / x2)
+            });
+            (2.0 * f64::consts::PI).sqrt() * sum
+        } else {
+            let sum = sum_series(|k| {
+                let k = k as f64;
+                let sign = if k % 2.0 == 0.0 { -1.0 } else { 1.0 };
+                sign * k * k * (-2.0 * k * k * x * x).exp()
+            });
+            8.0 * x * sum
+        }
+    }
+
+    /// Calculates the log probability density function for the kolmogorov
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(pdf(x))
+    /// ```
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Evaluates the cumulative distribution function using the
+/// Jacobi theta representation that converges quickly for small `x`
+fn cdf_small(x: f64) -> f64 {
+    let sum = sum_series(|k| {
+        let odd = (2 * k - 1) as f64;
+        (-odd * odd * f64::consts::PI * f64::consts::PI / (8.0 * x * x)).exp()
+    });
+    (2.0 * f64::consts::PI).sqrt() / x * sum
+}
+
+/// Evaluates the survival function using the alternating
+/// series that converges quickly for large `x`
+fn sf_large(x: f64) -> f64 {
+    let sum = sum_series(|k| {
+        let k = k as f64;
+        let sign = if k % 2.0 == 0.0 { -1.0 } else { 1.0 };
+        sign * (-2.0 * k * k * x * x).exp()
+    });
+    2.0 * sum
+}
+
+/// Sums the terms `term(1), term(2), ...` until they no longer
+/// contribute to the sum at double precision
+fn sum_series<F>(term: F) -> f64
+    where F: Fn(usize) -> f64
+{
+    let mut sum = 0.0;
+    for k in 1..MAX_TERMS {
+        let t = term(k);
+        sum += t;
+        if t.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use distribution::*;
+
+    #[test]
+    fn test_mean() {
+        assert_almost_eq!(Kolmogorov.mean(), 0.86873116063615914183, 1e-15);
+    }
+
+    #[test]
+    fn test_variance() {
+        assert_almost_eq!(Kolmogorov.variance(), 0.067773203963865079378, 1e-15);
+        assert_almost_eq!(Kolmogorov.std_dev(), 0.067773203963865079378f64.sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_almost_eq!(Kolmogorov.cdf(Kolmogorov.median()), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = Kolmogorov.mode();
+        assert!(Kolmogorov.pdf(mode) > Kolmogorov.pdf(mode - 1e-6));
+        assert!(Kolmogorov.pdf(mode) > Kolmogorov.pdf(mode + 1e-6));
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(Kolmogorov.min(), 0.0);
+        assert_eq!(Kolmogorov.max(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_pdf() {
+        assert_eq!(Kolmogorov.pdf(0.0), 0.0);
+        assert_almost_eq!(Kolmogorov.pdf(0.1), 1.6241713974329762202e-49, 1e-62);
+        assert_almost_eq!(Kolmogorov.pdf(0.3), 0.00081939341969313027874, 1e-16);
+        assert_almost_eq!(Kolmogorov.pdf(0.5), 0.63958285094045663465, 1e-14);
+        assert_almost_eq!(Kolmogorov.pdf(0.8), 1.6270243456365923177, 1e-14);
+        assert_almost_eq!(Kolmogorov.pdf(1.0), 1.0719485583569417625, 1e-14);
+        assert_almost_eq!(Kolmogorov.pdf(1.5), 0.13330722741988021004, 1e-15);
+        assert_almost_eq!(Kolmogorov.pdf(3.0), 3.6551951387310308247e-7, 1e-20);
+        assert_almost_eq!(Kolmogorov.pdf(5.0), 7.7149993918556711321e-21, 1e-34);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        assert_eq!(Kolmogorov.ln_pdf(0.0), f64::NEG_INFINITY);
+        assert_almost_eq!(Kolmogorov.ln_pdf(0.1), -112.3416717802464955, 1e-12);
+        assert_almost_eq!(Kolmogorov.ln_pdf(1.0), 1.0719485583569417625f64.ln(), 1e-14);
+    }
+
+    #[test]
+    fn test_cdf() {
+        assert_eq!(Kolmogorov.cdf(-1.0), 0.0);
+        assert_eq!(Kolmogorov.cdf(0.0), 0.0);
+        assert_almost_eq!(Kolmogorov.cdf(0.1), 6.6093052422454707514e-53, 1e-65);
+        assert_almost_eq!(Kolmogorov.cdf(0.3), 9.3058013345666319427e-6, 1e-19);
+        assert_almost_eq!(Kolmogorov.cdf(0.5), 0.036054756335124905614, 1e-15);
+        assert_almost_eq!(Kolmogorov.cdf(0.8), 0.455857588425801851, 1e-15);
+        assert_almost_eq!(Kolmogorov.cdf(1.0), 0.7300003283226454788, 1e-15);
+        assert_almost_eq!(Kolmogorov.cdf(1.5), 0.97778203738347487128, 1e-15);
+        assert_almost_eq!(Kolmogorov.cdf(2.0), 0.99932907474422030465, 1e-15);
+        assert_eq!(Kolmogorov.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_sf() {
+        assert_eq!(Kolmogorov.sf(0.0), 1.0);
+        assert_almost_eq!(Kolmogorov.sf(0.5), 0.96394524366487509439, 1e-15);
+        assert_almost_eq!(Kolmogorov.sf(0.8), 0.544142411574198149, 1e-15);
+        assert_almost_eq!(Kolmogorov.sf(1.0), 0.2699996716773545212, 1e-15);
+        assert_almost_eq!(Kolmogorov.sf(1.5), 0.022217962616525128721, 1e-16);
+        assert_almost_eq!(Kolmogorov.sf(2.0), 0.00067092525577969534654, 1e-18);
+        assert_almost_eq!(Kolmogorov.sf(3.0), 3.0459959489425256872e-8, 1e-22);
+        assert_almost_eq!(Kolmogorov.sf(5.0), 3.857499695927835566e-22, 1e-36);
+        assert_eq!(Kolmogorov.sf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_ln_cdf_ln_sf() {
+        assert_almost_eq!(Kolmogorov.ln_cdf(0.1), -120.14853138741826431, 1e-12);
+        assert_almost_eq!(Kolmogorov.ln_sf(5.0), -49.306852819440054691, 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        assert_eq!(Kolmogorov.inverse_cdf(0.0), 0.0);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.01), 0.44102769851792936842, 1e-14);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.1), 0.57117326510634016319, 1e-14);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.5), 0.82757355518990769011, 1e-14);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.9), 1.2238478702170823883, 1e-14);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.99), 1.6276236115189503465, 1e-14);
+        assert_almost_eq!(Kolmogorov.inverse_cdf(0.999999), 2.693386134452709643, 1e-9);
+        assert_eq!(Kolmogorov.inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_p_gt_1() {
+        Kolmogorov.inverse_cdf(1.5);
+    }
+}
@@ -5,6 +5,7 @@ use consts;
 use error::StatsError;
 use function::erf;
 use result::Result;
+use statistics::Statistics;
 use super::*;
 
 /// Implements the [Log-normal](https://en.wikipedia.org/wiki/Log-normal_distribution)
@@ -56,6 +57,43 @@ impl LogNormal {
             })
         }
     }
+
+    /// Fits a log-normal distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two elements, any element
+    /// is not positive or all of its elements are equal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = Σ ln(x_i) / n
+    /// σ = sqrt(Σ (ln(x_i) - μ)^2 / n)
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{LogNormal, Median};
+    /// use statrs::prec;
+    ///
+    /// let (n, _) = LogNormal::fit(&[1.0, 1.0f64.exp(), 2.0f64.exp()]).unwrap();
+    /// assert!(prec::almost_eq(n.median(), 1.0f64.exp(), 1e-15));
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(LogNormal, f64)> {
+        if data.len() < 2 || data.iter().any(|&x| x <= 0.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let ln_data: Vec<f64> = data.iter().map(|x| x.ln()).collect();
+        let dist = LogNormal::new(ln_data.mean(), ln_data.population_std_dev())?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for LogNormal {
@@ -121,6 +159,67 @@ impl Univariate<f64, f64> for LogNormal {
         0.5 * erf::erfc((self.location - x.ln()) / (self.scale * f64::consts::SQRT_2))
     }
 
+    /// Calculates the survival function for the log-normal
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((ln(x) - μ) / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc` is the
+    /// complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x > 0.0, "{}", StatsError::ArgMustBePositive("x"));
+        0.5 * erf::erfc((x.ln() - self.location) / (self.scale * f64::consts::SQRT_2))
+    }
+
+    /// Calculates the natural logarithm of the cumulative distribution
+    /// function for the log-normal distribution at `x`, without underflow
+    /// in the lower tail
+    ///
+    /// # Panics
+    ///
+    /// If `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((μ - ln(x)) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc` is the
+    /// complementary error function
+    fn ln_cdf(&self, x: f64) -> f64 {
+        assert!(x > 0.0, "{}", StatsError::ArgMustBePositive("x"));
+        super::normal::ln_cdf_unchecked(x.ln(), self.location, self.scale)
+    }
+
+    /// Calculates the natural logarithm of the survival function for the
+    /// log-normal distribution at `x`, without underflow in the upper tail
+    ///
+    /// # Panics
+    ///
+    /// If `x <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((ln(x) - μ) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc` is the
+    /// complementary error function
+    fn ln_sf(&self, x: f64) -> f64 {
+        assert!(x > 0.0, "{}", StatsError::ArgMustBePositive("x"));
+        super::normal::ln_sf_unchecked(x.ln(), self.location, self.scale)
+    }
+
     /// Returns the minimum value in the domain of the log-normal
     /// distribution representable by a double precision float
     ///
@@ -146,6 +245,30 @@ impl Univariate<f64, f64> for LogNormal {
     }
 }
 
+impl InverseCdf<f64, f64> for LogNormal {
+    /// Calculates the inverse cumulative distribution function for the
+    /// log-normal distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ - sqrt(2) * σ * erfc_inv(2p))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc_inv` is
+    /// the inverse complementary error function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        super::normal::inverse_cdf_unchecked(p, self.location, self.scale).exp()
+    }
+}
+
 impl Mean<f64, f64> for LogNormal {
     /// Returns the mean of the log-normal distribution
     ///
@@ -639,4 +762,59 @@ mod test {
     fn test_neg_cdf() {
         get_value(0.0, 1.0, |x| x.cdf(0.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 0.5, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(1.0, 0.5, 0.11296576721103203905, 1e-14, |x| x.inverse_cdf(1e-10));
+        test_almost(1.0, 0.5, 0.57977443683986099742, 1e-14, |x| x.inverse_cdf(0.001));
+        test_almost(1.0, 0.5, 1.0202197117024205754, 1e-14, |x| x.inverse_cdf(0.025));
+        test_almost(1.0, 0.5, 2.7182818284590452354, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(1.0, 0.5, 7.2426125609753977247, 1e-13, |x| x.inverse_cdf(0.975));
+        test_almost(1.0, 0.5, 12.744708337272854113, 1e-12, |x| x.inverse_cdf(0.999));
+        test_case(1.0, 0.5, f64::INFINITY, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(0.0, 1.0, 0.5, 1e-15, |x| x.sf(1.0));
+        test_almost(0.0, 1.0, 6.2209605742717841235e-16, 1e-25, |x| x.sf(8f64.exp()));
+        test_case(0.0, 1.0, 0.0, |x| x.sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(0.0, 1.0, 0.5f64.ln(), 1e-15, |x| x.ln_sf(1.0));
+        test_almost(0.0, 1.0, -35.013437159914549896, 1e-13, |x| x.ln_sf(8f64.exp()));
+        test_almost(0.0, 1.0, -1254.8313611394199013, 1e-10, |x| x.ln_sf(50f64.exp()));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        test_almost(0.0, 1.0, 0.5f64.ln(), 1e-15, |x| x.ln_cdf(1.0));
+        test_almost(0.0, 1.0, -5005.5242086942050886, 1e-9, |x| x.ln_cdf((-100f64).exp()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sf_x_0() {
+        get_value(0.0, 1.0, |x| x.sf(0.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = LogNormal::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2]).unwrap();
+        assert_almost_eq!(n.location, 0.43826780016883201386, 1e-14);
+        assert_almost_eq!(n.scale, 0.6320489987489122312, 1e-14);
+        assert_almost_eq!(ln_likelihood, -13.984179751989562674, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(LogNormal::fit(&[]).is_err());
+        assert!(LogNormal::fit(&[1.0]).is_err());
+        assert!(LogNormal::fit(&[2.0, 2.0]).is_err());
+        assert!(LogNormal::fit(&[0.0, 1.0]).is_err());
+        assert!(LogNormal::fit(&[-1.0, 1.0]).is_err());
+    }
 }
@@ -8,10 +8,17 @@ pub use self::beta::Beta;
 pub use self::binomial::Binomial;
 pub use self::chi::Chi;
 pub use self::chi_squared::ChiSquared;
+pub use self::dirichlet::Dirichlet;
 pub use self::discrete_uniform::DiscreteUniform;
+pub use self::empirical::Empirical;
 pub use self::exponential::Exponential;
+pub use self::fisher_snedecor::FisherSnedecor;
 pub use self::gamma::Gamma;
+pub use self::kernel_density::{Bandwidth, Kernel, KernelDensity};
+pub use self::kolmogorov::Kolmogorov;
 pub use self::log_normal::LogNormal;
+pub use self::multinomial::Multinomial;
+pub use self::multivariate_normal::MultivariateNormal;
 pub use self::normal::Normal;
 pub use self::poisson::Poisson;
 pub use self::students_t::StudentsT;
@@ -24,10 +31,18 @@ mod beta;
 mod binomial;
 mod chi;
 mod chi_squared;
+mod dirichlet;
 mod discrete_uniform;
+mod empirical;
 mod exponential;
+mod fisher_snedecor;
 mod gamma;
+mod internal;
+mod kernel_density;
+mod kolmogorov;
 mod log_normal;
+mod multinomial;
+mod multivariate_normal;
 mod normal;
 mod poisson;
 mod students_t;
@@ -82,6 +97,61 @@ pub trait Univariate<T, K>: Distribution<K> {
     /// ```
     fn cdf(&self, x: K) -> K;
 
+    /// Returns the survival function calculated at `x` for a given
+    /// distribution i.e. `1 - cdf(x)`. Implementors should override the
+    /// default `1 - cdf(x)` to avoid the loss of precision in the upper
+    /// tail. May panic depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.25, n.sf(0.75));
+    /// ```
+    fn sf(&self, x: K) -> K
+        where K: From<f64> + Into<f64>
+    {
+        K::from(1.0 - self.cdf(x).into())
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution
+    /// function calculated at `x` for a given distribution. Defaults to
+    /// `ln(cdf(x))`. May panic depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5f64.ln(), n.ln_cdf(0.5));
+    /// ```
+    fn ln_cdf(&self, x: K) -> K
+        where K: From<f64> + Into<f64>
+    {
+        K::from(self.cdf(x).into().ln())
+    }
+
+    /// Returns the natural logarithm of the survival function calculated
+    /// at `x` for a given distribution. Defaults to `ln(sf(x))`. May panic
+    /// depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.25f64.ln(), n.ln_sf(0.75));
+    /// ```
+    fn ln_sf(&self, x: K) -> K
+        where K: From<f64> + Into<f64>
+    {
+        K::from(self.sf(x).into().ln())
+    }
+
     /// Returns the minimum value in the domain of a given distribution
     /// representable by a double-precision float. May panic depending on
     /// the implementor.
@@ -111,6 +181,25 @@ pub trait Univariate<T, K>: Distribution<K> {
     fn max(&self) -> T;
 }
 
+/// The `InverseCdf` trait is used to specify an interface for univariate
+/// distributions that can evaluate their quantile function e.g. the
+/// inverse of their cumulative distribution function
+pub trait InverseCdf<T, K>: Univariate<T, K> {
+    /// Returns the inverse cumulative distribution function calculated
+    /// at the probability `p` for a given distribution i.e. the smallest
+    /// `x` such that `cdf(x) >= p`. May panic depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{InverseCdf, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5, n.inverse_cdf(0.5));
+    /// ```
+    fn inverse_cdf(&self, p: K) -> T;
+}
+
 /// The `Mean` trait specifies a distribution that has a closed form
 /// solution for its mean(s)
 pub trait Mean<T, K>: Distribution<K> {
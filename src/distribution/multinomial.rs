@@ -0,0 +1,342 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use error::StatsError;
+use function::factorial;
+use result::Result;
+use super::*;
+
+/// Implements the [Multinomial](https://en.wikipedia.org/wiki/Multinomial_distribution)
+/// distribution which is a generalization of the [Binomial](https://en.wikipedia.org/wiki/Binomial_distribution)
+/// distribution to more than two categories
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Multinomial, Mean};
+///
+/// let n = Multinomial::new(&[0.2, 0.3, 0.5], 10).unwrap();
+/// assert_eq!(n.mean(), vec![2.0, 3.0, 5.0]);
+/// assert_eq!(n.pmf(&[0, 0, 10]), 0.0009765625);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Multinomial {
+    p: Vec<f64>,
+    n: i64,
+}
+
+impl Multinomial {
+    /// Constructs a new multinomial distribution of `n` trials with the
+    /// category probabilities `p`. The probabilities are normalized to
+    /// sum to `1`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` has fewer than two elements, any element of
+    /// `p` is `NaN`, infinite or negative, `p` sums to zero or `n < 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Multinomial;
+    ///
+    /// let mut result = Multinomial::new(&[0.2, 0.3, 0.5], 10);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Multinomial::new(&[0.5, -0.5], 10);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(p: &[f64], n: i64) -> Result<Multinomial> {
+        if p.len() < 2 || n < 0 || p.iter().any(|&x| x < 0.0 || !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let sum: f64 = p.iter().sum();
+        if sum <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Multinomial {
+            p: p.iter().map(|x| x / sum).collect(),
+            n: n,
+        })
+    }
+
+    /// Returns the normalized category probabilities of the multinomial
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Multinomial;
+    ///
+    /// let n = Multinomial::new(&[1.0, 3.0], 10).unwrap();
+    /// assert_eq!(n.p(), &[0.25, 0.75]);
+    /// ```
+    pub fn p(&self) -> &[f64] {
+        &self.p
+    }
+
+    /// Returns the number of trials of the multinomial distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Multinomial;
+    ///
+    /// let n = Multinomial::new(&[0.2, 0.3, 0.5], 10).unwrap();
+    /// assert_eq!(n.n(), 10);
+    /// ```
+    pub fn n(&self) -> i64 {
+        self.n
+    }
+
+    /// Returns the covariance matrix of the multinomial distribution as
+    /// a vector of rows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * p_i * (δ_ij - p_j)
+    /// ```
+    ///
+    /// where `δ_ij` is the Kronecker delta
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Multinomial;
+    ///
+    /// let n = Multinomial::new(&[0.5, 0.5], 4).unwrap();
+    /// assert_eq!(n.covariance(), vec![vec![1.0, -1.0], vec![-1.0, 1.0]]);
+    /// ```
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        let n = self.n as f64;
+        self.p
+            .iter()
+            .enumerate()
+            .map(|(i, &pi)| {
+                self.p
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &pj)| if i == j { n * pi * (1.0 - pi) } else { -n * pi * pj })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Calculates the probability mass function for the multinomial
+    /// distribution at the vector of counts `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.p().len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if any count is negative or the counts do not sum to `n`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (n! / Π x_i!) * Π p_i^x_i
+    /// ```
+    pub fn pmf(&self, x: &[i64]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Calculates the log probability mass function for the multinomial
+    /// distribution at the vector of counts `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.p().len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NEG_INFINITY` if any count is negative or the counts
+    /// do not sum to `n`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(n!) - Σ ln(x_i!) + Σ x_i * ln(p_i)
+    /// ```
+    pub fn ln_pmf(&self, x: &[i64]) -> f64 {
+        assert!(x.len() == self.p.len(),
+                "{}",
+                StatsError::ContainersMustBeSameLength);
+        if x.iter().any(|&x| x < 0) || x.iter().sum::<i64>() != self.n {
+            return f64::NEG_INFINITY;
+        }
+        x.iter().zip(self.p.iter()).fold(factorial::ln_factorial(self.n as u64), |acc, (&x, &p)| {
+            if x == 0 {
+                acc
+            } else {
+                acc - factorial::ln_factorial(x as u64) + x as f64 * p.ln()
+            }
+        })
+    }
+}
+
+impl Sample<Vec<f64>> for Multinomial {
+    /// Generate a random sample from a multinomial
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<Vec<f64>> for Multinomial {
+    /// Generate a random independent sample from a multinomial
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<Vec<f64>> for Multinomial {
+    /// Generate a random sample from the multinomial distribution
+    /// using `r` as the source of randomness, returning the number of
+    /// trials that fell into each category
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{Multinomial, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = Multinomial::new(&[0.2, 0.3, 0.5], 10).unwrap();
+    /// print!("{:?}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        let mut counts = vec![0.0; self.p.len()];
+        for _ in 0..self.n {
+            let u = r.next_f64();
+            let mut cumulative = 0.0;
+            // defaults to the last category to guard against rounding
+            // in the cumulative sum
+            let mut category = self.p.len() - 1;
+            for (i, p) in self.p.iter().enumerate() {
+                cumulative += *p;
+                if u < cumulative {
+                    category = i;
+                    break;
+                }
+            }
+            counts[category] += 1.0;
+        }
+        counts
+    }
+}
+
+impl Mean<Vec<f64>, Vec<f64>> for Multinomial {
+    /// Returns the mean vector of the multinomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * p_i
+    /// ```
+    fn mean(&self) -> Vec<f64> {
+        self.p.iter().map(|p| p * self.n as f64).collect()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use distribution::*;
+
+    fn try_create(p: &[f64], n: i64) -> Multinomial {
+        let dist = Multinomial::new(p, n);
+        assert!(dist.is_ok());
+        dist.unwrap()
+    }
+
+    fn bad_create_case(p: &[f64], n: i64) {
+        let dist = Multinomial::new(p, n);
+        assert!(dist.is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(&[0.2, 0.3, 0.5], 10);
+        assert_eq!(n.p(), &[0.2, 0.3, 0.5]);
+        assert_eq!(n.n(), 10);
+        assert_eq!(try_create(&[2.0, 0.0, 2.0], 0).p(), &[0.5, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(&[], 10);
+        bad_create_case(&[1.0], 10);
+        bad_create_case(&[0.5, 0.5], -1);
+        bad_create_case(&[0.0, 0.0], 10);
+        bad_create_case(&[-0.5, 1.5], 10);
+        bad_create_case(&[f64::NAN, 0.5], 10);
+        bad_create_case(&[f64::INFINITY, 0.5], 10);
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(try_create(&[0.25, 0.75], 8).mean(), vec![2.0, 6.0]);
+    }
+
+    #[test]
+    fn test_covariance() {
+        let cov = try_create(&[0.25, 0.25, 0.5], 8).covariance();
+        assert_eq!(cov, vec![vec![1.5, -0.5, -1.0], vec![-0.5, 1.5, -1.0], vec![-1.0, -1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_pmf() {
+        let n = try_create(&[0.2, 0.3, 0.5], 10);
+        assert_almost_eq!(n.pmf(&[2, 3, 5]), 0.08505, 1e-15);
+        assert_almost_eq!(n.pmf(&[0, 0, 10]), 0.0009765625, 1e-17);
+        assert_eq!(n.pmf(&[2, 3, 4]), 0.0);
+        assert_eq!(n.pmf(&[-1, 6, 5]), 0.0);
+        assert_eq!(try_create(&[0.5, 0.0, 0.5], 2).pmf(&[1, 1, 0]), 0.0);
+        assert_eq!(try_create(&[0.5, 0.5], 0).pmf(&[0, 0]), 1.0);
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let n = try_create(&[0.2, 0.3, 0.5], 10);
+        assert_almost_eq!(n.ln_pmf(&[2, 3, 5]), -2.4645159601402662834, 1e-14);
+        assert_almost_eq!(n.ln_pmf(&[0, 0, 10]), -6.9314718055994530942, 1e-14);
+        assert_almost_eq!(try_create(&[0.2, 0.3, 0.5], 1000).ln_pmf(&[200, 300, 500]), -6.993131174039724981, 1e-11);
+        assert_eq!(n.ln_pmf(&[2, 3, 4]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pmf_wrong_dim() {
+        try_create(&[0.5, 0.5], 2).pmf(&[2]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let n = try_create(&[0.2, 0.3, 0.5], 10);
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let count = 10000;
+        let mut sums = vec![0.0; 3];
+        for _ in 0..count {
+            let s = n.sample(&mut r);
+            assert_eq!(s.iter().sum::<f64>(), 10.0);
+            for (sum, x) in sums.iter_mut().zip(s.iter()) {
+                *sum += *x;
+            }
+        }
+        for (sum, mean) in sums.iter().zip(n.mean().iter()) {
+            assert_almost_eq!(sum / count as f64, *mean, 0.05);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use std::f64;
+use rand::Rng;
+use rand::distributions::{Sample, IndependentSample};
+use consts;
+use error::StatsError;
+use result::Result;
+use super::*;
+
+/// Implements the [Multivariate Normal](https://en.wikipedia.org/wiki/Multivariate_normal_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{MultivariateNormal, Mean};
+/// use statrs::prec;
+///
+/// let n = MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.0], [0.0, 1.0]]).unwrap();
+/// assert_eq!(n.mean(), vec![0.0, 0.0]);
+/// assert!(prec::almost_eq(n.pdf(&[0.0, 0.0]), 0.15915494309189533577, 1e-16));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    cov: Vec<f64>,
+    cov_chol: Vec<f64>,
+    ln_det: f64,
+}
+
+impl MultivariateNormal {
+    /// Constructs a new multivariate normal distribution with a mean of
+    /// `mean` and a covariance matrix of `cov`, given as a slice of rows
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` is empty, `cov` is not a square matrix
+    /// with the same dimension as `mean`, any element is `NaN` or infinite,
+    /// or `cov` is not symmetric positive definite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let mut result = MultivariateNormal::new(&[0.0, 0.0], &[[2.0, 1.0], [1.0, 2.0]]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 2.0], [2.0, 1.0]]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new<T>(mean: &[f64], cov: &[T]) -> Result<MultivariateNormal>
+        where T: AsRef<[f64]>
+    {
+        let n = mean.len();
+        if n == 0 || cov.len() != n || cov.iter().any(|row| row.as_ref().len() != n) {
+            return Err(StatsError::BadParams);
+        }
+        let cov: Vec<f64> = cov.iter().flat_map(|row| row.as_ref().iter().cloned()).collect();
+        MultivariateNormal::from_parts(mean.to_vec(), cov)
+    }
+
+    fn from_parts(mean: Vec<f64>, cov: Vec<f64>) -> Result<MultivariateNormal> {
+        let n = mean.len();
+        if mean.iter().chain(cov.iter()).any(|x| !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        for i in 0..n {
+            for j in 0..i {
+                let (a, b) = (cov[i * n + j], cov[j * n + i]);
+                if (a - b).abs() > 1e-12 * a.abs().max(b.abs()) {
+                    return Err(StatsError::BadParams);
+                }
+            }
+        }
+        let cov_chol = match internal::cholesky(&cov, n) {
+            Some(l) => l,
+            None => return Err(StatsError::BadParams),
+        };
+        let ln_det = 2.0 * (0..n).fold(0.0, |acc, i| acc + cov_chol[i * n + i].ln());
+        Ok(MultivariateNormal {
+            mean: mean,
+            cov: cov,
+            cov_chol: cov_chol,
+            ln_det: ln_det,
+        })
+    }
+
+    /// Returns the number of dimensions of the multivariate normal
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let n = MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert_eq!(n.dim(), 2);
+    /// ```
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the covariance matrix of the multivariate normal
+    /// distribution as a vector of rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let n = MultivariateNormal::new(&[0.0, 0.0], &[[2.0, 1.0], [1.0, 2.0]]).unwrap();
+    /// assert_eq!(n.covariance(), vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+    /// ```
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        self.cov.chunks(self.dim()).map(|row| row.to_vec()).collect()
+    }
+
+    /// Calculates the probability density function for the multivariate
+    /// normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.dim()`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2π)^(-k / 2) * det(Σ)^(-1 / 2) * exp(-(x - μ)^T * Σ^-1 * (x - μ) / 2)
+    /// ```
+    ///
+    /// where `μ` is the mean, `Σ` is the covariance matrix and `k` is the
+    /// number of dimensions
+    pub fn pdf(&self, x: &[f64]) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the multivariate
+    /// normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x.len() != self.dim()`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(k * ln(2π) + ln(det(Σ)) + (x - μ)^T * Σ^-1 * (x - μ)) / 2
+    /// ```
+    ///
+    /// where `μ` is the mean, `Σ` is the covariance matrix and `k` is the
+    /// number of dimensions. The quadratic form is evaluated as `|L^-1 * (x - μ)|^2`
+    /// where `L` is the Cholesky factor of `Σ`
+    pub fn ln_pdf(&self, x: &[f64]) -> f64 {
+        let n = self.dim();
+        assert!(x.len() == n, "{}", StatsError::ContainersMustBeSameLength);
+        let diff: Vec<f64> = x.iter().zip(self.mean.iter()).map(|(x, m)| x - m).collect();
+        let z = internal::forward_substitution(&self.cov_chol, n, &diff);
+        let quad = z.iter().fold(0.0, |acc, z| acc + z * z);
+        -(n as f64 * consts::LN_SQRT_2PI) - 0.5 * (self.ln_det + quad)
+    }
+
+    /// Returns the marginal distribution of the variables at `indices`,
+    /// in the order given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is empty, contains duplicates or
+    /// contains an index that is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{MultivariateNormal, Mean};
+    ///
+    /// let n = MultivariateNormal::new(&[1.0, 2.0], &[[2.0, 1.0], [1.0, 3.0]]).unwrap();
+    /// let m = n.marginal(&[1]).unwrap();
+    /// assert_eq!(m.mean(), vec![2.0]);
+    /// assert_eq!(m.covariance(), vec![vec![3.0]]);
+    /// ```
+    pub fn marginal(&self, indices: &[usize]) -> Result<MultivariateNormal> {
+        if indices.is_empty() || !self.valid_indices(indices) {
+            return Err(StatsError::BadParams);
+        }
+        let mean = indices.iter().map(|&i| self.mean[i]).collect();
+        MultivariateNormal::from_parts(mean, self.submatrix(indices, indices))
+    }
+
+    /// Returns the conditional distribution of the remaining variables,
+    /// in their original order, given that the variables at `indices`
+    /// take the corresponding `values`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` and `values` are of different lengths,
+    /// `indices` is empty, contains duplicates, contains an index that is
+    /// out of bounds or covers every variable, or any value is `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ_a + Σ_ab * Σ_bb^-1 * (v - μ_b)
+    /// Σ_aa - Σ_ab * Σ_bb^-1 * Σ_ba
+    /// ```
+    ///
+    /// where `a` are the remaining variables, `b` are the variables
+    /// at `indices` and `v` are the `values`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{MultivariateNormal, Mean};
+    ///
+    /// let n = MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.5], [0.5, 1.0]]).unwrap();
+    /// let c = n.conditional(&[0], &[2.0]).unwrap();
+    /// assert_eq!(c.mean(), vec![1.0]);
+    /// assert_eq!(c.covariance(), vec![vec![0.75]]);
+    /// ```
+    pub fn conditional(&self, indices: &[usize], values: &[f64]) -> Result<MultivariateNormal> {
+        if indices.len() != values.len() {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        let n = self.dim();
+        if indices.is_empty() || indices.len() >= n || !self.valid_indices(indices) ||
+           values.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let rest: Vec<usize> = (0..n).filter(|i| !indices.contains(i)).collect();
+        let (na, nb) = (rest.len(), indices.len());
+
+        // any principal submatrix of a positive definite matrix is
+        // itself positive definite so the factorisation cannot fail
+        let chol_bb = internal::cholesky(&self.submatrix(indices, indices), nb).unwrap();
+        let cov_ab = self.submatrix(&rest, indices);
+        let diff: Vec<f64> = indices.iter().zip(values.iter()).map(|(&i, v)| v - self.mean[i]).collect();
+        let w = internal::forward_substitution(&chol_bb, nb, &diff);
+        let w = internal::backward_substitution(&chol_bb, nb, &w);
+        // rows of L_bb^-1 * Σ_ba, so that the correction is their inner products
+        let k: Vec<Vec<f64>> =
+            (0..na).map(|i| internal::forward_substitution(&chol_bb, nb, &cov_ab[i * nb..(i + 1) * nb])).collect();
+
+        let mean = rest.iter()
+            .enumerate()
+            .map(|(i, &r)| {
+                self.mean[r] + cov_ab[i * nb..(i + 1) * nb].iter().zip(w.iter()).fold(0.0, |acc, (c, w)| acc + c * w)
+            })
+            .collect();
+        let mut cov = self.submatrix(&rest, &rest);
+        for i in 0..na {
+            for j in 0..i + 1 {
+                let correction = k[i].iter().zip(k[j].iter()).fold(0.0, |acc, (a, b)| acc + a * b);
+                cov[i * na + j] -= correction;
+                cov[j * na + i] = cov[i * na + j];
+            }
+        }
+        MultivariateNormal::from_parts(mean, cov)
+    }
+
+    fn valid_indices(&self, indices: &[usize]) -> bool {
+        indices.iter().enumerate().all(|(i, &x)| x < self.dim() && !indices[..i].contains(&x))
+    }
+
+    fn submatrix(&self, rows: &[usize], cols: &[usize]) -> Vec<f64> {
+        let n = self.dim();
+        rows.iter().flat_map(|&i| cols.iter().map(move |&j| self.cov[i * n + j])).collect()
+    }
+}
+
+impl Sample<Vec<f64>> for MultivariateNormal {
+    /// Generate a random sample from a multivariate normal
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn sample<R: Rng>(&mut self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl IndependentSample<Vec<f64>> for MultivariateNormal {
+    /// Generate a random independent sample from a multivariate normal
+    /// distribution using `r` as the source of randomness.
+    /// Refer [here](#method.sample-1) for implementation details
+    fn ind_sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        super::Distribution::sample(self, r)
+    }
+}
+
+impl Distribution<Vec<f64>> for MultivariateNormal {
+    /// Generate a random sample from the multivariate normal distribution
+    /// using `r` as the source of randomness. Draws a vector `z` of
+    /// independent standard normal samples and returns `μ + L * z` where
+    /// `L` is the Cholesky factor of the covariance matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::StdRng;
+    /// use statrs::distribution::{MultivariateNormal, Distribution};
+    ///
+    /// # fn main() {
+    /// let mut r = rand::StdRng::new().unwrap();
+    /// let n = MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// print!("{:?}", n.sample::<StdRng>(&mut r));
+    /// # }
+    /// ```
+    fn sample<R: Rng>(&self, r: &mut R) -> Vec<f64> {
+        let n = self.dim();
+        let z: Vec<f64> = (0..n).map(|_| super::normal::sample_unchecked(r, 0.0, 1.0)).collect();
+        (0..n)
+            .map(|i| self.mean[i] + (0..i + 1).fold(0.0, |acc, k| acc + self.cov_chol[i * n + k] * z[k]))
+            .collect()
+    }
+}
+
+impl Mean<Vec<f64>, Vec<f64>> for MultivariateNormal {
+    /// Returns the mean vector of the multivariate normal distribution
+    fn mean(&self) -> Vec<f64> {
+        self.mean.clone()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use distribution::*;
+
+    fn try_create(mean: &[f64], cov: &[[f64; 3]]) -> MultivariateNormal {
+        let n = MultivariateNormal::new(mean, cov);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn default() -> MultivariateNormal {
+        try_create(&[1.0, 2.0, 3.0], &[[2.0, 0.5, 0.3], [0.5, 1.0, 0.2], [0.3, 0.2, 1.5]])
+    }
+
+    #[test]
+    fn test_create() {
+        let n = default();
+        assert_eq!(n.dim(), 3);
+        assert_eq!(n.mean(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(n.covariance()[2], vec![0.3, 0.2, 1.5]);
+        assert!(MultivariateNormal::new(&[0.0], &[[f64::MIN_POSITIVE]]).is_ok());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(MultivariateNormal::new(&[], &[[0.0; 0]; 0]).is_err());
+        assert!(MultivariateNormal::new(&[0.0, 0.0], &[[1.0]]).is_err());
+        assert!(MultivariateNormal::new(&[0.0], &[[1.0, 0.0]]).is_err());
+        assert!(MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.5], [0.4, 1.0]]).is_err());
+        assert!(MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 1.0], [1.0, 1.0]]).is_err());
+        assert!(MultivariateNormal::new(&[0.0, 0.0], &[[-1.0, 0.0], [0.0, 1.0]]).is_err());
+        assert!(MultivariateNormal::new(&[f64::NAN, 0.0], &[[1.0, 0.0], [0.0, 1.0]]).is_err());
+        assert!(MultivariateNormal::new(&[0.0, 0.0], &[[1.0, 0.0], [0.0, f64::INFINITY]]).is_err());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = default();
+        assert_almost_eq!(n.pdf(&[1.5, 1.0, 2.0]), 0.013643490509379821840, 1e-16);
+        assert_almost_eq!(n.pdf(&[1.0, 2.0, 3.0]), (-3.2179520013898694896f64).exp(), 1e-16);
+        let n = MultivariateNormal::new(&[0.0], &[[4.0]]).unwrap();
+        let u = Normal::new(0.0, 2.0).unwrap();
+        assert_almost_eq!(n.pdf(&[1.3]), u.pdf(1.3), 1e-16);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = default();
+        assert_almost_eq!(n.ln_pdf(&[1.5, 1.0, 2.0]), -4.2944927568570662957, 1e-14);
+        assert_almost_eq!(n.ln_pdf(&[1.0, 2.0, 3.0]), -3.2179520013898694896, 1e-14);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pdf_wrong_dim() {
+        default().pdf(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_marginal() {
+        let m = default().marginal(&[2, 0]).unwrap();
+        assert_eq!(m.mean(), vec![3.0, 1.0]);
+        assert_eq!(m.covariance(), vec![vec![1.5, 0.3], vec![0.3, 2.0]]);
+
+        assert!(default().marginal(&[]).is_err());
+        assert!(default().marginal(&[3]).is_err());
+        assert!(default().marginal(&[1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_conditional() {
+        let c = default().conditional(&[1], &[2.5]).unwrap();
+        let mean = c.mean();
+        let cov = c.covariance();
+        assert_almost_eq!(mean[0], 1.25, 1e-15);
+        assert_almost_eq!(mean[1], 3.1, 1e-15);
+        assert_almost_eq!(cov[0][0], 1.75, 1e-15);
+        assert_almost_eq!(cov[0][1], 0.2, 1e-15);
+        assert_almost_eq!(cov[1][0], 0.2, 1e-15);
+        assert_almost_eq!(cov[1][1], 1.46, 1e-15);
+
+        let c = default().conditional(&[2, 0], &[4.0, 0.0]).unwrap();
+        assert_almost_eq!(c.mean()[0], 1.8487972508591065395, 1e-15);
+        assert_almost_eq!(c.covariance()[0][0], 0.86426116838487972273, 1e-15);
+    }
+
+    #[test]
+    fn test_conditional_bad_input() {
+        assert!(default().conditional(&[0], &[1.0, 2.0]).is_err());
+        assert!(default().conditional(&[], &[]).is_err());
+        assert!(default().conditional(&[0, 1, 2], &[1.0, 2.0, 3.0]).is_err());
+        assert!(default().conditional(&[0, 0], &[1.0, 1.0]).is_err());
+        assert!(default().conditional(&[5], &[1.0]).is_err());
+        assert!(default().conditional(&[0], &[f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        let n = default();
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let count = 20000;
+        let samples: Vec<Vec<f64>> = (0..count).map(|_| n.sample(&mut r)).collect();
+        for i in 0..3 {
+            let mean = samples.iter().fold(0.0, |acc, s| acc + s[i]) / count as f64;
+            assert_almost_eq!(mean, n.mean()[i], 0.05);
+        }
+        let cov01 = samples.iter().fold(0.0, |acc, s| acc + (s[0] - 1.0) * (s[1] - 2.0)) / count as f64;
+        assert_almost_eq!(cov01, 0.5, 0.05);
+    }
+}
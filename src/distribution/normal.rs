@@ -5,6 +5,7 @@ use consts;
 use error::StatsError;
 use function::erf;
 use result::Result;
+use statistics::Statistics;
 use super::*;
 
 /// Implements the [Normal](https://en.wikipedia.org/wiki/Normal_distribution)
@@ -55,6 +56,42 @@ impl Normal {
             })
         }
     }
+
+    /// Fits a normal distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two elements, contains
+    /// `NaN` or all of its elements are equal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = Σ x_i / n
+    /// σ = sqrt(Σ (x_i - μ)^2 / n)
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Mean};
+    ///
+    /// let (n, ln_likelihood) = Normal::fit(&[1.0, 2.0, 3.0]).unwrap();
+    /// assert_eq!(n.mean(), 2.0);
+    /// assert!(ln_likelihood < 0.0);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Normal, f64)> {
+        if data.len() < 2 || data.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let dist = Normal::new(data.mean(), data.population_std_dev())?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Normal {
@@ -115,6 +152,52 @@ impl Univariate<f64, f64> for Normal {
         cdf_unchecked(x, self.mean, self.std_dev)
     }
 
+    /// Calculates the survival function for the normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((x - μ) / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc` is the complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.mean, self.std_dev)
+    }
+
+    /// Calculates the natural logarithm of the cumulative distribution
+    /// function for the normal distribution at `x`, without underflow in
+    /// the lower tail
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((μ - x) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc` is the complementary error function
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_cdf_unchecked(x, self.mean, self.std_dev)
+    }
+
+    /// Calculates the natural logarithm of the survival function for the
+    /// normal distribution at `x`, without underflow in the upper tail
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((x - μ) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc` is the complementary error function
+    fn ln_sf(&self, x: f64) -> f64 {
+        ln_sf_unchecked(x, self.mean, self.std_dev)
+    }
+
     /// Returns the minimum value in the domain of the
     /// normal distribution representable by a double precision float
     ///
@@ -140,6 +223,30 @@ impl Univariate<f64, f64> for Normal {
     }
 }
 
+impl InverseCdf<f64, f64> for Normal {
+    /// Calculates the inverse cumulative distribution function for the
+    /// normal distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - sqrt(2) * σ * erfc_inv(2p)
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc_inv` is the inverse complementary error function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        inverse_cdf_unchecked(p, self.mean, self.std_dev)
+    }
+}
+
 impl Mean<f64, f64> for Normal {
     /// Returns the mean of the normal distribution
     ///
@@ -270,6 +377,40 @@ pub fn cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// performs an unchecked survival function calculation for a normal
+/// distribution with the given mean and standard deviation at x
+pub fn sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    0.5 * erf::erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
+}
+
+/// performs an unchecked log(cdf) calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn ln_cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    ln_half_erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
+}
+
+/// performs an unchecked log(sf) calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn ln_sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    ln_half_erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
+}
+
+// returns ln(erfc(z) / 2), using ln(1 - erfc(-z) / 2) for z < 0 where
+// erfc(z) / 2 is close to 1
+fn ln_half_erfc(z: f64) -> f64 {
+    if z < 0.0 {
+        (-0.5 * erf::erfc(-z)).ln_1p()
+    } else {
+        erf::ln_erfc(z) - f64::consts::LN_2
+    }
+}
+
+/// performs an unchecked inverse cdf calculation for a normal distribution
+/// with the given mean and standard deviation at p
+pub fn inverse_cdf_unchecked(p: f64, mean: f64, std_dev: f64) -> f64 {
+    mean - std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * p)
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -297,7 +438,7 @@ pub fn sample_unchecked<R: Rng>(r: &mut R, mean: f64, std_dev: f64) -> f64 {
 fn polar_transform(a: f64, b: f64) -> (f64, f64, bool) {
     let v1 = 2.0 * a - 1.0;
     let v2 = 2.0 * b - 1.0;
-    let r = v1 * v2 + v2 * v2;
+    let r = v1 * v1 + v2 * v2;
     if r >= 1.0 || r == 0.0 {
         (0.0, 0.0, false)
     } else {
@@ -310,6 +451,7 @@ fn polar_transform(a: f64, b: f64) -> (f64, f64, bool) {
 #[cfg(test)]
 mod test {
     use std::f64;
+    use rand::{SeedableRng, StdRng};
     use distribution::*;
 
     fn try_create(mean: f64, std_dev: f64) -> Normal {
@@ -480,4 +622,102 @@ mod test {
         test_case(5.0, 2.0, 0.69146246127401310363770461060833773988360217555457859, |x| x.cdf(6.0));
         test_almost(5.0, 2.0, 0.993790334674, 1e-12, |x| x.cdf(10.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(0.0, 1.0, f64::NEG_INFINITY, |x| x.inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -6.3613409024040562047, 1e-12, |x| x.inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, -3.0902323061678135415, 1e-14, |x| x.inverse_cdf(0.001));
+        test_almost(0.0, 1.0, -1.9599639845400542355, 1e-14, |x| x.inverse_cdf(0.025));
+        test_case(0.0, 1.0, 0.0, |x| x.inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 1.9599639845400542355, 1e-14, |x| x.inverse_cdf(0.975));
+        test_almost(0.0, 1.0, 3.0902323061678135415, 1e-13, |x| x.inverse_cdf(0.999));
+        test_case(0.0, 1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        test_case(5.0, 2.0, 5.0, |x| x.inverse_cdf(0.5));
+        test_almost(5.0, 2.0, 5.0 - 2.0 * 1.9599639845400542355, 1e-14, |x| x.inverse_cdf(0.025));
+        test_almost(5.0, 2.0, 4.0, 1e-14, |x| x.inverse_cdf(0.30853753872598689636229538939166226011639782444542207));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_input_lt_zero() {
+        test_case(0.0, 1.0, 0.0, |x| x.inverse_cdf(-0.1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_input_gt_one() {
+        test_case(0.0, 1.0, 0.0, |x| x.inverse_cdf(1.1));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(5.0, 2.0, 1.0, |x| x.sf(f64::NEG_INFINITY));
+        test_almost(5.0, 2.0, 0.69146246127401310364, 1e-15, |x| x.sf(4.0));
+        test_case(5.0, 2.0, 0.5, |x| x.sf(5.0));
+        test_almost(5.0, 2.0, 0.30853753872598689636, 1e-15, |x| x.sf(6.0));
+        test_almost(5.0, 2.0, 7.619853024160526066e-24, 1e-33, |x| x.sf(25.0));
+        test_case(5.0, 2.0, 0.0, |x| x.sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        test_case(5.0, 2.0, f64::NEG_INFINITY, |x| x.ln_cdf(f64::NEG_INFINITY));
+        test_almost(5.0, 2.0, -53.231285150512470578, 1e-12, |x| x.ln_cdf(-15.0));
+        test_almost(5.0, 2.0, -126260.26353813339671, 1e-9, |x| x.ln_cdf(-1000.0));
+        test_almost(5.0, 2.0, 0.5f64.ln(), 1e-15, |x| x.ln_cdf(5.0));
+        test_almost(5.0, 2.0, -2.866516129637635934e-7, 1e-21, |x| x.ln_cdf(15.0));
+        test_case(5.0, 2.0, 0.0, |x| x.ln_cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(5.0, 2.0, 0.0, |x| x.ln_sf(f64::NEG_INFINITY));
+        test_almost(5.0, 2.0, -3.167174337748926386e-5, 1e-19, |x| x.ln_sf(-3.0));
+        test_almost(5.0, 2.0, 0.5f64.ln(), 1e-15, |x| x.ln_sf(5.0));
+        test_almost(5.0, 2.0, -53.231285150512470578, 1e-12, |x| x.ln_sf(25.0));
+        test_almost(5.0, 2.0, -125007.13355063158687, 1e-9, |x| x.ln_sf(1005.0));
+        test_case(5.0, 2.0, f64::NEG_INFINITY, |x| x.ln_sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Normal::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2]).unwrap();
+        assert_almost_eq!(n.mean(), 1.87, 1e-14);
+        assert_almost_eq!(n.std_dev(), 1.11, 1e-14);
+        assert_almost_eq!(ln_likelihood, -15.232985485289155095, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Normal::fit(&[]).is_err());
+        assert!(Normal::fit(&[1.0]).is_err());
+        assert!(Normal::fit(&[1.0, 1.0]).is_err());
+        assert!(Normal::fit(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_polar_transform() {
+        // (-1, 0.5) lies outside the unit circle and must be rejected
+        assert!(!super::polar_transform(0.0, 0.75).2);
+        let (x, y, accepted) = super::polar_transform(0.75, 0.5);
+        assert!(accepted);
+        assert_almost_eq!(x, 1.6651092223153955127, 1e-15);
+        assert_eq!(y, 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let n = try_create(1.0, 2.0);
+        let count = 50000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|x| x.is_finite()));
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count as f64;
+        assert_almost_eq!(mean, 1.0, 0.03);
+        assert_almost_eq!(variance, 4.0, 0.1);
+        let below = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / count as f64;
+        assert_almost_eq!(below, n.cdf(0.0), 0.01);
+    }
 }
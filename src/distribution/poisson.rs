@@ -5,6 +5,7 @@ use rand::distributions::{Sample, IndependentSample};
 use error::StatsError;
 use function::{factorial, gamma};
 use result::Result;
+use statistics::Statistics;
 use super::*;
 
 /// Implements the [Poisson](https://en.wikipedia.org/wiki/Poisson_distribution)
@@ -65,6 +66,40 @@ impl Poisson {
     pub fn lambda(&self) -> f64 {
         self.lambda
     }
+
+    /// Fits a poisson distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, any element is not a
+    /// non-negative integer or all of its elements are zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ = Σ x_i / n
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let (n, _) = Poisson::fit(&[1.0, 2.0, 3.0]).unwrap();
+    /// assert_eq!(n.lambda(), 2.0);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Poisson, f64)> {
+        if data.is_empty() || data.iter().any(|&x| x < 0.0 || x.floor() != x) {
+            return Err(StatsError::BadParams);
+        }
+        let dist = Poisson::new(data.mean())?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pmf(x as i64));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Poisson {
@@ -122,13 +157,32 @@ impl Univariate<i64, f64> for Poisson {
     /// # Formula
     ///
     /// ```ignore
-    /// 1 - P(x + 1, λ)
+    /// Q(floor(x) + 1, λ)
     /// ```
     ///
-    /// where `λ` is the rate and `P` is the lower regularized gamma function
+    /// where `λ` is the rate and `Q` is the upper regularized gamma function
     fn cdf(&self, x: f64) -> f64 {
         assert!(x >= 0.0, format!("{}", StatsError::ArgNotNegative("x")));
-        1.0 - gamma::gamma_lr(x + 1.0, self.lambda)
+        gamma::gamma_ur(x.floor() + 1.0, self.lambda)
+    }
+
+    /// Calculates the survival function for the poisson
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(floor(x) + 1, λ)
+    /// ```
+    ///
+    /// where `λ` is the rate and `P` is the lower regularized gamma function
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        gamma::gamma_lr(x.floor() + 1.0, self.lambda)
     }
 
     /// Returns the minimum value in the domain of the poisson distribution
@@ -156,6 +210,33 @@ impl Univariate<i64, f64> for Poisson {
     }
 }
 
+impl InverseCdf<i64, f64> for Poisson {
+    /// Calculates the inverse cumulative distribution function for the
+    /// poisson distribution at `p` i.e. the smallest `x` such that
+    /// `cdf(x) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the Cornish-Fisher normal approximation
+    /// of the quantile and is then corrected by evaluating the exact
+    /// cumulative distribution function. Returns `0` if `p == 0.0`
+    /// and `2^63 - 1` if `p == 1.0`
+    fn inverse_cdf(&self, p: f64) -> i64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        if p == 1.0 {
+            return i64::MAX;
+        }
+        let guess = internal::normal_approx_quantile(p, self.mean(), self.std_dev(), self.skewness());
+        internal::integral_inverse_cdf(p, 0, i64::MAX, guess, |k| self.cdf(k as f64))
+    }
+}
+
 impl Mean<f64, f64> for Poisson {
     /// Returns the mean of the poisson distribution
     ///
@@ -514,9 +595,100 @@ mod test {
         test_almost(10.8, 0.9961800769608090000000, 1e-15, |x| x.cdf(20.0));
     }
 
+    #[test]
+    fn test_cdf_non_integer() {
+        test_almost(5.4, 0.0289061180327211000000, 1e-16, |x| x.cdf(1.5));
+        test_almost(5.4, 0.9774863006897650000000, 1e-15, |x| x.cdf(10.99));
+    }
+
+    #[test]
+    fn test_cdf_lower_tail() {
+        test_almost(30.0, 4.5010166480121239850e-11, 1e-24, |x| x.cdf(2.0));
+        test_almost(100.0, 1.1376879516952979195e-30, 1e-42, |x| x.cdf(10.0));
+    }
+
     #[test]
     #[should_panic]
     fn test_neg_cdf() {
         get_value(1.5, |x| x.cdf(-1.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.5, 0, |x| x.inverse_cdf(0.0));
+        test_case(1.5, i64::MAX, |x| x.inverse_cdf(1.0));
+        test_case(0.5, 0, |x| x.inverse_cdf(1e-10));
+        test_case(0.5, 0, |x| x.inverse_cdf(0.01));
+        test_case(0.5, 0, |x| x.inverse_cdf(0.1));
+        test_case(0.5, 0, |x| x.inverse_cdf(0.5));
+        test_case(0.5, 1, |x| x.inverse_cdf(0.9));
+        test_case(0.5, 3, |x| x.inverse_cdf(0.99));
+        test_case(0.5, 7, |x| x.inverse_cdf(0.999999));
+        test_case(5.5, 0, |x| x.inverse_cdf(1e-10));
+        test_case(5.5, 1, |x| x.inverse_cdf(0.01));
+        test_case(5.5, 3, |x| x.inverse_cdf(0.1));
+        test_case(5.5, 5, |x| x.inverse_cdf(0.5));
+        test_case(5.5, 9, |x| x.inverse_cdf(0.9));
+        test_case(5.5, 12, |x| x.inverse_cdf(0.99));
+        test_case(5.5, 20, |x| x.inverse_cdf(0.999999));
+        test_case(1000.0, 806, |x| x.inverse_cdf(1e-10));
+        test_case(1000.0, 927, |x| x.inverse_cdf(0.01));
+        test_case(1000.0, 960, |x| x.inverse_cdf(0.1));
+        test_case(1000.0, 1000, |x| x.inverse_cdf(0.5));
+        test_case(1000.0, 1041, |x| x.inverse_cdf(0.9));
+        test_case(1000.0, 1074, |x| x.inverse_cdf(0.99));
+        test_case(1000.0, 1154, |x| x.inverse_cdf(0.999999));
+        test_case(1000000.0, 993645, |x| x.inverse_cdf(1e-10));
+        test_case(1000000.0, 997674, |x| x.inverse_cdf(0.01));
+        test_case(1000000.0, 998719, |x| x.inverse_cdf(0.1));
+        test_case(1000000.0, 1000000, |x| x.inverse_cdf(0.5));
+        test_case(1000000.0, 1001282, |x| x.inverse_cdf(0.9));
+        test_case(1000000.0, 1002327, |x| x.inverse_cdf(0.99));
+        test_case(1000000.0, 1004757, |x| x.inverse_cdf(0.999999));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_p_gt_1() {
+        get_value(1.5, |x| x.inverse_cdf(1.1));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_almost(5.4, 0.97109388196727893358, 1e-15, |x| x.sf(1.0));
+        test_almost(5.4, 0.97109388196727893358, 1e-15, |x| x.sf(1.5));
+        test_almost(5.4, 3.3399081836017739927e-14, 1e-27, |x| x.sf(30.0));
+        test_almost(1.5, 2.3372210700347163715e-17, 1e-30, |x| x.sf(20.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        test_almost(5.4, -3.5437020096343741273, 1e-14, |x| x.ln_cdf(1.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(5.4, 3.3399081836017739927e-14f64.ln(), 1e-12, |x| x.ln_sf(30.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sf_lt_0() {
+        get_value(1.5, |x| x.sf(-1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Poisson::fit(&[2.0, 3.0, 1.0, 0.0, 4.0, 2.0, 5.0, 3.0, 2.0, 1.0]).unwrap();
+        assert_almost_eq!(n.lambda(), 2.3, 1e-15);
+        assert_almost_eq!(ln_likelihood, -17.471596225758545388, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Poisson::fit(&[]).is_err());
+        assert!(Poisson::fit(&[0.0, 0.0]).is_err());
+        assert!(Poisson::fit(&[1.5, 2.0]).is_err());
+        assert!(Poisson::fit(&[-1.0, 2.0]).is_err());
+    }
 }
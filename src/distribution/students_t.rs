@@ -175,6 +175,33 @@ impl Univariate<f64, f64> for StudentsT {
         }
     }
 
+    /// Calculates the survival function for the student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < μ {
+    ///     1 - (1 / 2) * I(t, v / 2, 1 / 2)
+    /// } else {
+    ///     (1 / 2) * I(t, v / 2, 1 / 2)
+    /// }
+    /// ```
+    ///
+    /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
+    /// `σ` is the scale, `v` is the freedom, and `I` is the regularized incomplete
+    /// beta function
+    fn sf(&self, x: f64) -> f64 {
+        if self.freedom == f64::INFINITY {
+            super::normal::sf_unchecked(x, self.location, self.scale)
+        } else {
+            let k = (x - self.location) / self.scale;
+            let h = self.freedom / (self.freedom + k * k);
+            let ib = 0.5 * beta::beta_reg(self.freedom / 2.0, 0.5, h);
+            if x <= self.location { 1.0 - ib } else { ib }
+        }
+    }
+
     /// Returns the minimum value in the domain of the student's t-distribution
     /// representable by a double precision float
     ///
@@ -200,6 +227,38 @@ impl Univariate<f64, f64> for StudentsT {
     }
 }
 
+impl InverseCdf<f64, f64> for StudentsT {
+    /// Calculates the inverse cumulative distribution function for the
+    /// student's t-distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Falls back to the inverse cumulative distribution function of the
+    /// normal distribution if `freedom == f64::INFINITY`. Otherwise there is
+    /// no closed form solution so the cumulative distribution function is
+    /// inverted numerically using a bracketed root finder. The lower tail
+    /// is always inverted to preserve precision for `p` close to `1`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        if self.freedom == f64::INFINITY {
+            return super::normal::inverse_cdf_unchecked(p, self.location, self.scale);
+        }
+
+        let (q, sign) = if p > 0.5 { (1.0 - p, 1.0) } else { (p, -1.0) };
+        let k = internal::inverse_cdf(q, f64::NEG_INFINITY, f64::INFINITY, -1.0, |k| {
+            let ib = 0.5 * beta::beta_reg(self.freedom / 2.0, 0.5, self.freedom / (self.freedom + k * k));
+            if k <= 0.0 { ib } else { 1.0 - ib }
+        });
+        self.location - sign * self.scale * k
+    }
+}
+
 impl Mean<f64, f64> for StudentsT {
     /// Returns the mean of the student's t-distribution
     ///
@@ -607,4 +666,38 @@ mod test {
         test_almost(0.0, 1.0, f64::INFINITY, 0.841344746068543, 1e-10, |x| x.cdf(1.0));
         test_almost(0.0, 1.0, f64::INFINITY, 0.977249868051821, 1e-11, |x| x.cdf(2.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(0.0, 1.0, 1.0, f64::NEG_INFINITY, |x| x.inverse_cdf(0.0));
+        test_almost(0.0, 1.0, 1.0, -318.3088389855504393, 1e-10, |x| x.inverse_cdf(0.001));
+        test_almost(0.0, 1.0, 1.0, -3.0776835371752532199, 1e-13, |x| x.inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 1.0, 0.0, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 1.0, 3.0776835371752541331, 1e-13, |x| x.inverse_cdf(0.9));
+        test_almost(0.0, 1.0, 1.0, 318.3088389855501632, 1e-10, |x| x.inverse_cdf(0.999));
+        test_case(0.0, 1.0, 1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        test_almost(0.0, 1.0, 5.0, -5.8934295313560101001, 1e-13, |x| x.inverse_cdf(0.001));
+        test_almost(0.0, 1.0, 5.0, -1.4758840488244810353, 1e-14, |x| x.inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 5.0, 1.4758840488244812516, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(0.0, 1.0, 5.0, 5.8934295313560089535, 1e-13, |x| x.inverse_cdf(0.999));
+        test_almost(0.0, 1.0, 30.0, -3.3851848668293051155, 1e-12, |x| x.inverse_cdf(0.001));
+        test_almost(0.0, 1.0, 30.0, 1.3104150253913957112, 1e-12, |x| x.inverse_cdf(0.9));
+        test_almost(-5.0, 2.0, 5.0, -5.0 + 2.0 * 1.4758840488244812516, 1e-13, |x| x.inverse_cdf(0.9));
+        test_almost(0.0, 1.0, f64::INFINITY, 1.9599639845400542355, 1e-14, |x| x.inverse_cdf(0.975));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(0.0, 1.0, 5.0, 0.5, |x| x.sf(0.0));
+        test_almost(0.0, 1.0, 5.0, 0.8183912661754386872, 1e-15, |x| x.sf(-1.0));
+        test_almost(0.0, 1.0, 5.0, 0.1816087338245613128, 1e-15, |x| x.sf(1.0));
+        test_almost(0.0, 1.0, 5.0, 3.0238788133006126158e-8, 1e-20, |x| x.sf(50.0));
+        test_almost(5.0, 2.0, f64::INFINITY, 7.619853024160526066e-24, 1e-33, |x| x.sf(25.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_almost(0.0, 1.0, 5.0, 0.5f64.ln(), 1e-15, |x| x.ln_sf(0.0));
+        test_almost(0.0, 1.0, 5.0, 3.0238788133006126158e-8f64.ln(), 1e-11, |x| x.ln_sf(50.0));
+    }
 }
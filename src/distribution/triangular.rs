@@ -137,6 +137,37 @@ impl Univariate<f64, f64> for Triangular {
         }
     }
 
+    /// Calculates the survival function for the triangular
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x <= min {
+    ///     1
+    /// } if min < x <= mode {
+    ///     1 - (x - min)^2 / ((max - min) * (mode - min))
+    /// } else if mode < x < max {
+    ///     (max - x)^2 / ((max - min) * (max - mode))
+    /// } else {
+    ///     0
+    /// }
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if x <= a {
+            1.0
+        } else if a < x && x <= c {
+            1.0 - (x - a) * (x - a) / ((b - a) * (c - a))
+        } else if c < x && x < b {
+            (b - x) * (b - x) / ((b - a) * (b - c))
+        } else {
+            0.0
+        }
+    }
+
     /// Returns the minimum value in the domain of the
     /// triangular distribution representable by a double precision float
     ///
@@ -158,6 +189,40 @@ impl Univariate<f64, f64> for Triangular {
     }
 }
 
+impl InverseCdf<f64, f64> for Triangular {
+    /// Calculates the inverse cumulative distribution function for the
+    /// triangular distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if p < (c - a) / (b - a) {
+    ///     a + sqrt(p * (b - a) * (c - a))
+    /// } else {
+    ///     b - sqrt((1 - p) * (b - a) * (b - c))
+    /// }
+    /// ```
+    ///
+    /// where `a` is the minimum, `b` is the maximum, and `c` is the mode
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if p < (c - a) / (b - a) {
+            a + (p * (b - a) * (c - a)).sqrt()
+        } else {
+            b - ((1.0 - p) * (b - a) * (b - c)).sqrt()
+        }
+    }
+}
+
 impl Mean<f64, f64> for Triangular {
     /// Returns the mean of the triangular distribution
     ///
@@ -511,4 +576,36 @@ mod test {
         test_case(-5.0, -3.0, -4.0, 0.5, |x| x.cdf(-4.0));
         test_case(-5.0, -3.0, -4.0, 0.875, |x| x.cdf(-3.5));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(0.0, 1.0, 0.5, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.25, |x| x.inverse_cdf(0.125));
+        test_case(0.0, 1.0, 0.5, 0.5, |x| x.inverse_cdf(0.5));
+        test_case(0.0, 1.0, 0.5, 0.75, |x| x.inverse_cdf(0.875));
+        test_case(0.0, 1.0, 0.5, 1.0, |x| x.inverse_cdf(1.0));
+        test_almost(-5.0, 8.0, -3.5, -4.0, 1e-14, |x| x.inverse_cdf(0.05128205128205128205128));
+        test_almost(-5.0, 8.0, -3.5, -3.5, 1e-14, |x| x.inverse_cdf(0.1153846153846153846154));
+        test_almost(-5.0, 8.0, -3.5, 4.0, 1e-14, |x| x.inverse_cdf(0.892976588628762541806));
+        test_case(-5.0, -3.0, -4.0, -5.0, |x| x.inverse_cdf(0.0));
+        test_case(-5.0, -3.0, -4.0, -3.0, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(0.0, 1.0, 0.5, 1.0, |x| x.sf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.875, |x| x.sf(0.25));
+        test_case(0.0, 1.0, 0.5, 0.5, |x| x.sf(0.5));
+        test_case(0.0, 1.0, 0.5, 0.125, |x| x.sf(0.75));
+        test_case(0.0, 1.0, 0.5, 0.0, |x| x.sf(1.0));
+        test_almost(-5.0, 8.0, -3.5, 1.0 - 0.0128205128205128205128, 1e-15, |x| x.sf(-4.5));
+        test_almost(-5.0, 8.0, -3.5, 0.060200668896321070234, 1e-15, |x| x.sf(5.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(0.0, 1.0, 0.5, 0.0, |x| x.ln_sf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.125f64.ln(), |x| x.ln_sf(0.75));
+        test_case(0.0, 1.0, 0.5, f64::NEG_INFINITY, |x| x.ln_sf(1.0));
+    }
 }
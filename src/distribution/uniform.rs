@@ -118,6 +118,28 @@ impl Univariate<f64, f64> for Uniform {
         (x - self.min) / (self.max - self.min)
     }
 
+    /// Calculates the survival function for the uniform
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `1.0` if `x <= min` and `0.0` if `x >= max`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - x) / (max - min)
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            return 1.0;
+        }
+        if x >= self.max {
+            return 0.0;
+        }
+        (self.max - x) / (self.max - self.min)
+    }
+
     fn min(&self) -> f64 {
         self.min
     }
@@ -127,6 +149,30 @@ impl Univariate<f64, f64> for Uniform {
     }
 }
 
+impl InverseCdf<f64, f64> for Uniform {
+    /// Calculates the inverse cumulative distribution function for the
+    /// uniform distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + p * (max - min)
+    /// ```
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        if p == 1.0 {
+            return self.max;
+        }
+        self.min + p * (self.max - self.min)
+    }
+}
+
 impl Mean<f64, f64> for Uniform {
     /// Returns the mean for the continuous uniform distribution
     ///
@@ -451,4 +497,31 @@ mod test {
         test_case(0.0, f64::INFINITY, 0.0, |x| x.cdf(10.0));
         test_case(0.0, f64::INFINITY, 1.0, |x| x.cdf(f64::INFINITY));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(0.0, 0.0, 0.0, |x| x.inverse_cdf(0.5));
+        test_case(0.0, 1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(0.0, 1.0, 0.5, |x| x.inverse_cdf(0.5));
+        test_case(0.0, 1.0, 1.0, |x| x.inverse_cdf(1.0));
+        test_case(0.0, 10.0, 1.0, |x| x.inverse_cdf(0.1));
+        test_case(-5.0, 100.0, -5.0, |x| x.inverse_cdf(0.0));
+        test_case(-5.0, 100.0, 100.0, |x| x.inverse_cdf(1.0));
+        test_almost(-5.0, 100.0, 0.0, 1e-14, |x| x.inverse_cdf(0.04761904761904761904762));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(0.0, 10.0, 1.0, |x| x.sf(-1.0));
+        test_case(0.0, 10.0, 0.75, |x| x.sf(2.5));
+        test_case(0.0, 10.0, 0.0, |x| x.sf(10.0));
+        test_case(-5.0, 5.0, 0.25, |x| x.sf(2.5));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(0.0, 10.0, 0.0, |x| x.ln_sf(-1.0));
+        test_case(0.0, 10.0, 0.75f64.ln(), |x| x.ln_sf(2.5));
+        test_case(0.0, 10.0, f64::NEG_INFINITY, |x| x.ln_sf(10.0));
+    }
 }
@@ -5,6 +5,8 @@ use consts;
 use error::StatsError;
 use function::{gamma, stable};
 use result::Result;
+use statistics::Statistics;
+use prec;
 use super::*;
 
 /// Implements the [Weibull](https://en.wikipedia.org/wiki/Weibull_distribution)
@@ -89,6 +91,77 @@ impl Weibull {
     pub fn scale(&self) -> f64 {
         self.scale
     }
+
+    /// Fits a weibull distribution to `data` using maximum likelihood
+    /// estimation, returning the fitted distribution along with the
+    /// log-likelihood of `data` under it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two elements, any element
+    /// is not positive or all of its elements are equal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ x_i^k * ln(x_i) / Σ x_i^k - 1 / k = Σ ln(x_i) / n
+    /// λ = (Σ x_i^k / n)^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the scale and `n` is the number of
+    /// samples. The shape is found using Newton's method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Weibull;
+    ///
+    /// let (n, _) = Weibull::fit(&[0.5, 1.2, 2.3, 0.8, 3.1]).unwrap();
+    /// assert!(n.shape() > 0.0);
+    /// ```
+    pub fn fit(data: &[f64]) -> Result<(Weibull, f64)> {
+        if data.len() < 2 || data.iter().any(|&x| x <= 0.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        // the shape equation is invariant to scaling the data, working
+        // relative to the maximum keeps x^k from overflowing
+        let max = data.iter().fold(0.0f64, |acc, &x| acc.max(x));
+        let ln_data: Vec<f64> = data.iter().map(|x| (x / max).ln()).collect();
+        let ln_mean = ln_data.mean();
+        let ln_std_dev = ln_data.population_std_dev();
+        if ln_std_dev <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+
+        // moment estimate from the Gumbel distribution of ln(x)
+        let mut shape = f64::consts::PI / (6f64.sqrt() * ln_std_dev);
+        for _ in 0..internal::MAX_FIT_ITERATIONS {
+            let (mut s0, mut s1, mut s2) = (0.0, 0.0, 0.0);
+            for &l in &ln_data {
+                let xk = (shape * l).exp();
+                s0 += xk;
+                s1 += xk * l;
+                s2 += xk * l * l;
+            }
+            let f = s1 / s0 - 1.0 / shape - ln_mean;
+            let df = (s2 * s0 - s1 * s1) / (s0 * s0) + 1.0 / (shape * shape);
+            let mut next = shape - f / df;
+            if next <= 0.0 {
+                next = shape / 2.0;
+            }
+            let done = (next - shape).abs() <= prec::F64_PREC * next;
+            shape = next;
+            if done {
+                break;
+            }
+        }
+
+        let sum = ln_data.iter().fold(0.0, |acc, &l| acc + (shape * l).exp());
+        let scale = max * (sum / data.len() as f64).powf(1.0 / shape);
+        let dist = Weibull::new(shape, scale)?;
+        let ln_likelihood = data.iter().fold(0.0, |acc, &x| acc + dist.ln_pdf(x));
+        Ok((dist, ln_likelihood))
+    }
 }
 
 impl Sample<f64> for Weibull {
@@ -153,6 +226,44 @@ impl Univariate<f64, f64> for Weibull {
         -stable::exp_minus_one(-x.powf(self.shape) * self.scale_pow_shape_inv)
     }
 
+    /// Calculates the survival function for the weibull
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^-((x/λ)^k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        (-x.powf(self.shape) * self.scale_pow_shape_inv).exp()
+    }
+
+    /// Calculates the natural logarithm of the survival function for
+    /// the weibull distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -((x/λ)^k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn ln_sf(&self, x: f64) -> f64 {
+        assert!(x >= 0.0, "{}", StatsError::ArgNotNegative("x"));
+        -x.powf(self.shape) * self.scale_pow_shape_inv
+    }
+
     /// Returns the minimum value in the domain of the weibull
     /// distribution representable by a double precision float
     ///
@@ -178,6 +289,29 @@ impl Univariate<f64, f64> for Weibull {
     }
 }
 
+impl InverseCdf<f64, f64> for Weibull {
+    /// Calculates the inverse cumulative distribution function for the
+    /// weibull distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (-ln(1 - p))^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        assert!(p >= 0.0 && p <= 1.0,
+                "{}",
+                StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        self.scale * (-(-p).ln_1p()).powf(1.0 / self.shape)
+    }
+}
+
 impl Mean<f64, f64> for Weibull {
     /// Returns the mean of the weibull distribution
     ///
@@ -529,4 +663,49 @@ mod test {
         test_case(10.0, 1.0, 0.63212055882855767840447622983853913255418886896823, |x| x.cdf(1.0));
         test_case(10.0, 1.0, 1.0, |x| x.cdf(10.0));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(1.0, 1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 0.69314718055994530942, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(1.0, 0.1, 0.069314718055994530942, 1e-16, |x| x.inverse_cdf(0.5));
+        test_almost(10.0, 10.0, 9.6401223546778973665856033763604752124634905617583, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(10.0, 10.0, 1.0, 1e-14, |x| x.inverse_cdf(9.9999999995000000000166666666662500000000083333333e-11));
+        test_almost(10.0, 1.0, 1.0, 1e-15, |x| x.inverse_cdf(0.63212055882855767840447622983853913255418886896823));
+        test_case(10.0, 1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        test_case(2.0, 1.0, 1.0, |x| x.sf(0.0));
+        test_almost(2.0, 1.0, (-1.0f64).exp(), 1e-15, |x| x.sf(1.0));
+        test_almost(2.0, 1.0, 3.720075976020835963e-44, 1e-57, |x| x.sf(10.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        test_case(2.0, 1.0, 0.0, |x| x.ln_sf(0.0));
+        test_almost(2.0, 1.0, -1.0, 1e-15, |x| x.ln_sf(1.0));
+        test_almost(2.0, 1.0, -10000.0, 1e-10, |x| x.ln_sf(100.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let (n, ln_likelihood) = Weibull::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2]).unwrap();
+        assert_almost_eq!(n.shape(), 1.7890385483724355722, 1e-12);
+        assert_almost_eq!(n.scale(), 2.1136079032064011776, 1e-12);
+        assert_almost_eq!(ln_likelihood, -14.114214577400989944, 1e-12);
+
+        let large: Vec<f64> = [0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6, 1.4, 4.2].iter().map(|x| x * 1e200).collect();
+        let (n, _) = Weibull::fit(&large).unwrap();
+        assert_almost_eq!(n.shape(), 1.7890385483724355722, 1e-12);
+    }
+
+    #[test]
+    fn test_fit_bad_input() {
+        assert!(Weibull::fit(&[]).is_err());
+        assert!(Weibull::fit(&[1.0]).is_err());
+        assert!(Weibull::fit(&[2.0, 2.0]).is_err());
+        assert!(Weibull::fit(&[0.0, 1.0]).is_err());
+    }
 }
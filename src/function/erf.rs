@@ -1,6 +1,7 @@
 //! Provides special functions related to the [error](https://en.wikipedia.org/wiki/Error_function) function
 
 use std::f64;
+use consts;
 use function::evaluate;
 
 /// `erf` calculates the error function at `x`.
@@ -48,6 +49,29 @@ pub fn erfc(x: f64) -> f64 {
     }
 }
 
+/// `ln_erfc` calculates the natural logarithm of the complementary
+/// error function at `x`. Unlike `erfc(x).ln()` it does not underflow
+/// to `-INF` for large `x`
+pub fn ln_erfc(x: f64) -> f64 {
+    if x < 26.0 || x.is_nan() {
+        erfc(x).ln()
+    } else {
+        // asymptotic expansion erfc(x) = e^(-x^2) / (x * sqrt(π)) *
+        // Σ (-1)^n * (2n - 1)!! / (2x^2)^n, whose terms decrease well past
+        // the point where they stop contributing for x >= 26
+        let t = 1.0 / (2.0 * x * x);
+        let mut term = 1f64;
+        let mut sum = 1.0;
+        let mut n = 1.0;
+        while term.abs() > 1e-17 {
+            term *= -(2.0 * n - 1.0) * t;
+            sum += term;
+            n += 1.0;
+        }
+        -x * x - x.ln() - 0.5 * consts::LN_PI + sum.ln()
+    }
+}
+
 /// `erfc_inv` calculates the complementary inverse
 /// error function at `x`.
 pub fn erfc_inv(x: f64) -> f64 {
@@ -506,58 +530,60 @@ fn erf_impl(z: f64, inv: bool) -> f64 {
             z * evaluate::polynomial(z, ERF_IMPL_AN) / evaluate::polynomial(z, ERF_IMPL_AD)
         }
     } else if z < 110.0 {
+        // the offsets b must be exactly the single precision constants the
+        // rational approximations were fitted to
         let (r, b) = if z < 0.75 {
             (evaluate::polynomial(z - 0.5, ERF_IMPL_BN) /
              evaluate::polynomial(z - 0.5, ERF_IMPL_BD),
-             0.3440242112)
+             0.3440242111682892)
         } else if z < 1.25 {
             (evaluate::polynomial(z - 0.75, ERF_IMPL_CN) /
              evaluate::polynomial(z - 0.75, ERF_IMPL_CD),
-             0.419990927)
+             0.4199909269809723)
         } else if z < 2.25 {
             (evaluate::polynomial(z - 1.25, ERF_IMPL_DN) /
              evaluate::polynomial(z - 1.25, ERF_IMPL_DD),
-             0.4898625016)
+             0.48986250162124634)
         } else if z < 3.5 {
             (evaluate::polynomial(z - 2.25, ERF_IMPL_EN) /
              evaluate::polynomial(z - 2.25, ERF_IMPL_ED),
-             0.5317370892)
+             0.5317370891571045)
         } else if z < 5.25 {
             (evaluate::polynomial(z - 3.5, ERF_IMPL_FN) /
              evaluate::polynomial(z - 3.5, ERF_IMPL_FD),
-             0.5489973426)
+             0.5489973425865173)
         } else if z < 8.0 {
             (evaluate::polynomial(z - 5.25, ERF_IMPL_GN) /
              evaluate::polynomial(z - 5.25, ERF_IMPL_GD),
-             0.5571740866)
+             0.5571740865707397)
         } else if z < 11.5 {
             (evaluate::polynomial(z - 8.0, ERF_IMPL_HN) /
              evaluate::polynomial(z - 8.0, ERF_IMPL_HD),
-             0.5609807968)
+             0.5609807968139648)
         } else if z < 17.0 {
             (evaluate::polynomial(z - 11.5, ERF_IMPL_IN) /
              evaluate::polynomial(z - 11.5, ERF_IMPL_ID),
-             0.5626493692)
+             0.5626493692398071)
         } else if z < 24.0 {
             (evaluate::polynomial(z - 17.0, ERF_IMPL_JN) /
              evaluate::polynomial(z - 17.0, ERF_IMPL_JD),
-             0.5634598136)
+             0.5634598135948181)
         } else if z < 38.0 {
             (evaluate::polynomial(z - 24.0, ERF_IMPL_KN) /
              evaluate::polynomial(z - 24.0, ERF_IMPL_KD),
-             0.5638477802)
+             0.5638477802276611)
         } else if z < 60.0 {
             (evaluate::polynomial(z - 38.0, ERF_IMPL_LN) /
              evaluate::polynomial(z - 38.0, ERF_IMPL_LD),
-             0.5640528202)
+             0.5640528202056885)
         } else if z < 85.0 {
             (evaluate::polynomial(z - 60.0, ERF_IMPL_MN) /
              evaluate::polynomial(z - 60.0, ERF_IMPL_MD),
-             0.5641309023)
+             0.5641309022903442)
         } else {
             (evaluate::polynomial(z - 85.0, ERF_IMPL_NN) /
              evaluate::polynomial(z - 85.0, ERF_IMPL_ND),
-             0.5641584396)
+             0.5641584396362305)
         };
         let g = (-z * z).exp() / z;
         g * b + g * r
@@ -657,6 +683,48 @@ mod test {
         assert_eq!(super::erf(f64::NEG_INFINITY), -1.0);
     }
 
+    #[test]
+    fn test_erfc_offsets() {
+        // Normal::cdf(-1.25) == erfc(1.25 / sqrt(2)) / 2 was off by about
+        // 5e-12 while the offsets were rounded to ten digits
+        assert_almost_eq!(super::erfc(1.25 / f64::consts::SQRT_2) / 2.0, 0.10564977366685525769, 1e-16);
+        assert_almost_eq!(super::erf(1.25 / f64::consts::SQRT_2), 0.78870045266628948462, 1e-16);
+    }
+
+    #[test]
+    fn test_erf_erfc_precision() {
+        // one point in each interval of the rational approximation
+        let cases = [(0.6, 0.6038560908479259050823068, 0.3961439091520740949176932),
+                     (1.0, 0.8427007929497148693412206, 0.1572992070502851306587794),
+                     (1.5, 0.9661051464753107270669763, 0.03389485352468927293302374),
+                     (2.0, 0.9953222650189527341620693, 0.004677734981047265837930744),
+                     (3.0, 0.9999779095030014145586272, 0.00002209049699858544137277613),
+                     (4.5, 0.9999999998033839558457113, 1.96616044154288747627916e-10),
+                     (6.0, 0.9999999999999999784802633, 2.151973671249891311659335e-17),
+                     (9.0, 1.0, 4.137031746513810238053903e-37),
+                     (14.0, 1.0, 3.037229847750311665115173e-87),
+                     (20.0, 1.0, 5.395865611607900928934999e-176)];
+        for &(x, erf, erfc) in cases.iter() {
+            assert_almost_eq!(super::erf(x), erf, 1e-15);
+            assert_almost_eq!(super::erfc(x) / erfc, 1.0, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_ln_erfc() {
+        assert!(super::ln_erfc(f64::NAN).is_nan());
+        assert_almost_eq!(super::ln_erfc(-5.0), 0.69314718055917657952, 1e-15);
+        assert_almost_eq!(super::ln_erfc(0.5), -0.73501112983708440303, 1e-15);
+        assert_almost_eq!(super::ln_erfc(3.0), -10.720363041981112568, 1e-13);
+        assert_almost_eq!(super::ln_erfc(25.9), -674.63735189531925263, 1e-11);
+        assert_almost_eq!(super::ln_erfc(26.0), -679.83119976319423026, 1e-11);
+        assert_almost_eq!(super::ln_erfc(30.0), -903.97411711064387808, 1e-11);
+        assert_almost_eq!(super::ln_erfc(100.0), -10005.177585122664333, 1e-10);
+        assert_almost_eq!(super::ln_erfc(1e5), -10000000012.085290408, 1e-5);
+        assert_eq!(super::ln_erfc(f64::INFINITY), f64::NEG_INFINITY);
+        assert_eq!(super::ln_erfc(f64::NEG_INFINITY), 2f64.ln());
+    }
+
     #[test]
     fn test_erfc() {
         assert!(super::erfc(f64::NAN).is_nan());
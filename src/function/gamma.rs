@@ -307,6 +307,46 @@ pub fn digamma(x: f64) -> f64 {
     result
 }
 
+/// Computes the Trigamma function which is defined as the derivative of
+/// the digamma function. Arguments below `12` are shifted upwards using
+/// the recurrence `ψ1(x) = ψ1(x + 1) + 1 / x^2` before evaluating the
+/// asymptotic expansion and negative arguments use the reflection formula
+/// `ψ1(1 - x) + ψ1(x) = π^2 / sin^2(πx)`
+pub fn trigamma(x: f64) -> f64 {
+    let c = 12.0;
+    let b2 = 1.0 / 6.0;
+    let b4 = 1.0 / 30.0;
+    let b6 = 1.0 / 42.0;
+    let b8 = 1.0 / 30.0;
+    let b10 = 5.0 / 66.0;
+    let b12 = 691.0 / 2730.0;
+    let b14 = 7.0 / 6.0;
+
+    if x == f64::NEG_INFINITY || x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0.0 && x.floor() == x {
+        return f64::INFINITY;
+    }
+    if x < 0.0 {
+        let s = (f64::consts::PI * x).sin();
+        return f64::consts::PI * f64::consts::PI / (s * s) - trigamma(1.0 - x);
+    }
+
+    let mut result = 0.0;
+    let mut z = x;
+    while z < c {
+        result += 1.0 / (z * z);
+        z += 1.0;
+    }
+
+    let r = 1.0 / z;
+    let r2 = r * r;
+    result += r + 0.5 * r2 + r * r2 *
+              (b2 - r2 * (b4 - r2 * (b6 - r2 * (b8 - r2 * (b10 - r2 * (b12 - r2 * b14))))));
+    result
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test{
@@ -495,4 +535,26 @@ mod test{
         assert_almost_eq!(super::digamma(5.5), 1.6110931485817511237336268416044190359814435699427405, 1e-14);
         assert_almost_eq!(super::digamma(10.1), 2.2622143570941481235561593642219403924532310597356171, 1e-14);
     }
+
+    #[test]
+    fn test_trigamma() {
+        assert!(super::trigamma(f64::NAN).is_nan());
+        assert!(super::trigamma(f64::NEG_INFINITY).is_nan());
+        assert_eq!(super::trigamma(0.0), f64::INFINITY);
+        assert_eq!(super::trigamma(-2.0), f64::INFINITY);
+        assert_almost_eq!(super::trigamma(-1.5), 9.3792466449891237539, 1e-13);
+        assert_almost_eq!(super::trigamma(-0.5), 8.9348022005446793094, 1e-13);
+        assert_almost_eq!(super::trigamma(1e-8), 10000000000000001.645, 10.0);
+        assert_almost_eq!(super::trigamma(0.1), 101.43329915079275882, 1e-12);
+        assert_almost_eq!(super::trigamma(0.5), 4.9348022005446793094, 1e-14);
+        assert_almost_eq!(super::trigamma(1.0), 1.6449340668482264365, 1e-14);
+        assert_almost_eq!(super::trigamma(1.5), 0.93480220054467930942, 1e-14);
+        assert_almost_eq!(super::trigamma(2.0), 0.64493406684822643647, 1e-14);
+        assert_almost_eq!(super::trigamma(3.7), 0.3100378576700383191, 1e-14);
+        assert_almost_eq!(super::trigamma(10.0), 0.10516633568168574612, 1e-15);
+        assert_almost_eq!(super::trigamma(12.5), 0.083285224601578370444, 1e-15);
+        assert_almost_eq!(super::trigamma(100.0), 0.010050166663333571395, 1e-16);
+        assert_almost_eq!(super::trigamma(1e8), 1.0000000050000000167e-8, 1e-22);
+        assert_eq!(super::trigamma(f64::INFINITY), 0.0);
+    }
 }
@@ -0,0 +1,437 @@
+use std::f64;
+use distribution::{FisherSnedecor, Univariate};
+use error::StatsError;
+use result::Result;
+
+/// A row of an analysis of variance table, holding the sum of squares,
+/// degrees of freedom and mean square of a source of variation together
+/// with its F-statistic and p-value
+///
+/// # Remarks
+///
+/// The F-statistic and p-value of the residual row are `f64::NAN`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AnovaRow {
+    sum_of_squares: f64,
+    freedom: f64,
+    mean_square: f64,
+    statistic: f64,
+    p_value: f64,
+}
+
+impl AnovaRow {
+    fn effect(sum_of_squares: f64, freedom: f64, residuals: &AnovaRow) -> AnovaRow {
+        let mean_square = sum_of_squares / freedom;
+        let statistic = mean_square / residuals.mean_square;
+        let p_value = if statistic.is_nan() {
+            f64::NAN
+        } else {
+            FisherSnedecor::new(freedom, residuals.freedom).unwrap().sf(statistic)
+        };
+        AnovaRow {
+            sum_of_squares: sum_of_squares,
+            freedom: freedom,
+            mean_square: mean_square,
+            statistic: statistic,
+            p_value: p_value,
+        }
+    }
+
+    fn residuals(sum_of_squares: f64, freedom: f64) -> AnovaRow {
+        AnovaRow {
+            sum_of_squares: sum_of_squares,
+            freedom: freedom,
+            mean_square: sum_of_squares / freedom,
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+        }
+    }
+
+    /// Returns the sum of squares of the source of variation
+    pub fn sum_of_squares(&self) -> f64 {
+        self.sum_of_squares
+    }
+
+    /// Returns the degrees of freedom of the source of variation
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the mean square of the source of variation, the sum of
+    /// squares divided by the degrees of freedom
+    pub fn mean_square(&self) -> f64 {
+        self.mean_square
+    }
+
+    /// Returns the F-statistic, the mean square divided by the residual
+    /// mean square
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value of the F-statistic
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+/// Implements the [analysis of variance](https://en.wikipedia.org/wiki/Analysis_of_variance)
+/// of whether groups of observations share the same mean, producing an
+/// ANOVA table with a row per effect and a row for the residuals
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::Anova;
+///
+/// let ctrl = [4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14];
+/// let trt1 = [4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69];
+/// let trt2 = [6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26];
+/// let anova = Anova::one_way(&[&ctrl[..], &trt1[..], &trt2[..]]).unwrap();
+/// assert_eq!(anova.effects()[0].freedom(), 2.0);
+/// assert_eq!(anova.residuals().freedom(), 27.0);
+/// assert!(anova.effects()[0].p_value() < 0.05);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anova {
+    effects: Vec<AnovaRow>,
+    residuals: AnovaRow,
+}
+
+impl Anova {
+    /// Performs a one-way analysis of variance of the null hypothesis that
+    /// all `groups` have the same mean, assuming they share the same
+    /// variance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two groups, any group is
+    /// empty or contains `NaN`, or there are no more observations than
+    /// groups
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// SS_groups = Σ_i n_i * (m_i - m)^2
+    /// SS_residuals = Σ_i Σ_j (x_ij - m_i)^2
+    /// ```
+    ///
+    /// where `m_i` is the mean of the `n_i` observations `x_ij` of the `i`th
+    /// group and `m` is the grand mean. For `k` groups and `N` observations
+    /// the effect has `k - 1` and the residuals `N - k` degrees of freedom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::Anova;
+    ///
+    /// let result = Anova::one_way(&[vec![1.0, 2.0], vec![4.0, 6.0]]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = Anova::one_way(&[vec![1.0], vec![4.0]]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn one_way<T>(groups: &[T]) -> Result<Anova>
+        where T: AsRef<[f64]>
+    {
+        check_groups(groups)?;
+        let n = groups.iter().map(|g| g.as_ref().len()).sum::<usize>();
+        if n <= groups.len() {
+            return Err(StatsError::BadParams);
+        }
+        let means: Vec<f64> = groups.iter().map(|g| mean(g.as_ref())).collect();
+        let grand_mean = groups.iter()
+            .zip(means.iter())
+            .map(|(g, m)| g.as_ref().len() as f64 * m)
+            .sum::<f64>() / n as f64;
+
+        let mut between = 0.0;
+        let mut within = 0.0;
+        for (group, &m) in groups.iter().zip(means.iter()) {
+            let group = group.as_ref();
+            between += group.len() as f64 * (m - grand_mean) * (m - grand_mean);
+            within += group.iter().map(|x| (x - m) * (x - m)).sum::<f64>();
+        }
+        let residuals = AnovaRow::residuals(within, (n - groups.len()) as f64);
+        Ok(Anova {
+            effects: vec![AnovaRow::effect(between, (groups.len() - 1) as f64, &residuals)],
+            residuals: residuals,
+        })
+    }
+
+    /// Performs Welch's one-way analysis of variance of the null hypothesis
+    /// that all `groups` have the same mean, without assuming they share the
+    /// same variance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two groups or any group
+    /// contains fewer than two elements, `NaN`, or only equal elements
+    ///
+    /// # Remarks
+    ///
+    /// The table is expressed in terms of the precision weighted group
+    /// means. The sum of squares of the effect is the weighted sum of
+    /// squares below and the residual mean square is the denominator of
+    /// Welch's statistic, so that the ratio of the mean squares is the
+    /// statistic. The residual degrees of freedom are generally not an
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F = Σ_i w_i * (m_i - m_w)^2 / (k - 1) / (1 + 2 * (k - 2) / (k^2 - 1) * Λ)
+    /// Λ = Σ_i (1 - w_i / W)^2 / (n_i - 1)
+    /// ```
+    ///
+    /// where `m_i`, `s_i^2` and `n_i` are the mean, sample variance and size
+    /// of the `i`th group, `w_i = n_i / s_i^2`, `W = Σ_i w_i`,
+    /// `m_w = Σ_i w_i * m_i / W` and `k` is the number of groups. The
+    /// statistic has `k - 1` and `(k^2 - 1) / (3 * Λ)` degrees of freedom
+    pub fn welch<T>(groups: &[T]) -> Result<Anova>
+        where T: AsRef<[f64]>
+    {
+        check_groups(groups)?;
+        let mut weights = Vec::with_capacity(groups.len());
+        let mut means = Vec::with_capacity(groups.len());
+        for group in groups {
+            let group = group.as_ref();
+            if group.len() < 2 {
+                return Err(StatsError::BadParams);
+            }
+            let m = mean(group);
+            let variance = group.iter().map(|x| (x - m) * (x - m)).sum::<f64>() /
+                           (group.len() - 1) as f64;
+            if variance == 0.0 {
+                return Err(StatsError::BadParams);
+            }
+            weights.push(group.len() as f64 / variance);
+            means.push(m);
+        }
+        let total_weight: f64 = weights.iter().sum();
+        let weighted_mean =
+            weights.iter().zip(means.iter()).map(|(w, m)| w * m).sum::<f64>() / total_weight;
+        let between: f64 = weights.iter()
+            .zip(means.iter())
+            .map(|(w, m)| w * (m - weighted_mean) * (m - weighted_mean))
+            .sum();
+        let lambda: f64 = weights.iter()
+            .zip(groups.iter())
+            .map(|(w, g)| (1.0 - w / total_weight).powi(2) / (g.as_ref().len() - 1) as f64)
+            .sum();
+
+        let k = groups.len() as f64;
+        let freedom = (k * k - 1.0) / (3.0 * lambda);
+        let mean_square = 1.0 + 2.0 * (k - 2.0) / (k * k - 1.0) * lambda;
+        let residuals = AnovaRow::residuals(mean_square * freedom, freedom);
+        Ok(Anova {
+            effects: vec![AnovaRow::effect(between, k - 1.0, &residuals)],
+            residuals: residuals,
+        })
+    }
+
+    /// Performs a two-way analysis of variance with interaction of a
+    /// balanced design, where `cells[i][j]` holds the replicates observed
+    /// at the `i`th level of the first factor and the `j`th level of the
+    /// second factor. The effects are the first factor, the second factor
+    /// and their interaction, in that order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either factor has fewer than two levels, the
+    /// rows of `cells` are of different lengths, the cells hold different
+    /// numbers of replicates, any cell holds fewer than two replicates, or
+    /// any replicate is `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// SS_a = b * n * Σ_i (m_i. - m)^2
+    /// SS_b = a * n * Σ_j (m_.j - m)^2
+    /// SS_ab = n * Σ_i Σ_j (m_ij - m_i. - m_.j + m)^2
+    /// SS_residuals = Σ_i Σ_j Σ_k (x_ijk - m_ij)^2
+    /// ```
+    ///
+    /// where the factors have `a` and `b` levels, each cell holds `n`
+    /// replicates `x_ijk` with mean `m_ij`, `m_i.` and `m_.j` are the means
+    /// of the levels of the factors and `m` is the grand mean. The effects
+    /// have `a - 1`, `b - 1` and `(a - 1) * (b - 1)` and the residuals
+    /// `a * b * (n - 1)` degrees of freedom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::Anova;
+    ///
+    /// let cells = vec![vec![vec![4.0, 5.0], vec![6.0, 6.5]],
+    ///                  vec![vec![5.0, 5.5], vec![9.0, 8.0]]];
+    /// let anova = Anova::two_way(&cells).unwrap();
+    /// assert_eq!(anova.effects().len(), 3);
+    /// assert_eq!(anova.residuals().freedom(), 4.0);
+    /// ```
+    pub fn two_way<T, U>(cells: &[T]) -> Result<Anova>
+        where T: AsRef<[U]>,
+              U: AsRef<[f64]>
+    {
+        let a = cells.len();
+        if a < 2 {
+            return Err(StatsError::BadParams);
+        }
+        let b = cells[0].as_ref().len();
+        if cells.iter().any(|row| row.as_ref().len() != b) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if b < 2 {
+            return Err(StatsError::BadParams);
+        }
+        let n = cells[0].as_ref()[0].as_ref().len();
+        if cells.iter().flat_map(|row| row.as_ref()).any(|cell| cell.as_ref().len() != n) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if n < 2 ||
+           cells.iter()
+            .flat_map(|row| row.as_ref())
+            .any(|cell| cell.as_ref().iter().any(|x| x.is_nan())) {
+            return Err(StatsError::BadParams);
+        }
+
+        let cell_means: Vec<Vec<f64>> = cells.iter()
+            .map(|row| row.as_ref().iter().map(|cell| mean(cell.as_ref())).collect())
+            .collect();
+        let row_means: Vec<f64> = cell_means.iter().map(|row| mean(row)).collect();
+        let col_means: Vec<f64> =
+            (0..b).map(|j| cell_means.iter().map(|row| row[j]).sum::<f64>() / a as f64).collect();
+        let grand_mean = mean(&row_means);
+
+        let ss_a = (b * n) as f64 *
+                   row_means.iter().map(|m| (m - grand_mean) * (m - grand_mean)).sum::<f64>();
+        let ss_b = (a * n) as f64 *
+                   col_means.iter().map(|m| (m - grand_mean) * (m - grand_mean)).sum::<f64>();
+        let mut ss_ab = 0.0;
+        let mut ss_e = 0.0;
+        for i in 0..a {
+            for j in 0..b {
+                let m = cell_means[i][j];
+                let d = m - row_means[i] - col_means[j] + grand_mean;
+                ss_ab += n as f64 * d * d;
+                ss_e += cells[i].as_ref()[j].as_ref().iter().map(|x| (x - m) * (x - m)).sum::<f64>();
+            }
+        }
+
+        let residuals = AnovaRow::residuals(ss_e, (a * b * (n - 1)) as f64);
+        Ok(Anova {
+            effects: vec![AnovaRow::effect(ss_a, (a - 1) as f64, &residuals),
+                          AnovaRow::effect(ss_b, (b - 1) as f64, &residuals),
+                          AnovaRow::effect(ss_ab, ((a - 1) * (b - 1)) as f64, &residuals)],
+            residuals: residuals,
+        })
+    }
+
+    /// Returns the rows of the table for the effects
+    pub fn effects(&self) -> &[AnovaRow] {
+        &self.effects
+    }
+
+    /// Returns the row of the table for the residuals
+    pub fn residuals(&self) -> &AnovaRow {
+        &self.residuals
+    }
+}
+
+fn check_groups<T: AsRef<[f64]>>(groups: &[T]) -> Result<()> {
+    if groups.len() < 2 ||
+       groups.iter().any(|g| g.as_ref().is_empty() || g.as_ref().iter().any(|x| x.is_nan())) {
+        Err(StatsError::BadParams)
+    } else {
+        Ok(())
+    }
+}
+
+fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const CTRL: [f64; 10] = [4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14];
+    const TRT1: [f64; 10] = [4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69];
+    const TRT2: [f64; 10] = [6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26];
+
+    fn check(row: &AnovaRow, ss: f64, freedom: f64, statistic: f64, p_value: f64) {
+        assert_almost_eq!(row.sum_of_squares(), ss, 1e-12 * ss);
+        assert_almost_eq!(row.freedom(), freedom, 1e-12 * freedom);
+        assert_almost_eq!(row.mean_square(), ss / freedom, 1e-12 * ss / freedom);
+        assert_almost_eq!(row.statistic(), statistic, 1e-12 * statistic);
+        assert_almost_eq!(row.p_value(), p_value, 1e-14);
+    }
+
+    fn check_residuals(row: &AnovaRow, ss: f64, freedom: f64) {
+        assert_almost_eq!(row.sum_of_squares(), ss, 1e-12 * ss);
+        assert_eq!(row.freedom(), freedom);
+        assert_almost_eq!(row.mean_square(), ss / freedom, 1e-12 * ss / freedom);
+        assert!(row.statistic().is_nan());
+        assert!(row.p_value().is_nan());
+    }
+
+    #[test]
+    fn test_one_way() {
+        let anova = Anova::one_way(&[&CTRL[..], &TRT1[..], &TRT2[..]]).unwrap();
+        assert_eq!(anova.effects().len(), 1);
+        check(&anova.effects()[0], 3.7663399999999995201, 2.0, 4.8460878623801349399, 0.01590995832562292537);
+        check_residuals(anova.residuals(), 10.492090000000000726, 27.0);
+    }
+
+    #[test]
+    fn test_one_way_unequal_sizes() {
+        let anova = Anova::one_way(&[vec![1.0, 2.0, 4.0], vec![3.0, 5.0], vec![6.0, 8.0, 7.0, 9.0]]).unwrap();
+        check(&anova.effects()[0], 48.333333333333333333, 2.0, 12.428571428571428571, 0.0073516803840877914952);
+        check_residuals(anova.residuals(), 11.666666666666666667, 6.0);
+    }
+
+    #[test]
+    fn test_welch() {
+        let anova = Anova::welch(&[&CTRL[..], &TRT1[..], &TRT2[..]]).unwrap();
+        check(&anova.effects()[0], 10.765249018758238497, 2.0, 5.1809724081131875798, 0.017392821490170005785);
+        assert_almost_eq!(anova.residuals().freedom(), 17.128418616644131936, 1e-12);
+        assert!(anova.residuals().statistic().is_nan());
+    }
+
+    #[test]
+    fn test_two_way() {
+        let cells = vec![vec![vec![4.0, 5.0, 6.0], vec![6.0, 6.5, 7.0], vec![5.0, 4.0, 6.5]],
+                         vec![vec![5.0, 5.5, 4.5], vec![9.0, 8.0, 8.5], vec![7.0, 7.5, 6.0]]];
+        let anova = Anova::two_way(&cells).unwrap();
+        assert_eq!(anova.effects().len(), 3);
+        check(&anova.effects()[0], 6.7222222222222222222, 1.0, 10.297872340425531915, 0.0075057092008853609172);
+        check(&anova.effects()[1], 19.0, 2.0, 14.553191489361702128, 0.00061891625845199029977);
+        check(&anova.effects()[2], 3.4444444444444444444, 2.0, 2.6382978723404255319, 0.11228931912221416379);
+        check_residuals(anova.residuals(), 7.8333333333333333333, 12.0);
+    }
+
+    #[test]
+    fn test_constant_data() {
+        let anova = Anova::one_way(&[vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+        assert!(anova.effects()[0].statistic().is_nan());
+        assert!(anova.effects()[0].p_value().is_nan());
+        let anova = Anova::one_way(&[vec![1.0, 1.0], vec![2.0, 2.0]]).unwrap();
+        assert_eq!(anova.effects()[0].statistic(), f64::INFINITY);
+        assert_eq!(anova.effects()[0].p_value(), 0.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(Anova::one_way(&[&CTRL[..]]).is_err());
+        assert!(Anova::one_way(&[&CTRL[..], &[]]).is_err());
+        assert!(Anova::one_way(&[&CTRL[..], &[f64::NAN]]).is_err());
+        assert!(Anova::welch(&[&CTRL[..], &[1.0]]).is_err());
+        assert!(Anova::welch(&[&CTRL[..], &[1.0, 1.0]]).is_err());
+        assert!(Anova::two_way(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]]]).is_err());
+        assert!(Anova::two_way(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 2.0]]]).is_err());
+        assert!(Anova::two_way(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 2.0], vec![3.0]]]).is_err());
+        assert!(Anova::two_way(&[vec![vec![1.0], vec![3.0]], vec![vec![1.0], vec![3.0]]]).is_err());
+    }
+}
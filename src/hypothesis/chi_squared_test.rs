@@ -0,0 +1,254 @@
+use distribution::{ChiSquared, Univariate};
+use error::StatsError;
+use result::Result;
+
+/// Enumeration of possible statistics used to measure the
+/// discrepancy between the observed and expected counts
+/// of a contingency table
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ContingencyStatistic {
+    /// Pearson's chi-squared statistic `Σ (O - E)^2 / E`
+    Pearson,
+    /// The log-likelihood ratio or G-test statistic `2 * Σ O * ln(O / E)`
+    LogLikelihood,
+}
+
+/// Implements [Pearson's chi-squared tests](https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test)
+/// for goodness of fit and for independence in contingency tables
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::{ChiSquaredTest, ContingencyStatistic};
+///
+/// let table = [[10.0, 10.0, 20.0], [20.0, 20.0, 20.0]];
+/// let t = ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, false).unwrap();
+/// assert_eq!(t.freedom(), 2.0);
+/// assert!(t.p_value() > 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChiSquaredTest {
+    statistic: f64,
+    freedom: f64,
+    p_value: f64,
+}
+
+impl ChiSquaredTest {
+    /// Performs Pearson's chi-squared goodness-of-fit test of the null
+    /// hypothesis that the `observed` counts follow the distribution
+    /// given by the `expected` counts
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `observed` and `expected` are of different
+    /// lengths, contain fewer than two categories, any observed count
+    /// is negative or any expected count is not positive
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ (O_i - E_i)^2 / E_i
+    /// ```
+    ///
+    /// where `O_i` and `E_i` are the observed and expected counts. The
+    /// statistic has `k - 1` degrees of freedom for `k` categories
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::ChiSquaredTest;
+    ///
+    /// let observed = [16.0, 18.0, 16.0, 14.0, 12.0, 12.0];
+    /// let expected = [16.0, 16.0, 16.0, 16.0, 16.0, 8.0];
+    /// let t = ChiSquaredTest::goodness_of_fit(&observed, &expected).unwrap();
+    /// assert_eq!(t.statistic(), 3.5);
+    /// ```
+    pub fn goodness_of_fit(observed: &[f64], expected: &[f64]) -> Result<ChiSquaredTest> {
+        if observed.len() != expected.len() {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if observed.len() < 2 || observed.iter().any(|&x| x < 0.0 || x.is_nan()) ||
+           expected.iter().any(|&x| x <= 0.0 || x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let statistic = observed.iter()
+            .zip(expected.iter())
+            .fold(0.0, |acc, (&o, &e)| acc + (o - e) * (o - e) / e);
+        Ok(ChiSquaredTest::from_statistic(statistic, observed.len() as f64 - 1.0))
+    }
+
+    /// Performs a chi-squared test of the null hypothesis that the row and
+    /// column variables of the contingency `table` of observed counts are
+    /// independent, using the given `statistic`. If `correction` is `true`
+    /// and the table has one degree of freedom, Yates' continuity correction
+    /// is applied by moving each observed count `0.5` towards its expected
+    /// count before computing the statistic
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows of `table` are of different lengths,
+    /// the table has fewer than two rows or columns, any count is negative
+    /// or any row or column sums to zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E_ij = (Σ_j O_ij) * (Σ_i O_ij) / N
+    /// ```
+    ///
+    /// where `O_ij` are the observed counts and `N` is the total count. The
+    /// statistic has `(r - 1) * (c - 1)` degrees of freedom for an `r × c`
+    /// table
+    pub fn independence<T>(table: &[T],
+                           statistic: ContingencyStatistic,
+                           correction: bool)
+                           -> Result<ChiSquaredTest>
+        where T: AsRef<[f64]>
+    {
+        if table.len() < 2 {
+            return Err(StatsError::BadParams);
+        }
+        let cols = table[0].as_ref().len();
+        if table.iter().any(|row| row.as_ref().len() != cols) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if cols < 2 || table.iter().any(|row| row.as_ref().iter().any(|&x| x < 0.0 || x.is_nan())) {
+            return Err(StatsError::BadParams);
+        }
+
+        let row_sums: Vec<f64> = table.iter().map(|row| row.as_ref().iter().sum()).collect();
+        let col_sums: Vec<f64> =
+            (0..cols).map(|j| table.iter().map(|row| row.as_ref()[j]).sum()).collect();
+        if row_sums.iter().chain(col_sums.iter()).any(|&x| x == 0.0) {
+            return Err(StatsError::BadParams);
+        }
+        let total: f64 = row_sums.iter().sum();
+        let freedom = ((table.len() - 1) * (cols - 1)) as f64;
+
+        let mut sum = 0.0;
+        for (row, row_sum) in table.iter().zip(row_sums.iter()) {
+            for (&observed, col_sum) in row.as_ref().iter().zip(col_sums.iter()) {
+                let expected = row_sum * col_sum / total;
+                let observed = if correction && freedom == 1.0 {
+                    let diff = observed - expected;
+                    observed - diff.signum() * diff.abs().min(0.5)
+                } else {
+                    observed
+                };
+                sum += match statistic {
+                    ContingencyStatistic::Pearson => {
+                        (observed - expected) * (observed - expected) / expected
+                    }
+                    ContingencyStatistic::LogLikelihood => {
+                        if observed == 0.0 {
+                            0.0
+                        } else {
+                            2.0 * observed * (observed / expected).ln()
+                        }
+                    }
+                };
+            }
+        }
+        Ok(ChiSquaredTest::from_statistic(sum, freedom))
+    }
+
+    fn from_statistic(statistic: f64, freedom: f64) -> ChiSquaredTest {
+        let p_value = if statistic > 0.0 {
+            ChiSquared::new(freedom).unwrap().sf(statistic)
+        } else {
+            1.0
+        };
+        ChiSquaredTest {
+            statistic: statistic,
+            freedom: freedom,
+            p_value: p_value,
+        }
+    }
+
+    /// Returns the chi-squared statistic of the test
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the degrees of freedom of the chi-squared distribution
+    /// the statistic is compared against
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the p-value of the test i.e. the probability of a statistic
+    /// at least as large as the one observed under the null hypothesis
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use hypothesis::*;
+
+    fn check(t: ChiSquaredTest, statistic: f64, freedom: f64, p_value: f64) {
+        assert_almost_eq!(t.statistic(), statistic, 1e-12);
+        assert_eq!(t.freedom(), freedom);
+        assert_almost_eq!(t.p_value(), p_value, 1e-14);
+    }
+
+    #[test]
+    fn test_goodness_of_fit() {
+        check(ChiSquaredTest::goodness_of_fit(&[16.0, 18.0, 16.0, 14.0, 12.0, 12.0], &[16.0, 16.0, 16.0, 16.0, 16.0, 8.0]).unwrap(),
+            3.5, 5.0, 0.62338762774958203461);
+        check(ChiSquaredTest::goodness_of_fit(&[43.0, 52.0, 54.0, 40.0], &[47.25; 4]).unwrap(),
+            2.9365079365079365079, 3.0, 0.40151835272592830094);
+        check(ChiSquaredTest::goodness_of_fit(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]).unwrap(), 0.0, 2.0, 1.0);
+    }
+
+    #[test]
+    fn test_goodness_of_fit_bad_input() {
+        assert!(ChiSquaredTest::goodness_of_fit(&[1.0, 2.0], &[1.0, 2.0, 3.0]).is_err());
+        assert!(ChiSquaredTest::goodness_of_fit(&[1.0], &[1.0]).is_err());
+        assert!(ChiSquaredTest::goodness_of_fit(&[-1.0, 2.0], &[1.0, 2.0]).is_err());
+        assert!(ChiSquaredTest::goodness_of_fit(&[1.0, 2.0], &[0.0, 2.0]).is_err());
+        assert!(ChiSquaredTest::goodness_of_fit(&[1.0, 2.0], &[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_independence() {
+        let table = [[10.0, 10.0, 20.0], [20.0, 20.0, 20.0]];
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, false).unwrap(),
+            2.7777777777777777778, 2.0, 0.24935220877729619882);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, true).unwrap(),
+            2.7777777777777777778, 2.0, 0.24935220877729619882);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::LogLikelihood, false).unwrap(),
+            2.7688587616781242319, 2.0, 0.25046668010954266798);
+
+        let table = vec![vec![0.0, 5.0, 3.0], vec![4.0, 2.0, 6.0], vec![7.0, 1.0, 1.0]];
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, false).unwrap(),
+            14.09264520202020202, 4.0, 0.0070051657787142235638);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::LogLikelihood, false).unwrap(),
+            16.060681142111336251, 4.0, 0.0029388101547182830056);
+    }
+
+    #[test]
+    fn test_independence_yates() {
+        let table: [&[f64]; 2] = [&[12.0, 5.0], &[7.0, 9.0]];
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, false).unwrap(),
+            2.4305755196815568333, 1.0, 0.11898920553214525359);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, true).unwrap(),
+            1.4559963788146837682, 1.0, 0.22756821457580981318);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::LogLikelihood, false).unwrap(),
+            2.4600430077307168725, 1.0, 0.11677558467849599789);
+        check(ChiSquaredTest::independence(&table, ContingencyStatistic::LogLikelihood, true).unwrap(),
+            1.4660007253363233252, 1.0, 0.225977750721884689);
+    }
+
+    #[test]
+    fn test_independence_bad_input() {
+        let table: [&[f64]; 2] = [&[1.0, 2.0], &[1.0]];
+        assert!(ChiSquaredTest::independence(&table, ContingencyStatistic::Pearson, false).is_err());
+        assert!(ChiSquaredTest::independence(&[[1.0, 2.0]], ContingencyStatistic::Pearson, false).is_err());
+        assert!(ChiSquaredTest::independence(&[[1.0], [2.0]], ContingencyStatistic::Pearson, false).is_err());
+        assert!(ChiSquaredTest::independence(&[[1.0, -2.0], [3.0, 4.0]], ContingencyStatistic::Pearson, false).is_err());
+        assert!(ChiSquaredTest::independence(&[[0.0, 2.0], [0.0, 4.0]], ContingencyStatistic::Pearson, false).is_err());
+    }
+}
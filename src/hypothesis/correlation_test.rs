@@ -0,0 +1,244 @@
+use distribution::{Normal, StudentsT};
+use error::StatsError;
+use result::Result;
+use statistics;
+use super::{p_value, tie_sum, Alternative};
+
+/// Implements tests of the null hypothesis that two paired samples are
+/// uncorrelated, based on the [Pearson](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient),
+/// [Spearman](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient)
+/// or [Kendall tau-b](https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient)
+/// correlation coefficients
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::{Alternative, CorrelationTest};
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let y = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+/// let t = CorrelationTest::pearson(&x, &y, Alternative::TwoSided).unwrap();
+/// assert!(t.estimate() > 0.0);
+/// assert!(t.p_value() < 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CorrelationTest {
+    statistic: f64,
+    p_value: f64,
+    estimate: f64,
+}
+
+impl CorrelationTest {
+    /// Performs a test of the null hypothesis that the Pearson correlation
+    /// coefficient of `x` and `y` is zero against the given `alternative`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// fewer than three elements or `NaN`, or either sample is constant
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// t = r * sqrt((n - 2) / (1 - r^2))
+    /// ```
+    ///
+    /// where `r` is the Pearson correlation coefficient. The statistic has
+    /// `n - 2` degrees of freedom under the assumption that the samples
+    /// are normally distributed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::{Alternative, CorrelationTest};
+    ///
+    /// let x = [1.0, 2.0, 3.0, 4.0];
+    /// let result = CorrelationTest::pearson(&x, &[1.0, 3.0, 2.0, 4.0], Alternative::Greater);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = CorrelationTest::pearson(&x, &[1.0, 3.0, 2.0], Alternative::Greater);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn pearson(x: &[f64], y: &[f64], alternative: Alternative) -> Result<CorrelationTest> {
+        check_samples(x, y)?;
+        CorrelationTest::from_estimate(statistics::pearson(x, y), x.len(), alternative)
+    }
+
+    /// Performs a test of the null hypothesis that the Spearman rank
+    /// correlation coefficient of `x` and `y` is zero against the given
+    /// `alternative`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// fewer than three elements or `NaN`, or either sample is constant
+    ///
+    /// # Remarks
+    ///
+    /// The p-value uses the same Student's t approximation as the Pearson
+    /// test applied to the ranks rather than the exact permutation
+    /// distribution, so it is approximate for small samples
+    pub fn spearman(x: &[f64], y: &[f64], alternative: Alternative) -> Result<CorrelationTest> {
+        check_samples(x, y)?;
+        CorrelationTest::from_estimate(statistics::spearman(x, y), x.len(), alternative)
+    }
+
+    /// Performs a test of the null hypothesis that Kendall's tau-b rank
+    /// correlation coefficient of `x` and `y` is zero against the given
+    /// `alternative`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// fewer than three elements or `NaN`, or either sample is constant
+    ///
+    /// # Remarks
+    ///
+    /// The p-value uses the normal approximation of the distribution of
+    /// `S = n_c - n_d`, the difference between the numbers of concordant
+    /// and discordant pairs, with its variance corrected for ties
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// z = S / sqrt((v_0 - v_t - v_u) / 18 + v_1 / (2 * n * (n - 1)) + v_2 / (9 * n * (n - 1) * (n - 2)))
+    /// ```
+    ///
+    /// where `v_0 = n * (n - 1) * (2 * n + 5)`, `v_t` and `v_u` are the
+    /// sums of `t * (t - 1) * (2 * t + 5)` over the sizes `t` of the groups
+    /// of ties in `x` and in `y`, `v_1` is the product of the sums of
+    /// `t * (t - 1)` and `v_2` the product of the sums of
+    /// `t * (t - 1) * (t - 2)` over the groups of ties in `x` and in `y`
+    pub fn kendall(x: &[f64], y: &[f64], alternative: Alternative) -> Result<CorrelationTest> {
+        check_samples(x, y)?;
+        let tau = statistics::kendall(x, y);
+        if tau.is_nan() {
+            return Err(StatsError::BadParams);
+        }
+
+        let n = x.len() as f64;
+        let pairs = |t: f64| t * (t - 1.0);
+        let triples = |t: f64| t * (t - 1.0) * (t - 2.0);
+        let spread = |t: f64| t * (t - 1.0) * (2.0 * t + 5.0);
+        let (px, py) = (tie_sum(x, pairs), tie_sum(y, pairs));
+        let n0 = n * (n - 1.0) / 2.0;
+        let s = tau * ((n0 - px / 2.0) * (n0 - py / 2.0)).sqrt();
+        let variance = (n * (n - 1.0) * (2.0 * n + 5.0) - tie_sum(x, spread) - tie_sum(y, spread)) / 18.0 +
+                       px * py / (2.0 * n * (n - 1.0)) +
+                       tie_sum(x, triples) * tie_sum(y, triples) / (9.0 * n * (n - 1.0) * (n - 2.0));
+        let statistic = s / variance.sqrt();
+        Ok(CorrelationTest {
+            statistic: statistic,
+            p_value: p_value(&Normal::new(0.0, 1.0).unwrap(), statistic, alternative),
+            estimate: tau,
+        })
+    }
+
+    fn from_estimate(r: f64, n: usize, alternative: Alternative) -> Result<CorrelationTest> {
+        if r.is_nan() {
+            return Err(StatsError::BadParams);
+        }
+        let freedom = n as f64 - 2.0;
+        let statistic = r * (freedom / (1.0 - r * r)).sqrt();
+        let dist = StudentsT::new(0.0, 1.0, freedom).unwrap();
+        Ok(CorrelationTest {
+            statistic: statistic,
+            p_value: p_value(&dist, statistic, alternative),
+            estimate: r,
+        })
+    }
+
+    /// Returns the statistic of the test, a t-statistic for the Pearson
+    /// and Spearman tests and a z-score for the Kendall test
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value of the test under the chosen alternative
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    /// Returns the estimated correlation coefficient
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+}
+
+fn check_samples(x: &[f64], y: &[f64]) -> Result<()> {
+    if x.len() != y.len() {
+        Err(StatsError::ContainersMustBeSameLength)
+    } else if x.len() < 3 || x.iter().chain(y.iter()).any(|v| v.is_nan()) {
+        Err(StatsError::BadParams)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const X: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    const Y: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+    const A: [f64; 12] = [1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0, 5.0, 6.0, 7.0, 7.0, 8.0];
+    const B: [f64; 12] = [2.0, 1.0, 3.0, 3.0, 5.0, 4.0, 4.0, 7.0, 6.0, 6.0, 9.0, 8.0];
+
+    fn check(t: CorrelationTest, estimate: f64, statistic: f64, p_value: f64) {
+        assert_almost_eq!(t.estimate(), estimate, 1e-14);
+        assert_almost_eq!(t.statistic(), statistic, 1e-13);
+        assert_almost_eq!(t.p_value(), p_value, 1e-11);
+    }
+
+    #[test]
+    fn test_pearson() {
+        check(CorrelationTest::pearson(&X, &Y, Alternative::TwoSided).unwrap(),
+            0.79517020583357784031, 3.7089904278041077889, 0.0059649957969184591009);
+        check(CorrelationTest::pearson(&X, &Y, Alternative::Less).unwrap(),
+            0.79517020583357784031, 3.7089904278041077889, 0.99701750210154077045);
+        check(CorrelationTest::pearson(&X, &Y, Alternative::Greater).unwrap(),
+            0.79517020583357784031, 3.7089904278041077889, 0.0029824978984592295504);
+        check(CorrelationTest::pearson(&A, &B, Alternative::TwoSided).unwrap(),
+            0.91310364738976981169, 7.0819043023655692934, 0.000033674355686956913844);
+    }
+
+    #[test]
+    fn test_spearman() {
+        check(CorrelationTest::spearman(&X, &Y, Alternative::TwoSided).unwrap(),
+            0.78181818181818181818, 3.5465802250219868391, 0.007547007781067862544);
+        check(CorrelationTest::spearman(&X, &Y, Alternative::Greater).unwrap(),
+            0.78181818181818181818, 3.5465802250219868391, 0.003773503890533931272);
+        check(CorrelationTest::spearman(&A, &B, Alternative::TwoSided).unwrap(),
+            0.93784635197303870496, 8.5455242619740181996, 6.5774170900058574092e-6);
+    }
+
+    #[test]
+    fn test_kendall() {
+        check(CorrelationTest::kendall(&X, &Y, Alternative::TwoSided).unwrap(),
+            0.6, 2.4149534156997728721, 0.015737222266311005784);
+        check(CorrelationTest::kendall(&X, &Y, Alternative::Less).unwrap(),
+            0.6, 2.4149534156997728721, 0.99213138886684449711);
+        check(CorrelationTest::kendall(&X, &Y, Alternative::Greater).unwrap(),
+            0.6, 2.4149534156997728721, 0.0078686111331555028918);
+        check(CorrelationTest::kendall(&A, &B, Alternative::TwoSided).unwrap(),
+            0.82268766144290636642, 3.5687268404800967113, 0.00035872019040935771905);
+    }
+
+    #[test]
+    fn test_perfect_correlation() {
+        let t = CorrelationTest::pearson(&X, &X, Alternative::TwoSided).unwrap();
+        assert_eq!(t.estimate(), 1.0);
+        assert_eq!(t.p_value(), 0.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(CorrelationTest::pearson(&X, &Y[..9], Alternative::TwoSided).is_err());
+        assert!(CorrelationTest::spearman(&X[..2], &Y[..2], Alternative::TwoSided).is_err());
+        assert!(CorrelationTest::kendall(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0], Alternative::TwoSided).is_err());
+        assert!(CorrelationTest::pearson(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], Alternative::TwoSided).is_err());
+        assert!(CorrelationTest::kendall(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], Alternative::TwoSided).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use distribution::{ChiSquared, Univariate};
+use error::StatsError;
+use result::Result;
+use statistics::{RankTieBreaker, Statistics};
+use super::tie_sum;
+
+/// Largest pooled sample size for which the test computes an exact p-value
+const EXACT_MAX: usize = 20;
+
+/// Largest number of groups for which the test computes an exact p-value
+const EXACT_MAX_GROUPS: usize = 3;
+
+/// Implements the [Kruskal-Wallis H test](https://en.wikipedia.org/wiki/Kruskal%E2%80%93Wallis_one-way_analysis_of_variance)
+/// of whether two or more independent samples were drawn from the same
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::KruskalWallisTest;
+///
+/// let x = [2.9, 3.0, 2.5, 2.6, 3.2];
+/// let y = [3.8, 2.7, 4.0, 2.4];
+/// let z = [2.8, 3.4, 3.7, 2.2, 2.0];
+/// let t = KruskalWallisTest::new(&[&x[..], &y[..], &z[..]]).unwrap();
+/// assert_eq!(t.freedom(), 2.0);
+/// assert!(t.p_value() > 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KruskalWallisTest {
+    statistic: f64,
+    freedom: f64,
+    p_value: f64,
+}
+
+impl KruskalWallisTest {
+    /// Performs a Kruskal-Wallis test of the null hypothesis that all
+    /// `groups` were drawn from the same distribution against the
+    /// alternative that at least one of them is stochastically larger than
+    /// another
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two groups, any group is
+    /// empty or contains `NaN`, or all elements are equal
+    ///
+    /// # Remarks
+    ///
+    /// The p-value is exact if there are at most `3` groups with fewer than
+    /// `20` elements in total and there are no ties. Otherwise it is taken
+    /// from the chi-squared approximation of the null distribution, which is
+    /// only approximate for very small groups, and a statistic of zero or
+    /// less gives a p-value of `1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// H = (12 / (N * (N + 1)) * Σ(R_i^2 / n_i) - 3 * (N + 1)) / (1 - Σ(t^3 - t) / (N^3 - N))
+    /// ```
+    ///
+    /// where `R_i` is the sum of the ranks of the `n_i` elements of the
+    /// `i`th group in the pooled sample of size `N` and `t` are the sizes of
+    /// the groups of ties in the pooled sample. The statistic has `k - 1`
+    /// degrees of freedom for `k` groups
+    pub fn new<T>(groups: &[T]) -> Result<KruskalWallisTest>
+        where T: AsRef<[f64]>
+    {
+        if groups.len() < 2 ||
+           groups.iter().any(|g| g.as_ref().is_empty() || g.as_ref().iter().any(|x| x.is_nan())) {
+            return Err(StatsError::BadParams);
+        }
+        let pooled: Vec<f64> = groups.iter().flat_map(|g| g.as_ref().iter().cloned()).collect();
+        let n = pooled.len() as f64;
+        let ties = tie_sum(&pooled, |t| t * t * t - t);
+        if ties == n * n * n - n {
+            return Err(StatsError::BadParams);
+        }
+
+        let ranks = pooled.ranks_copy(RankTieBreaker::Average);
+        let mut start = 0;
+        let mut sum = 0.0;
+        let mut rank_sums = Vec::with_capacity(groups.len());
+        for group in groups {
+            let len = group.as_ref().len();
+            let rank_sum: f64 = ranks[start..start + len].iter().sum();
+            sum += rank_sum * rank_sum / len as f64;
+            rank_sums.push(rank_sum as usize);
+            start += len;
+        }
+        let statistic = (12.0 / (n * (n + 1.0)) * sum - 3.0 * (n + 1.0)) /
+                        (1.0 - ties / (n * n * n - n));
+        let freedom = (groups.len() - 1) as f64;
+        let p_value = if groups.len() <= EXACT_MAX_GROUPS && pooled.len() < EXACT_MAX &&
+                         ties == 0.0 {
+            let sizes: Vec<usize> = groups.iter().map(|g| g.as_ref().len()).collect();
+            exact_p_value(&sizes, &rank_sums)
+        } else if statistic > 0.0 {
+            ChiSquared::new(freedom).unwrap().sf(statistic)
+        } else {
+            1.0
+        };
+        Ok(KruskalWallisTest {
+            statistic: statistic,
+            freedom: freedom,
+            p_value: p_value,
+        })
+    }
+
+    /// Returns the statistic `H` of the test
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the degrees of freedom of the chi-squared approximation of
+    /// the null distribution
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the p-value of the test
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+// returns the probability that Σ(R_i^2 / n_i), and so H, is at least as
+// large as for the observed rank sums when the ranks 1..N without ties are
+// assigned to groups of the given sizes in all possible ways. The states of
+// the recursion over the ranks hold the size and rank sum of every group
+// filled so far, and Σ(R_i^2 / n_i) is scaled to an integer by the least
+// common multiple of the sizes so that equal statistics compare equal
+fn exact_p_value(sizes: &[usize], rank_sums: &[usize]) -> f64 {
+    let k = sizes.len();
+    let n: usize = sizes.iter().sum();
+    let mut states: HashMap<Vec<usize>, f64> = HashMap::new();
+    states.insert(vec![0; 2 * k], 1.0);
+    for rank in 1..n + 1 {
+        let mut next = HashMap::with_capacity(states.len() * k);
+        for (state, count) in states {
+            for i in 0..k {
+                if state[i] < sizes[i] {
+                    let mut filled = state.clone();
+                    filled[i] += 1;
+                    filled[k + i] += rank;
+                    *next.entry(filled).or_insert(0.0) += count;
+                }
+            }
+        }
+        states = next;
+    }
+
+    let multiple = sizes.iter().fold(1, |acc, &size| acc / gcd(acc, size) * size);
+    let score = |sums: &[usize]| -> usize {
+        sums.iter().zip(sizes.iter()).map(|(&r, &size)| r * r * (multiple / size)).sum()
+    };
+    let observed = score(rank_sums);
+    let mut total = 0.0;
+    let mut tail = 0.0;
+    for (state, count) in states {
+        total += count;
+        if score(&state[k..]) >= observed {
+            tail += count;
+        }
+    }
+    tail / total
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const X: [f64; 5] = [2.9, 3.0, 2.5, 2.6, 3.2];
+    const Y: [f64; 4] = [3.8, 2.7, 4.0, 2.4];
+    const Z: [f64; 5] = [2.8, 3.4, 3.7, 2.2, 2.0];
+
+    #[test]
+    fn test_kruskal_wallis() {
+        let t = KruskalWallisTest::new(&[&X[..], &Y[..], &Z[..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 0.77142857142857142857, 1e-14);
+        assert_eq!(t.freedom(), 2.0);
+        assert_almost_eq!(t.p_value(), 0.71077335363049648764, 1e-14);
+    }
+
+    #[test]
+    fn test_exact() {
+        // critical values from published tables of the exact distribution
+        let t = KruskalWallisTest::new(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        assert_almost_eq!(t.statistic(), 4.5714285714285714286, 1e-14);
+        assert_almost_eq!(t.p_value(), 1.0 / 15.0, 1e-15);
+
+        let t = KruskalWallisTest::new(&[vec![1.0, 2.0, 3.0], vec![4.0, 6.0, 8.0], vec![5.0, 7.0, 9.0]]).unwrap();
+        assert_almost_eq!(t.statistic(), 5.6, 1e-14);
+        assert_almost_eq!(t.p_value(), 0.05, 1e-15);
+
+        let t = KruskalWallisTest::new(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]]).unwrap();
+        assert_almost_eq!(t.statistic(), 7.2, 1e-14);
+        assert_almost_eq!(t.p_value(), 1.0 / 280.0, 1e-15);
+
+        let a = [1.0, 2.0, 3.0, 7.0, 10.0];
+        let b = [4.0, 5.0, 8.0, 9.0, 14.0];
+        let c = [6.0, 11.0, 12.0, 13.0, 15.0];
+        let t = KruskalWallisTest::new(&[&a[..], &b[..], &c[..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 5.78, 1e-13);
+        assert_almost_eq!(t.p_value(), 0.048776620205191633763, 1e-15);
+
+        let a = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let b = [5.0, 6.0, 7.0, 8.0, 14.0];
+        let c = [9.0, 11.0, 12.0, 13.0, 15.0];
+        let t = KruskalWallisTest::new(&[&a[..], &b[..], &c[..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 8.0, 1e-13);
+        assert_almost_eq!(t.p_value(), 0.0094587951730808873666, 1e-15);
+    }
+
+    #[test]
+    fn test_chi_squared_approximation() {
+        let w = [3.5, 3.9, 2.1, 3.3];
+        let t = KruskalWallisTest::new(&[&X[..], &Y[..], &Z[..], &w[..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 1.5508771929824561404, 1e-14);
+        assert_eq!(t.freedom(), 3.0);
+        assert_almost_eq!(t.p_value(), 0.67057864510633096555, 1e-14);
+    }
+
+    #[test]
+    fn test_ties() {
+        let a = [1.0, 2.0, 2.0, 4.0, 5.0];
+        let b = [2.0, 4.0, 6.0, 7.0, 7.0, 9.0];
+        let c = [5.0, 8.0, 9.0, 9.0];
+        let d = [3.0, 1.0, 10.0];
+        let t = KruskalWallisTest::new(&[&a[..], &b[..], &c[..], &d[..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 6.2103883664228491815, 1e-13);
+        assert_eq!(t.freedom(), 3.0);
+        assert_almost_eq!(t.p_value(), 0.10181115841656719882, 1e-14);
+    }
+
+    #[test]
+    fn test_two_groups() {
+        let t = KruskalWallisTest::new(&[vec![1.0, 3.0, 4.0], vec![2.0, 5.0, 6.0, 7.0]]).unwrap();
+        assert_almost_eq!(t.statistic(), 2.0, 1e-14);
+        assert_eq!(t.freedom(), 1.0);
+        assert_almost_eq!(t.p_value(), 0.22857142857142857143, 1e-14);
+    }
+
+    #[test]
+    fn test_equal_rank_sums() {
+        let t = KruskalWallisTest::new(&[&[1.0, 2.0, 3.0][..], &[3.0, 2.0, 1.0][..]]).unwrap();
+        assert_almost_eq!(t.statistic(), 0.0, 1e-14);
+        assert_eq!(t.p_value(), 1.0);
+
+        let t = KruskalWallisTest::new(&[vec![1.0, 6.0], vec![2.0, 5.0], vec![3.0, 4.0]]).unwrap();
+        assert_almost_eq!(t.statistic(), 0.0, 1e-14);
+        assert_eq!(t.p_value(), 1.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(KruskalWallisTest::new(&[&X[..]]).is_err());
+        assert!(KruskalWallisTest::new(&[&X[..], &[]]).is_err());
+        assert!(KruskalWallisTest::new(&[&X[..], &[f64::NAN]]).is_err());
+        assert!(KruskalWallisTest::new(&[&[1.0, 1.0][..], &[1.0]]).is_err());
+    }
+}
@@ -0,0 +1,346 @@
+use std::f64;
+use distribution::{Kolmogorov, Univariate};
+use error::StatsError;
+use result::Result;
+
+/// Largest sample size for which the one-sample test computes
+/// an exact p-value
+const ONE_SAMPLE_EXACT_MAX: usize = 100;
+
+/// Largest product of the sample sizes for which the two-sample
+/// test computes an exact p-value
+const TWO_SAMPLE_EXACT_MAX: usize = 10000;
+
+/// Implements the [Kolmogorov-Smirnov test](https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test)
+/// of whether a sample was drawn from a reference distribution or whether
+/// two samples were drawn from the same distribution
+///
+/// # Remarks
+///
+/// Only the two-sided alternative is supported. Exact p-values are computed
+/// for small samples and the asymptotic [Kolmogorov](../distribution/struct.Kolmogorov.html)
+/// distribution is used otherwise. The exact p-values assume the data
+/// contains no ties
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::hypothesis::KolmogorovSmirnovTest;
+///
+/// let data = [0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64];
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let t = KolmogorovSmirnovTest::one_sample(&data, &n).unwrap();
+/// assert!(t.p_value() > 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KolmogorovSmirnovTest {
+    statistic: f64,
+    p_value: f64,
+}
+
+impl KolmogorovSmirnovTest {
+    /// Performs a one-sample Kolmogorov-Smirnov test of the null hypothesis
+    /// that `data` was drawn from the distribution `dist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty or contains `NaN`
+    ///
+    /// # Panics
+    ///
+    /// If the cumulative distribution function of `dist` panics for
+    /// any element of `data`
+    ///
+    /// # Remarks
+    ///
+    /// The p-value is exact for fewer than `100` samples, computed using
+    /// the method of Marsaglia, Tsang and Wang (2003), and taken from
+    /// the asymptotic distribution of `sqrt(n) * D` otherwise
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// D = max_i(max(i / n - F(x_(i)), F(x_(i)) - (i - 1) / n))
+    /// ```
+    ///
+    /// where `F` is the cumulative distribution function of `dist` and
+    /// `x_(i)` is the `i`th smallest element of `data`
+    pub fn one_sample<D>(data: &[f64], dist: &D) -> Result<KolmogorovSmirnovTest>
+        where D: Univariate<f64, f64>
+    {
+        let sorted = sorted_copy(data)?;
+        let n = sorted.len() as f64;
+        let statistic = sorted.iter().enumerate().fold(0.0f64, |acc, (i, &x)| {
+            let cdf = dist.cdf(x);
+            acc.max((i + 1) as f64 / n - cdf).max(cdf - i as f64 / n)
+        });
+
+        let p_value = if sorted.len() < ONE_SAMPLE_EXACT_MAX {
+            1.0 - one_sample_exact_cdf(sorted.len(), statistic)
+        } else {
+            Kolmogorov.sf(n.sqrt() * statistic)
+        };
+        Ok(KolmogorovSmirnovTest {
+            statistic: statistic,
+            p_value: p_value.max(0.0).min(1.0),
+        })
+    }
+
+    /// Performs a two-sample Kolmogorov-Smirnov test of the null hypothesis
+    /// that `x` and `y` were drawn from the same continuous distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` or `y` is empty or contains `NaN`
+    ///
+    /// # Remarks
+    ///
+    /// The p-value is exact if the product of the sample sizes is less
+    /// than `10000` and taken from the asymptotic distribution of
+    /// `sqrt(n * m / (n + m)) * D` otherwise
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// D = max_t |F_x(t) - F_y(t)|
+    /// ```
+    ///
+    /// where `F_x` and `F_y` are the empirical distribution functions
+    /// of `x` and `y`
+    pub fn two_sample(x: &[f64], y: &[f64]) -> Result<KolmogorovSmirnovTest> {
+        let x = sorted_copy(x)?;
+        let y = sorted_copy(y)?;
+        let n = x.len() as f64;
+        let m = y.len() as f64;
+
+        let mut statistic = 0.0f64;
+        let mut i = 0;
+        let mut j = 0;
+        while i < x.len() && j < y.len() {
+            let t = x[i].min(y[j]);
+            while i < x.len() && x[i] <= t {
+                i += 1;
+            }
+            while j < y.len() && y[j] <= t {
+                j += 1;
+            }
+            statistic = statistic.max((i as f64 / n - j as f64 / m).abs());
+        }
+
+        let p_value = if x.len() * y.len() < TWO_SAMPLE_EXACT_MAX {
+            1.0 - two_sample_exact_cdf(x.len(), y.len(), statistic)
+        } else {
+            Kolmogorov.sf((n * m / (n + m)).sqrt() * statistic)
+        };
+        Ok(KolmogorovSmirnovTest {
+            statistic: statistic,
+            p_value: p_value.max(0.0).min(1.0),
+        })
+    }
+
+    /// Returns the Kolmogorov-Smirnov statistic `D` of the test
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value of the test i.e. the probability of a statistic
+    /// at least as large as the one observed under the null hypothesis
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+fn sorted_copy(data: &[f64]) -> Result<Vec<f64>> {
+    if data.is_empty() || data.iter().any(|x| x.is_nan()) {
+        return Err(StatsError::BadParams);
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(sorted)
+}
+
+/// Computes `P(D_n < d)` for the one-sample statistic of a sample of size
+/// `n`. Implementation adapted from "Evaluating Kolmogorov's Distribution",
+/// Marsaglia, Tsang and Wang, Journal of Statistical Software, 2003
+fn one_sample_exact_cdf(n: usize, d: f64) -> f64 {
+    let nd = n as f64 * d;
+    let k = nd as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nd;
+
+    let mut mat = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 >= j {
+                mat[i * m + j] = 1.0;
+            }
+        }
+    }
+    for i in 0..m {
+        mat[i * m] -= h.powi(i as i32 + 1);
+        mat[(m - 1) * m + i] -= h.powi((m - i) as i32);
+    }
+    if 2.0 * h - 1.0 > 0.0 {
+        mat[(m - 1) * m] += (2.0 * h - 1.0).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 > j {
+                for g in 1..(i + 2 - j) {
+                    mat[i * m + j] /= g as f64;
+                }
+            }
+        }
+    }
+
+    let (pow, mut exp) = matrix_power(&mat, m, n);
+    let mut s = pow[(k - 1) * m + k - 1];
+    for i in 1..(n + 1) {
+        s = s * i as f64 / n as f64;
+        if s < 1e-140 {
+            s *= 1e140;
+            exp -= 140;
+        }
+    }
+    s * 10f64.powi(exp)
+}
+
+/// Raises the `m × m` matrix `a` to the power `n`, returning the result
+/// scaled by `10^-exp` along with `exp` to avoid overflow
+fn matrix_power(a: &[f64], m: usize, n: usize) -> (Vec<f64>, i32) {
+    if n == 1 {
+        return (a.to_vec(), 0);
+    }
+    let (half, half_exp) = matrix_power(a, m, n / 2);
+    let squared = matrix_multiply(&half, &half, m);
+    let (mut result, mut exp) = if n % 2 == 0 {
+        (squared, 2 * half_exp)
+    } else {
+        (matrix_multiply(a, &squared, m), 2 * half_exp)
+    };
+    if result[(m / 2) * m + m / 2] > 1e140 {
+        for x in &mut result {
+            *x *= 1e-140;
+        }
+        exp += 140;
+    }
+    (result, exp)
+}
+
+fn matrix_multiply(a: &[f64], b: &[f64], m: usize) -> Vec<f64> {
+    let mut c = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            c[i * m + j] = (0..m).fold(0.0, |acc, k| acc + a[i * m + k] * b[k * m + j]);
+        }
+    }
+    c
+}
+
+/// Computes `P(D < d)` for the two-sample statistic of samples of size
+/// `n` and `m` by counting the lattice paths that stay within `d` of
+/// the diagonal, normalized at each step to avoid overflow
+fn two_sample_exact_cdf(n: usize, m: usize, d: f64) -> f64 {
+    let (n, m) = if n > m { (m, n) } else { (n, m) };
+    let nd = n as f64;
+    let md = m as f64;
+    // guards against the statistic being computed slightly below a
+    // lattice value due to rounding
+    let q = (0.5 + (d * nd * md - 1e-7).floor()) / (nd * md);
+
+    let mut u: Vec<f64> = (0..(m + 1)).map(|j| if j as f64 / md > q { 0.0 } else { 1.0 }).collect();
+    for i in 1..(n + 1) {
+        let w = i as f64 / (i + m) as f64;
+        u[0] = if i as f64 / nd > q { 0.0 } else { w * u[0] };
+        for j in 1..(m + 1) {
+            u[j] = if (i as f64 / nd - j as f64 / md).abs() > q {
+                0.0
+            } else {
+                w * u[j] + u[j - 1]
+            };
+        }
+    }
+    u[m]
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use distribution::{Kolmogorov, Normal, Uniform, Univariate};
+    use hypothesis::*;
+
+    const X: [f64; 25] = [0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64, 0.05, -0.06, 0.64,
+                          -0.82, 0.37, 1.77, 1.09, -1.28, 2.36, 1.31, 1.05, -0.32, -0.4, 1.06, -2.47];
+    const Y: [f64; 20] = [2.20, 1.66, 1.38, 0.20, 0.36, 0.00, 0.96, 1.56, 0.44, 1.50, -0.30, 0.66, 2.31,
+                          3.29, -0.27, -0.37, 0.38, 0.70, 0.52, -0.71];
+
+    #[test]
+    fn test_one_sample() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        let t = KolmogorovSmirnovTest::one_sample(&X, &n).unwrap();
+        assert_almost_eq!(t.statistic(), 0.17409188119887732407, 1e-15);
+        assert_almost_eq!(t.p_value(), 0.38974117186791828933, 1e-13);
+        let t = KolmogorovSmirnovTest::one_sample(&Y, &n).unwrap();
+        assert_almost_eq!(t.statistic(), 0.34057643321799123734, 1e-15);
+        assert_almost_eq!(t.p_value(), 0.014219149493419167541, 1e-13);
+        let t = KolmogorovSmirnovTest::one_sample(&X[..5], &n).unwrap();
+        assert_almost_eq!(t.statistic(), 0.32392218265410684308, 1e-15);
+        assert_almost_eq!(t.p_value(), 0.56955272164990540579, 1e-13);
+    }
+
+    #[test]
+    fn test_one_sample_asymptotic() {
+        let data: Vec<f64> = (0..150).map(|i| (i as f64 * 1.7).sin() * 1.5 + 0.1).collect();
+        let n = Normal::new(0.0, 1.0).unwrap();
+        let t = KolmogorovSmirnovTest::one_sample(&data, &n).unwrap();
+        assert_almost_eq!(t.statistic(), 0.14534926690995770279, 1e-10);
+        assert_almost_eq!(t.p_value(), 0.0035359416277053633083, 1e-10);
+    }
+
+    #[test]
+    fn test_one_sample_uniform() {
+        let u = Uniform::new(0.0, 1.0).unwrap();
+        let t = KolmogorovSmirnovTest::one_sample(&[0.5], &u).unwrap();
+        assert_eq!(t.statistic(), 0.5);
+        assert_almost_eq!(t.p_value(), 1.0, 1e-15);
+        let t = KolmogorovSmirnovTest::one_sample(&[0.1, 0.2], &u).unwrap();
+        assert_almost_eq!(t.statistic(), 0.8, 1e-15);
+        // P(D_2 >= 0.8) = 2 * (1 - 0.8)^2
+        assert_almost_eq!(t.p_value(), 0.08, 1e-14);
+    }
+
+    #[test]
+    fn test_two_sample() {
+        let t = KolmogorovSmirnovTest::two_sample(&X, &Y).unwrap();
+        assert_almost_eq!(t.statistic(), 0.23, 1e-15);
+        assert_almost_eq!(t.p_value(), 0.52863644481450110317, 1e-13);
+        let t = KolmogorovSmirnovTest::two_sample(&Y, &X).unwrap();
+        assert_almost_eq!(t.statistic(), 0.23, 1e-15);
+        assert_almost_eq!(t.p_value(), 0.52863644481450110317, 1e-13);
+        let t = KolmogorovSmirnovTest::two_sample(&X[..8], &Y[..6]).unwrap();
+        assert_eq!(t.statistic(), 0.5);
+        assert_almost_eq!(t.p_value(), 0.3016983016983016983, 1e-14);
+        let t = KolmogorovSmirnovTest::two_sample(&X, &X).unwrap();
+        assert_eq!(t.statistic(), 0.0);
+        assert_eq!(t.p_value(), 1.0);
+    }
+
+    #[test]
+    fn test_two_sample_asymptotic() {
+        let x: Vec<f64> = (0..120).map(|i| (i as f64 * 1.7).sin()).collect();
+        let y: Vec<f64> = (0..100).map(|i| (i as f64 * 2.3).cos() + 0.2).collect();
+        let t = KolmogorovSmirnovTest::two_sample(&x, &y).unwrap();
+        let n = 120.0 * 100.0 / 220.0f64;
+        assert_eq!(t.p_value(), Kolmogorov.sf(n.sqrt() * t.statistic()));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(KolmogorovSmirnovTest::one_sample(&[], &n).is_err());
+        assert!(KolmogorovSmirnovTest::one_sample(&[1.0, f64::NAN], &n).is_err());
+        assert!(KolmogorovSmirnovTest::two_sample(&[], &X).is_err());
+        assert!(KolmogorovSmirnovTest::two_sample(&X, &[f64::NAN]).is_err());
+    }
+}
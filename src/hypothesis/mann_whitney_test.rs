@@ -0,0 +1,186 @@
+use distribution::Normal;
+use error::StatsError;
+use result::Result;
+use statistics::{RankTieBreaker, Statistics};
+use super::{continuity_corrected, exact_p_value, p_value, tie_sum, Alternative};
+
+/// Largest sample size for which the test computes an exact p-value
+const EXACT_MAX: usize = 50;
+
+/// Implements the [Mann-Whitney U test](https://en.wikipedia.org/wiki/Mann%E2%80%93Whitney_U_test),
+/// also known as the Wilcoxon rank-sum test, of whether two independent
+/// samples were drawn from distributions with the same location
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::{Alternative, MannWhitneyTest};
+///
+/// let x = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+/// let y = [1.15, 0.88, 0.90, 0.74, 1.21];
+/// let t = MannWhitneyTest::new(&x, &y, Alternative::Greater).unwrap();
+/// assert_eq!(t.statistic(), 35.0);
+/// assert!(t.p_value() > 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MannWhitneyTest {
+    statistic: f64,
+    p_value: f64,
+}
+
+impl MannWhitneyTest {
+    /// Performs a Mann-Whitney U test of the null hypothesis that `x` and
+    /// `y` were drawn from the same distribution against the given
+    /// `alternative` that the distribution of `x` is shifted relative to
+    /// that of `y`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` or `y` is empty or contains `NaN`
+    ///
+    /// # Remarks
+    ///
+    /// The p-value is exact if both samples have fewer than `50` elements
+    /// and there are no ties. Otherwise it uses the normal approximation
+    /// with a continuity correction and the variance corrected for ties
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// U = R_x - n * (n + 1) / 2
+    /// z = (U - n * m / 2 ± 1 / 2) / sqrt(n * m / 12 * (n + m + 1 - Σ(t^3 - t) / ((n + m) * (n + m - 1))))
+    /// ```
+    ///
+    /// where `R_x` is the sum of the ranks of `x` in the pooled sample, `n`
+    /// and `m` are the sizes of `x` and `y`, and `t` are the sizes of the
+    /// groups of ties in the pooled sample
+    pub fn new(x: &[f64], y: &[f64], alternative: Alternative) -> Result<MannWhitneyTest> {
+        if x.is_empty() || y.is_empty() || x.iter().chain(y.iter()).any(|v| v.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mut pooled = x.to_vec();
+        pooled.extend_from_slice(y);
+        let ranks = pooled.ranks_copy(RankTieBreaker::Average);
+        let n = x.len() as f64;
+        let m = y.len() as f64;
+        let statistic = ranks[..x.len()].iter().sum::<f64>() - n * (n + 1.0) / 2.0;
+        let ties = tie_sum(&pooled, |t| t * t * t - t);
+
+        let p_value = if x.len() < EXACT_MAX && y.len() < EXACT_MAX && ties == 0.0 {
+            exact_p_value(&null_pmf(x.len(), y.len()), statistic as usize, alternative)
+        } else {
+            let total = n + m;
+            let variance = n * m / 12.0 * (total + 1.0 - ties / (total * (total - 1.0)));
+            let z = continuity_corrected(statistic - n * m / 2.0, alternative) / variance.sqrt();
+            p_value(&Normal::new(0.0, 1.0).unwrap(), z, alternative)
+        };
+        Ok(MannWhitneyTest {
+            statistic: statistic,
+            p_value: p_value,
+        })
+    }
+
+    /// Returns the statistic `U` of the test, the number of pairs in which
+    /// the element of `x` is greater than the element of `y`, counting ties
+    /// as one half
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value of the test under the chosen alternative
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+// returns the probability mass function of U for samples of sizes n and m
+// without ties, counting the orderings of the pooled sample with each value
+// of U by the recursion f(i, j, u) = f(i - 1, j, u - j) + f(i, j - 1, u)
+fn null_pmf(n: usize, m: usize) -> Vec<f64> {
+    // counts[j][u] holds f(i, j, u) for the current i
+    let mut counts: Vec<Vec<f64>> = vec![vec![1.0]; m + 1];
+    for i in 1..n + 1 {
+        let mut next = Vec::with_capacity(m + 1);
+        next.push(vec![1.0]);
+        for j in 1..m + 1 {
+            let mut f = vec![0.0; i * j + 1];
+            for (u, &c) in counts[j].iter().enumerate() {
+                f[u + j] += c;
+            }
+            for (u, &c) in next[j - 1].iter().enumerate() {
+                f[u] += c;
+            }
+            next.push(f);
+        }
+        counts = next;
+    }
+    let total: f64 = counts[m].iter().sum();
+    counts[m].iter().map(|c| c / total).collect()
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const X: [f64; 10] = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+    const Y: [f64; 5] = [1.15, 0.88, 0.90, 0.74, 1.21];
+
+    fn check(x: &[f64], y: &[f64], alternative: Alternative, statistic: f64, p_value: f64) {
+        let t = MannWhitneyTest::new(x, y, alternative).unwrap();
+        assert_eq!(t.statistic(), statistic);
+        assert_almost_eq!(t.p_value(), p_value, 1e-14);
+    }
+
+    #[test]
+    fn test_null_pmf() {
+        let pmf = super::null_pmf(3, 2);
+        let expected = [0.1, 0.1, 0.2, 0.2, 0.2, 0.1, 0.1];
+        assert_eq!(pmf.len(), expected.len());
+        for (&p, &e) in pmf.iter().zip(expected.iter()) {
+            assert_almost_eq!(p, e, 1e-15);
+        }
+        assert_eq!(super::null_pmf(4, 0), [1.0]);
+        assert_almost_eq!(super::null_pmf(49, 49).iter().sum::<f64>(), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_exact() {
+        check(&X, &Y, Alternative::Greater, 35.0, 0.12720612720612720613);
+        check(&X, &Y, Alternative::Less, 35.0, 0.8967698967698967699);
+        check(&X, &Y, Alternative::TwoSided, 35.0, 0.25441225441225441225);
+        check(&Y, &X, Alternative::Less, 15.0, 0.12720612720612720613);
+        check(&[1.0], &[2.0], Alternative::TwoSided, 0.0, 1.0);
+    }
+
+    #[test]
+    fn test_ties() {
+        let x = [1.0, 2.0, 2.0, 3.0, 5.0, 7.0];
+        let y = [2.0, 3.0, 4.0, 5.0, 5.0, 8.0, 9.0];
+        check(&x, &y, Alternative::TwoSided, 11.5, 0.19294713116718772063);
+        check(&x, &y, Alternative::Less, 11.5, 0.096473565583593860313);
+        check(&x, &y, Alternative::Greater, 11.5, 0.92599084636383658524);
+    }
+
+    #[test]
+    fn test_ties_centered() {
+        check(&[1.0, 2.0, 2.0], &[1.0, 2.0, 2.0], Alternative::TwoSided, 4.5, 1.0);
+        check(&[1.0, 2.0, 2.0], &[1.0, 2.0, 2.0], Alternative::Greater, 4.5, 0.60392630410205133363);
+    }
+
+    #[test]
+    fn test_normal_approximation() {
+        let x: Vec<f64> = (0..60).map(|i| (i as f64 * 0.37).sin() + 0.3).collect();
+        let y: Vec<f64> = (0..55).map(|i| (i as f64 * 0.71).cos()).collect();
+        let t = MannWhitneyTest::new(&x, &y, Alternative::TwoSided).unwrap();
+        assert_eq!(t.statistic(), 2160.0);
+        assert_almost_eq!(t.p_value(), 0.0043356025279207497592, 1e-14);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(MannWhitneyTest::new(&[], &Y, Alternative::TwoSided).is_err());
+        assert!(MannWhitneyTest::new(&X, &[1.0, f64::NAN], Alternative::TwoSided).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! Provides statistical hypothesis tests for data sets
+
+use distribution::Univariate;
+use statistics::SortedData;
+
+pub use self::anova::{Anova, AnovaRow};
+pub use self::chi_squared_test::{ChiSquaredTest, ContingencyStatistic};
+pub use self::correlation_test::CorrelationTest;
+pub use self::kruskal_wallis_test::KruskalWallisTest;
+pub use self::ks_test::KolmogorovSmirnovTest;
+pub use self::mann_whitney_test::MannWhitneyTest;
+pub use self::t_test::TTest;
+pub use self::wilcoxon_test::WilcoxonTest;
+
+mod anova;
+mod chi_squared_test;
+mod correlation_test;
+mod kruskal_wallis_test;
+mod ks_test;
+mod mann_whitney_test;
+mod t_test;
+mod wilcoxon_test;
+
+/// Enumeration of possible alternative hypotheses
+/// for a statistical test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Alternative {
+    /// The tested quantity differs from the hypothesized value
+    /// in either direction
+    TwoSided,
+    /// The tested quantity is less than the hypothesized value
+    Less,
+    /// The tested quantity is greater than the hypothesized value
+    Greater,
+}
+
+// returns the p-value of a statistic with the continuous null distribution
+// `dist` under the given alternative
+fn p_value<D: Univariate<f64, f64>>(dist: &D, statistic: f64, alternative: Alternative) -> f64 {
+    match alternative {
+        Alternative::TwoSided => (2.0 * dist.cdf(statistic).min(dist.sf(statistic))).min(1.0),
+        Alternative::Less => dist.cdf(statistic),
+        Alternative::Greater => dist.sf(statistic),
+    }
+}
+
+// returns the p-value of an observed statistic with the discrete null
+// distribution `pmf` on `0, 1, ..., pmf.len() - 1` under the given
+// alternative, where the two-sided p-value doubles the smaller tail
+fn exact_p_value(pmf: &[f64], statistic: usize, alternative: Alternative) -> f64 {
+    let lower: f64 = pmf[..statistic + 1].iter().sum();
+    let upper: f64 = pmf[statistic..].iter().sum();
+    match alternative {
+        Alternative::TwoSided => (2.0 * lower.min(upper)).min(1.0),
+        Alternative::Less => lower.min(1.0),
+        Alternative::Greater => upper.min(1.0),
+    }
+}
+
+// moves a centered statistic half a unit towards zero, or towards the
+// tail of a one-sided alternative, for the normal approximation of a
+// discrete null distribution. A two-sided statistic of zero is left as is
+fn continuity_corrected(centered: f64, alternative: Alternative) -> f64 {
+    match alternative {
+        Alternative::TwoSided if centered == 0.0 => 0.0,
+        Alternative::TwoSided => centered - 0.5 * centered.signum(),
+        Alternative::Less => centered + 0.5,
+        Alternative::Greater => centered - 0.5,
+    }
+}
+
+// returns the sum of f(t) over the sizes t of the groups of ties in data
+fn tie_sum<F>(data: &[f64], f: F) -> f64
+    where F: Fn(f64) -> f64
+{
+    let sorted = SortedData::new(data);
+    let sorted = sorted.as_slice();
+    let mut sum = 0.0;
+    let mut start = 0;
+    for i in 1..sorted.len() + 1 {
+        if i == sorted.len() || sorted[i] != sorted[start] {
+            let t = (i - start) as f64;
+            sum += f(t);
+            start = i;
+        }
+    }
+    sum
+}
@@ -0,0 +1,345 @@
+use std::f64;
+use distribution::{InverseCdf, StudentsT, Univariate};
+use error::StatsError;
+use result::Result;
+use statistics::Statistics;
+use super::Alternative;
+
+/// Implements [Student's t-tests](https://en.wikipedia.org/wiki/Student%27s_t-test)
+/// for the mean of a sample, the mean difference of paired samples and the
+/// difference in means of two independent samples
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::{Alternative, TTest};
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let y = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+/// let t = TTest::welch(&x, &y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(t.p_value() > 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TTest {
+    statistic: f64,
+    freedom: f64,
+    p_value: f64,
+    estimate: f64,
+    confidence_interval: (f64, f64),
+}
+
+impl TTest {
+    /// Performs a one-sample t-test of the null hypothesis that the mean of
+    /// `data` is `mu` against the given `alternative`. The confidence interval
+    /// for the mean is computed at the `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` contains fewer than two elements, contains
+    /// `NaN` or infinite elements, has all elements equal, or `confidence`
+    /// is not in the interval `(0, 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::{Alternative, TTest};
+    ///
+    /// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    /// let result = TTest::one_sample(&x, 0.0, Alternative::TwoSided, 0.95);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = TTest::one_sample(&x[..1], 0.0, Alternative::TwoSided, 0.95);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn one_sample(data: &[f64],
+                      mu: f64,
+                      alternative: Alternative,
+                      confidence: f64)
+                      -> Result<TTest> {
+        check_confidence(confidence)?;
+        if data.len() < 2 || data.iter().any(|x| !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let n = data.len() as f64;
+        let std_err = (data.variance() / n).sqrt();
+        TTest::from_estimate(data.mean(), mu, std_err, n - 1.0, alternative, confidence)
+    }
+
+    /// Performs a paired t-test of the null hypothesis that the mean of the
+    /// differences `x[i] - y[i]` is `mu` against the given `alternative`. The
+    /// confidence interval for the mean difference is computed at the
+    /// `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// fewer than two elements or `NaN` or infinite elements, all
+    /// differences are equal, or `confidence` is not in the interval `(0, 1)`
+    pub fn paired(x: &[f64],
+                  y: &[f64],
+                  mu: f64,
+                  alternative: Alternative,
+                  confidence: f64)
+                  -> Result<TTest> {
+        if x.len() != y.len() {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        let diff: Vec<f64> = x.iter().zip(y.iter()).map(|(a, b)| a - b).collect();
+        TTest::one_sample(&diff, mu, alternative, confidence)
+    }
+
+    /// Performs a two-sample t-test of the null hypothesis that the difference
+    /// in means of the independent samples `x` and `y` is `mu` against the
+    /// given `alternative`, assuming both samples share the same variance. The
+    /// confidence interval for the difference in means is computed at the
+    /// `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` or `y` contain fewer than two elements or
+    /// `NaN` or infinite elements, both have all elements equal, or
+    /// `confidence` is not in the interval `(0, 1)`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// t = (mean(x) - mean(y) - μ) / sqrt(s_p^2 * (1 / n_x + 1 / n_y))
+    /// s_p^2 = ((n_x - 1) * s_x^2 + (n_y - 1) * s_y^2) / (n_x + n_y - 2)
+    /// ```
+    ///
+    /// where `μ` is the hypothesized difference and `s^2` and `n` are the
+    /// sample variances and sample sizes. The statistic has `n_x + n_y - 2`
+    /// degrees of freedom
+    pub fn pooled(x: &[f64],
+                  y: &[f64],
+                  mu: f64,
+                  alternative: Alternative,
+                  confidence: f64)
+                  -> Result<TTest> {
+        check_confidence(confidence)?;
+        if x.len() < 2 || y.len() < 2 || x.iter().chain(y.iter()).any(|v| !v.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let nx = x.len() as f64;
+        let ny = y.len() as f64;
+        let freedom = nx + ny - 2.0;
+        let pooled_var = ((nx - 1.0) * x.variance() + (ny - 1.0) * y.variance()) / freedom;
+        let std_err = (pooled_var * (1.0 / nx + 1.0 / ny)).sqrt();
+        TTest::from_estimate(x.mean() - y.mean(), mu, std_err, freedom, alternative, confidence)
+    }
+
+    /// Performs Welch's two-sample t-test of the null hypothesis that the
+    /// difference in means of the independent samples `x` and `y` is `mu`
+    /// against the given `alternative`, without assuming equal variances.
+    /// The confidence interval for the difference in means is computed at
+    /// the `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` or `y` contain fewer than two elements or
+    /// `NaN` or infinite elements, both have all elements equal, or
+    /// `confidence` is not in the interval `(0, 1)`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// t = (mean(x) - mean(y) - μ) / sqrt(s_x^2 / n_x + s_y^2 / n_y)
+    /// v = (s_x^2 / n_x + s_y^2 / n_y)^2 /
+    ///     ((s_x^2 / n_x)^2 / (n_x - 1) + (s_y^2 / n_y)^2 / (n_y - 1))
+    /// ```
+    ///
+    /// where `μ` is the hypothesized difference, `s^2` and `n` are the
+    /// sample variances and sample sizes, and `v` is the Welch-Satterthwaite
+    /// approximation of the degrees of freedom
+    pub fn welch(x: &[f64],
+                 y: &[f64],
+                 mu: f64,
+                 alternative: Alternative,
+                 confidence: f64)
+                 -> Result<TTest> {
+        check_confidence(confidence)?;
+        if x.len() < 2 || y.len() < 2 || x.iter().chain(y.iter()).any(|v| !v.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let nx = x.len() as f64;
+        let ny = y.len() as f64;
+        let vx = x.variance() / nx;
+        let vy = y.variance() / ny;
+        let std_err = (vx + vy).sqrt();
+        let freedom = (vx + vy) * (vx + vy) / (vx * vx / (nx - 1.0) + vy * vy / (ny - 1.0));
+        TTest::from_estimate(x.mean() - y.mean(), mu, std_err, freedom, alternative, confidence)
+    }
+
+    fn from_estimate(estimate: f64,
+                     mu: f64,
+                     std_err: f64,
+                     freedom: f64,
+                     alternative: Alternative,
+                     confidence: f64)
+                     -> Result<TTest> {
+        // constant samples have no standard error, for which neither the
+        // statistic nor the Welch-Satterthwaite freedom is defined
+        if std_err == 0.0 || !freedom.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        let statistic = (estimate - mu) / std_err;
+        let dist = StudentsT::new(0.0, 1.0, freedom)?;
+        let (p_value, confidence_interval) = match alternative {
+            Alternative::TwoSided => {
+                let p = 2.0 * dist.cdf(statistic).min(dist.sf(statistic));
+                let q = dist.inverse_cdf(0.5 + confidence / 2.0);
+                (p.min(1.0), (estimate - q * std_err, estimate + q * std_err))
+            }
+            Alternative::Less => {
+                let q = dist.inverse_cdf(confidence);
+                (dist.cdf(statistic), (f64::NEG_INFINITY, estimate + q * std_err))
+            }
+            Alternative::Greater => {
+                let q = dist.inverse_cdf(confidence);
+                (dist.sf(statistic), (estimate - q * std_err, f64::INFINITY))
+            }
+        };
+        Ok(TTest {
+            statistic: statistic,
+            freedom: freedom,
+            p_value: p_value,
+            estimate: estimate,
+            confidence_interval: confidence_interval,
+        })
+    }
+
+    /// Returns the t-statistic of the test
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the degrees of freedom of the t-distribution
+    /// the statistic is compared against
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the p-value of the test under the chosen alternative
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    /// Returns the estimated mean, mean difference or difference
+    /// in means depending on the test performed
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Returns the confidence interval for the estimate. One-sided
+    /// alternatives produce an interval that is unbounded on one side
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        self.confidence_interval
+    }
+}
+
+fn check_confidence(confidence: f64) -> Result<()> {
+    if confidence > 0.0 && confidence < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("confidence", 0.0, 1.0))
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const X: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    const Y: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+    fn check(t: TTest, statistic: f64, freedom: f64, p_value: f64, lower: f64, upper: f64) {
+        assert_almost_eq!(t.statistic(), statistic, 1e-12);
+        assert_almost_eq!(t.freedom(), freedom, 1e-12);
+        assert_almost_eq!(t.p_value(), p_value, 1e-12);
+        let (l, u) = t.confidence_interval();
+        if lower.is_infinite() { assert_eq!(l, lower); } else { assert_almost_eq!(l, lower, 1e-11); }
+        if upper.is_infinite() { assert_eq!(u, upper); } else { assert_almost_eq!(u, upper, 1e-11); }
+    }
+
+    #[test]
+    fn test_one_sample() {
+        check(TTest::one_sample(&X, 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            1.325710140713821279, 9.0, 0.21759778006844894285, -0.52978041352623256341, 2.0297804135262325634);
+        check(TTest::one_sample(&X, 0.0, Alternative::Less, 0.95).unwrap(),
+            1.325710140713821279, 9.0, 0.89120110996577552858, f64::NEG_INFINITY, 1.7870552787292595884);
+        check(TTest::one_sample(&X, 0.0, Alternative::Greater, 0.95).unwrap(),
+            1.325710140713821279, 9.0, 0.10879889003422447142, -0.2870552787292595884, f64::INFINITY);
+        check(TTest::one_sample(&X, 1.0, Alternative::TwoSided, 0.95).unwrap(),
+            -0.44190338023794042634, 9.0, 0.66898662361467480905, -0.52978041352623256341, 2.0297804135262325634);
+        assert_almost_eq!(TTest::one_sample(&X, 0.0, Alternative::TwoSided, 0.95).unwrap().estimate(), 0.75, 1e-15);
+    }
+
+    #[test]
+    fn test_paired() {
+        check(TTest::paired(&X, &Y, 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            -4.0621276833820361097, 9.0, 0.0028328901973842727403, -2.4598857632769827987, -0.70011423672301720129);
+        check(TTest::paired(&X, &Y, 0.0, Alternative::Less, 0.95).unwrap(),
+            -4.0621276833820361097, 9.0, 0.0014164450986921363702, f64::NEG_INFINITY, -0.86699473297071618215);
+        check(TTest::paired(&X, &Y, 0.0, Alternative::Greater, 0.95).unwrap(),
+            -4.0621276833820361097, 9.0, 0.99858355490130786363, -2.2930052670292838178, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_pooled() {
+        check(TTest::pooled(&X, &Y, 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            -1.8608134674868531015, 18.0, 0.079186714215938110724, -3.3638740322875985537, 0.20387403228759855368);
+        check(TTest::pooled(&X, &Y, 0.0, Alternative::Less, 0.95).unwrap(),
+            -1.8608134674868531015, 18.0, 0.039593357107969055362, f64::NEG_INFINITY, -0.10762216830039766862);
+        check(TTest::pooled(&X, &Y, 0.0, Alternative::Greater, 0.95).unwrap(),
+            -1.8608134674868531015, 18.0, 0.96040664289203094464, -3.0523778316996023314, f64::INFINITY);
+        check(TTest::pooled(&X, &Y[..7], 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            -1.2556792676210763853, 15.0, 0.22844042377771521722, -3.2562043974814445273, 0.8419186831957302416);
+    }
+
+    #[test]
+    fn test_welch() {
+        check(TTest::welch(&X, &Y, 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            -1.8608134674868531015, 17.776473516178490143, 0.079394140187358138337, -3.3654832307117109713, 0.20548323071171097135);
+        check(TTest::welch(&X, &Y, 0.0, Alternative::Less, 0.95).unwrap(),
+            -1.8608134674868531015, 17.776473516178490143, 0.039697070093679069169, f64::NEG_INFINITY, -0.10661850266839356518);
+        check(TTest::welch(&X, &Y, 0.0, Alternative::Greater, 0.95).unwrap(),
+            -1.8608134674868531015, 17.776473516178490143, 0.96030292990632093083, -3.0533814973316064348, f64::INFINITY);
+        check(TTest::welch(&X, &Y[..7], 0.0, Alternative::TwoSided, 0.95).unwrap(),
+            -1.2111945016566955324, 11.35042989155367535, 0.25043537578633985142, -3.3925306891398662373, 0.97824497485415195162);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(TTest::one_sample(&[], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::one_sample(&[1.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::one_sample(&X, 0.0, Alternative::TwoSided, 0.0).is_err());
+        assert!(TTest::one_sample(&X, 0.0, Alternative::TwoSided, 1.0).is_err());
+        assert!(TTest::one_sample(&X, 0.0, Alternative::TwoSided, f64::NAN).is_err());
+        assert!(TTest::paired(&X, &Y[..9], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::pooled(&X, &Y[..1], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::welch(&X[..1], &Y, 0.0, Alternative::TwoSided, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_constant_samples() {
+        let c = [1.0, 1.0, 1.0];
+        assert!(TTest::one_sample(&c, 1.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::one_sample(&c, 0.0, Alternative::Less, 0.95).is_err());
+        assert!(TTest::paired(&X, &X, 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::pooled(&c, &[2.0, 2.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::welch(&c, &c, 0.0, Alternative::TwoSided, 0.95).is_err());
+        let t = TTest::welch(&c, &Y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(t.freedom(), 9.0, 1e-12);
+    }
+
+    #[test]
+    fn test_non_finite_data() {
+        assert!(TTest::one_sample(&[1.0, 2.0, f64::INFINITY], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::one_sample(&[1.0, 2.0, f64::NAN], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::paired(&[1.0, 2.0, 3.0], &[0.5, f64::NEG_INFINITY, 1.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::pooled(&X, &[1.0, f64::NAN], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(TTest::welch(&[f64::INFINITY, 1.0], &Y, 0.0, Alternative::TwoSided, 0.95).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use distribution::Normal;
+use error::StatsError;
+use result::Result;
+use statistics::{RankTieBreaker, Statistics};
+use super::{continuity_corrected, exact_p_value, p_value, tie_sum, Alternative};
+
+/// Largest number of non-zero differences for which the test computes an
+/// exact p-value
+const EXACT_MAX: usize = 50;
+
+/// Implements the [Wilcoxon signed-rank test](https://en.wikipedia.org/wiki/Wilcoxon_signed-rank_test)
+/// of the location of a sample, or of the differences of paired samples,
+/// about a hypothesized value
+///
+/// # Examples
+///
+/// ```
+/// use statrs::hypothesis::{Alternative, WilcoxonTest};
+///
+/// let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+/// let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+/// let t = WilcoxonTest::paired(&x, &y, 0.0, Alternative::Greater).unwrap();
+/// assert_eq!(t.statistic(), 40.0);
+/// assert!(t.p_value() < 0.05);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WilcoxonTest {
+    statistic: f64,
+    p_value: f64,
+}
+
+impl WilcoxonTest {
+    /// Performs a Wilcoxon signed-rank test of the null hypothesis that the
+    /// distribution of `data` is symmetric about `mu` against the given
+    /// `alternative`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` contains `NaN` or no element different
+    /// from `mu`
+    ///
+    /// # Remarks
+    ///
+    /// Elements equal to `mu` are discarded. The p-value is exact if fewer
+    /// than `50` elements remain and there are neither ties nor discarded
+    /// elements. Otherwise it uses the normal approximation with a
+    /// continuity correction and the variance corrected for ties
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// V = Σ_{d_i > 0} R_i
+    /// z = (V - n * (n + 1) / 4 ± 1 / 2) / sqrt(n * (n + 1) * (2 * n + 1) / 24 - Σ(t^3 - t) / 48)
+    /// ```
+    ///
+    /// where `d_i = x_i - mu` are the `n` non-zero differences, `R_i` is the
+    /// rank of `|d_i|` and `t` are the sizes of the groups of ties among the
+    /// `|d_i|`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::hypothesis::{Alternative, WilcoxonTest};
+    ///
+    /// let result = WilcoxonTest::one_sample(&[1.0, 2.0, 3.0], 0.0, Alternative::TwoSided);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = WilcoxonTest::one_sample(&[1.0, 1.0], 1.0, Alternative::TwoSided);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn one_sample(data: &[f64], mu: f64, alternative: Alternative) -> Result<WilcoxonTest> {
+        if data.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let diff: Vec<f64> = data.iter().map(|x| x - mu).filter(|&d| d != 0.0).collect();
+        if diff.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let abs_diff: Vec<f64> = diff.iter().map(|d| d.abs()).collect();
+        let ranks = abs_diff.ranks_copy(RankTieBreaker::Average);
+        let statistic: f64 =
+            diff.iter().zip(ranks.iter()).filter(|&(&d, _)| d > 0.0).map(|(_, r)| r).sum();
+        let ties = tie_sum(&abs_diff, |t| t * t * t - t);
+
+        let p_value = if diff.len() < EXACT_MAX && diff.len() == data.len() && ties == 0.0 {
+            exact_p_value(&null_pmf(diff.len()), statistic as usize, alternative)
+        } else {
+            let n = diff.len() as f64;
+            let variance = n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - ties / 48.0;
+            let z = continuity_corrected(statistic - n * (n + 1.0) / 4.0, alternative) /
+                    variance.sqrt();
+            p_value(&Normal::new(0.0, 1.0).unwrap(), z, alternative)
+        };
+        Ok(WilcoxonTest {
+            statistic: statistic,
+            p_value: p_value,
+        })
+    }
+
+    /// Performs a Wilcoxon signed-rank test of the null hypothesis that the
+    /// distribution of the differences `x[i] - y[i]` is symmetric about `mu`
+    /// against the given `alternative`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// `NaN` or no difference is different from `mu`
+    pub fn paired(x: &[f64], y: &[f64], mu: f64, alternative: Alternative) -> Result<WilcoxonTest> {
+        if x.len() != y.len() {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        let diff: Vec<f64> = x.iter().zip(y.iter()).map(|(a, b)| a - b).collect();
+        WilcoxonTest::one_sample(&diff, mu, alternative)
+    }
+
+    /// Returns the statistic `V` of the test, the sum of the ranks of the
+    /// positive differences
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value of the test under the chosen alternative
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+// returns the probability mass function of V for n differences without
+// ties, counting the subsets of the ranks 1, ..., n with each sum
+fn null_pmf(n: usize) -> Vec<f64> {
+    let mut counts = vec![0.0; n * (n + 1) / 2 + 1];
+    counts[0] = 1.0;
+    for k in 1..n + 1 {
+        for v in (k..k * (k + 1) / 2 + 1).rev() {
+            counts[v] += counts[v - k];
+        }
+    }
+    let total = 2f64.powi(n as i32);
+    counts.iter().map(|c| c / total).collect()
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use hypothesis::*;
+
+    const X: [f64; 9] = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+    const Y: [f64; 9] = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+
+    fn check(t: WilcoxonTest, statistic: f64, p_value: f64) {
+        assert_eq!(t.statistic(), statistic);
+        assert_almost_eq!(t.p_value(), p_value, 1e-14);
+    }
+
+    #[test]
+    fn test_null_pmf() {
+        let pmf = super::null_pmf(3);
+        let expected = [0.125, 0.125, 0.125, 0.25, 0.125, 0.125, 0.125];
+        assert_eq!(pmf, expected);
+        assert_almost_eq!(super::null_pmf(49).iter().sum::<f64>(), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn test_exact() {
+        check(WilcoxonTest::paired(&X, &Y, 0.0, Alternative::Greater).unwrap(), 40.0, 0.01953125);
+        check(WilcoxonTest::paired(&X, &Y, 0.0, Alternative::Less).unwrap(), 40.0, 0.986328125);
+        check(WilcoxonTest::paired(&X, &Y, 0.0, Alternative::TwoSided).unwrap(), 40.0, 0.0390625);
+        check(WilcoxonTest::one_sample(&X, 1.5, Alternative::TwoSided).unwrap(), 33.0, 0.25);
+    }
+
+    #[test]
+    fn test_ties_and_zeros() {
+        let data = [1.5, -0.5, 2.0, 0.0, 3.0, 2.0, -2.0, 4.5, 0.5, 1.0];
+        check(WilcoxonTest::one_sample(&data, 0.0, Alternative::TwoSided).unwrap(), 37.5, 0.084456142457137527998);
+        check(WilcoxonTest::one_sample(&data, 0.0, Alternative::Greater).unwrap(), 37.5, 0.042228071228568763999);
+        check(WilcoxonTest::one_sample(&data, 0.0, Alternative::Less).unwrap(), 37.5, 0.96743674816432179437);
+    }
+
+    #[test]
+    fn test_ties_centered() {
+        // the statistic equals its mean, so the continuity correction vanishes
+        check(WilcoxonTest::one_sample(&[1.0, -1.0, 2.0, -2.0], 0.0, Alternative::TwoSided).unwrap(), 5.0, 1.0);
+    }
+
+    #[test]
+    fn test_normal_approximation() {
+        let data: Vec<f64> = (0..60).map(|i| (i as f64 * 0.37).sin() + 0.2).collect();
+        check(WilcoxonTest::one_sample(&data, 0.0, Alternative::TwoSided).unwrap(), 1331.0, 0.0022226547611692012078);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(WilcoxonTest::one_sample(&[], 0.0, Alternative::TwoSided).is_err());
+        assert!(WilcoxonTest::one_sample(&[0.0, 0.0], 0.0, Alternative::TwoSided).is_err());
+        assert!(WilcoxonTest::one_sample(&[1.0, f64::NAN], 0.0, Alternative::TwoSided).is_err());
+        assert!(WilcoxonTest::paired(&X, &Y[..8], 0.0, Alternative::TwoSided).is_err());
+    }
+}
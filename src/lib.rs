@@ -42,8 +42,10 @@ pub mod distribution;
 pub mod euclid;
 pub mod function;
 pub mod generate;
+pub mod hypothesis;
 pub mod consts;
 pub mod prec;
+pub mod regression;
 pub mod statistics;
 
 mod result;
@@ -0,0 +1,478 @@
+use std::f64;
+use distribution::{FisherSnedecor, StudentsT, Univariate};
+use error::StatsError;
+use result::Result;
+
+/// Implements ordinary [least squares](https://en.wikipedia.org/wiki/Ordinary_least_squares)
+/// linear regression of a response on one or more predictors with an
+/// intercept, together with the usual inference under the assumption of
+/// independent, normally distributed errors with constant variance
+///
+/// # Remarks
+///
+/// The model is fitted by the Householder QR decomposition of the predictors
+/// centered at their means, which avoids forming the normal equations and
+/// keeps the fit accurate for nearly collinear predictors
+///
+/// # Examples
+///
+/// ```
+/// use statrs::regression::LinearRegression;
+///
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [2.1, 3.9, 6.2, 7.8, 10.1];
+/// let fit = LinearRegression::simple(&x, &y).unwrap();
+/// assert!((fit.coefficients()[1] - 1.99).abs() < 1e-12);
+/// assert!(fit.p_values()[1] < 0.001);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegression {
+    coefficients: Vec<f64>,
+    std_errors: Vec<f64>,
+    t_statistics: Vec<f64>,
+    p_values: Vec<f64>,
+    residuals: Vec<f64>,
+    freedom: f64,
+    residual_std_error: f64,
+    r_squared: f64,
+    adjusted_r_squared: f64,
+    f_statistic: f64,
+    f_p_value: f64,
+}
+
+impl LinearRegression {
+    /// Fits the simple linear regression `y = b_0 + b_1 * x` by least
+    /// squares
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` and `y` are of different lengths, contain
+    /// fewer than three elements or `NaN`, or `x` is constant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::regression::LinearRegression;
+    ///
+    /// let result = LinearRegression::simple(&[1.0, 2.0, 3.0], &[1.0, 3.0, 2.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = LinearRegression::simple(&[1.0, 1.0, 1.0], &[1.0, 3.0, 2.0]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn simple(x: &[f64], y: &[f64]) -> Result<LinearRegression> {
+        LinearRegression::multiple(&[x], y)
+    }
+
+    /// Fits the multiple linear regression
+    /// `y = b_0 + b_1 * x_1 + ... + b_k * x_k` by least squares, where each
+    /// entry of `x` holds the observations of one predictor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` is empty, any predictor is of a different
+    /// length than `y`, there are fewer than `k + 2` observations, any
+    /// observation is `NaN`, or the predictors are constant or linearly
+    /// dependent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::regression::LinearRegression;
+    ///
+    /// let x1 = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let x2 = [0.5, 0.1, 0.9, 0.3, 0.8, 0.2];
+    /// let y = [3.0, 3.9, 7.8, 7.5, 10.9, 9.4];
+    /// let fit = LinearRegression::multiple(&[&x1, &x2], &y).unwrap();
+    /// assert_eq!(fit.coefficients().len(), 3);
+    ///
+    /// let result = LinearRegression::multiple(&[&x1, &x1], &y);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn multiple<T>(x: &[T], y: &[f64]) -> Result<LinearRegression>
+        where T: AsRef<[f64]>
+    {
+        let n = y.len();
+        let k = x.len();
+        if k == 0 {
+            return Err(StatsError::BadParams);
+        }
+        if x.iter().any(|col| col.as_ref().len() != n) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if n < k + 2 || y.iter().chain(x.iter().flat_map(|col| col.as_ref())).any(|v| v.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+
+        let y_mean = mean(y);
+        let x_means: Vec<f64> = x.iter().map(|col| mean(col.as_ref())).collect();
+        // the centered predictors are stored column by column
+        let mut centered = Vec::with_capacity(n * k);
+        for (col, &m) in x.iter().zip(x_means.iter()) {
+            centered.extend(col.as_ref().iter().map(|v| v - m));
+        }
+        let centered_y: Vec<f64> = y.iter().map(|v| v - y_mean).collect();
+
+        let mut r = centered.clone();
+        let mut qty = centered_y.clone();
+        householder_qr(&mut r, &mut qty, n, k)?;
+        let slopes = solve_upper(&r, &qty, n, k);
+        let r_inv = invert_upper(&r, n, k);
+
+        let residuals: Vec<f64> = (0..n)
+            .map(|i| centered_y[i] - (0..k).map(|j| centered[j * n + i] * slopes[j]).sum::<f64>())
+            .collect();
+        let sse: f64 = residuals.iter().map(|e| e * e).sum();
+        let sst: f64 = centered_y.iter().map(|v| v * v).sum();
+        let freedom = (n - k - 1) as f64;
+        let variance = sse / freedom;
+
+        // the diagonal of (X'X)^-1 for the centered predictors is given by the
+        // squared row norms of R^-1 while the intercept picks up the variance
+        // of the means through ||R^-T * x_mean||^2
+        let mut coefficients = Vec::with_capacity(k + 1);
+        let mut std_errors = Vec::with_capacity(k + 1);
+        let intercept_inflation: f64 = (0..k)
+            .map(|l| (0..l + 1).map(|j| r_inv[j * k + l] * x_means[j]).sum::<f64>())
+            .map(|w| w * w)
+            .sum();
+        coefficients.push(y_mean - slopes.iter().zip(x_means.iter()).map(|(b, m)| b * m).sum::<f64>());
+        std_errors.push((variance * (1.0 / n as f64 + intercept_inflation)).sqrt());
+        for j in 0..k {
+            let norm: f64 = (j..k).map(|l| r_inv[j * k + l] * r_inv[j * k + l]).sum();
+            coefficients.push(slopes[j]);
+            std_errors.push((variance * norm).sqrt());
+        }
+
+        let dist = StudentsT::new(0.0, 1.0, freedom).unwrap();
+        let t_statistics: Vec<f64> =
+            coefficients.iter().zip(std_errors.iter()).map(|(b, s)| b / s).collect();
+        let p_values = t_statistics.iter()
+            .map(|t| if t.is_nan() { f64::NAN } else { 2.0 * dist.sf(t.abs()) })
+            .collect();
+
+        let r_squared = 1.0 - sse / sst;
+        let f_statistic = (sst - sse) / k as f64 / variance;
+        let f_p_value = if f_statistic.is_nan() {
+            f64::NAN
+        } else {
+            FisherSnedecor::new(k as f64, freedom).unwrap().sf(f_statistic.max(0.0))
+        };
+
+        Ok(LinearRegression {
+            coefficients: coefficients,
+            std_errors: std_errors,
+            t_statistics: t_statistics,
+            p_values: p_values,
+            residuals: residuals,
+            freedom: freedom,
+            residual_std_error: variance.sqrt(),
+            r_squared: r_squared,
+            adjusted_r_squared: 1.0 - (1.0 - r_squared) * (n - 1) as f64 / freedom,
+            f_statistic: f_statistic,
+            f_p_value: f_p_value,
+        })
+    }
+
+    /// Returns the estimated coefficients, the intercept followed by the
+    /// coefficient of each predictor
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the standard errors of the coefficients
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(s^2 * ((X'X)^-1)_jj)
+    /// ```
+    ///
+    /// where `s` is the residual standard error and `X` is the design matrix
+    /// including a column of ones for the intercept
+    pub fn std_errors(&self) -> &[f64] {
+        &self.std_errors
+    }
+
+    /// Returns the t-statistics of the coefficients for the null hypotheses
+    /// that they are zero, the coefficients divided by their standard errors
+    pub fn t_statistics(&self) -> &[f64] {
+        &self.t_statistics
+    }
+
+    /// Returns the two-sided p-values of the t-statistics of the
+    /// coefficients, each with `n - k - 1` degrees of freedom
+    pub fn p_values(&self) -> &[f64] {
+        &self.p_values
+    }
+
+    /// Returns the residuals, the observed responses minus the fitted values
+    pub fn residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+
+    /// Returns the residual degrees of freedom `n - k - 1` for `n`
+    /// observations of `k` predictors
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the residual standard error, the estimate of the standard
+    /// deviation of the errors
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s = sqrt(Σe_i^2 / (n - k - 1))
+    /// ```
+    ///
+    /// where `e_i` are the residuals
+    pub fn residual_std_error(&self) -> f64 {
+        self.residual_std_error
+    }
+
+    /// Returns the coefficient of determination, the fraction of the
+    /// variance of the response explained by the predictors
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if the response is constant
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// R^2 = 1 - Σe_i^2 / Σ(y_i - ȳ)^2
+    /// ```
+    ///
+    /// where `e_i` are the residuals and `ȳ` is the mean response
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Returns the coefficient of determination adjusted for the number of
+    /// predictors
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - (1 - R^2) * (n - 1) / (n - k - 1)
+    /// ```
+    pub fn adjusted_r_squared(&self) -> f64 {
+        self.adjusted_r_squared
+    }
+
+    /// Returns the F-statistic for the null hypothesis that all coefficients
+    /// except the intercept are zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F = (Σ(y_i - ȳ)^2 - Σe_i^2) / k / s^2
+    /// ```
+    ///
+    /// where `e_i` are the residuals, `ȳ` is the mean response and `s` is the
+    /// residual standard error. The statistic has `k` and `n - k - 1` degrees
+    /// of freedom
+    pub fn f_statistic(&self) -> f64 {
+        self.f_statistic
+    }
+
+    /// Returns the p-value of the F-statistic
+    pub fn f_p_value(&self) -> f64 {
+        self.f_p_value
+    }
+}
+
+fn mean(data: &[f64]) -> f64 {
+    let m = data.iter().sum::<f64>() / data.len() as f64;
+    // corrects for the rounding error of the first pass
+    m + data.iter().map(|x| x - m).sum::<f64>() / data.len() as f64
+}
+
+// reduces the column-major `n × k` matrix `a` to the upper triangular `R` of
+// its QR decomposition in place and applies `Q'` to `b`. Fails if a column
+// is constant or linearly dependent on the preceding ones
+fn householder_qr(a: &mut [f64], b: &mut [f64], n: usize, k: usize) -> Result<()> {
+    let norms: Vec<f64> =
+        (0..k).map(|j| a[j * n..(j + 1) * n].iter().map(|x| x * x).sum::<f64>().sqrt()).collect();
+    for j in 0..k {
+        let norm = a[j * n + j..(j + 1) * n].iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm <= f64::EPSILON * n as f64 * norms[j] || norms[j] == 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let alpha = if a[j * n + j] > 0.0 { -norm } else { norm };
+        let mut v = a[j * n + j..(j + 1) * n].to_vec();
+        v[0] -= alpha;
+        let vv: f64 = v.iter().map(|x| x * x).sum();
+        for l in j + 1..k {
+            reflect(&v, vv, &mut a[l * n + j..(l + 1) * n]);
+        }
+        reflect(&v, vv, &mut b[j..]);
+        a[j * n + j] = alpha;
+    }
+    Ok(())
+}
+
+// applies the reflection `I - 2vv' / v'v` to `x`
+fn reflect(v: &[f64], vv: f64, x: &mut [f64]) {
+    let s = 2.0 * v.iter().zip(x.iter()).map(|(p, q)| p * q).sum::<f64>() / vv;
+    for (p, q) in v.iter().zip(x.iter_mut()) {
+        *q -= s * p;
+    }
+}
+
+// solves `Rx = b` for the upper triangular `k × k` matrix `R` stored in the
+// leading rows of the column-major `n × k` matrix `r`
+fn solve_upper(r: &[f64], b: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut x = vec![0.0; k];
+    for i in (0..k).rev() {
+        let sum: f64 = (i + 1..k).map(|j| r[j * n + i] * x[j]).sum();
+        x[i] = (b[i] - sum) / r[i * n + i];
+    }
+    x
+}
+
+// returns the inverse of the upper triangular `k × k` matrix `R` stored in
+// the leading rows of the column-major `n × k` matrix `r` as a row-major
+// `k × k` matrix
+fn invert_upper(r: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut inv = vec![0.0; k * k];
+    for col in 0..k {
+        let mut e = vec![0.0; k];
+        e[col] = 1.0;
+        let x = solve_upper(r, &e, n, k);
+        for row in 0..k {
+            inv[row * k + col] = x[row];
+        }
+    }
+    inv
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use regression::LinearRegression;
+    use testing;
+
+    const X: [f64; 8] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    const Y: [f64; 8] = [1.2, 1.9, 3.2, 3.8, 5.3, 5.8, 7.4, 7.9];
+
+    fn try_create<T: AsRef<[f64]>>(x: &[T], y: &[f64]) -> LinearRegression {
+        let fit = LinearRegression::multiple(x, y);
+        assert!(fit.is_ok());
+        fit.unwrap()
+    }
+
+    fn columns(table: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let y = table.iter().map(|row| row[0]).collect();
+        let x = (1..table[0].len()).map(|j| table.iter().map(|row| row[j]).collect()).collect();
+        (y, x)
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let empty: [&[f64]; 0] = [];
+        assert!(LinearRegression::multiple(&empty, &Y).is_err());
+        assert!(LinearRegression::simple(&X[..7], &Y).is_err());
+        assert!(LinearRegression::simple(&X[..2], &Y[..2]).is_err());
+        assert!(LinearRegression::simple(&[1.0, 2.0, f64::NAN], &[1.0, 2.0, 3.0]).is_err());
+        assert!(LinearRegression::simple(&[2.0; 8], &Y).is_err());
+        let x2: Vec<f64> = X.iter().map(|x| 3.0 * x - 1.0).collect();
+        assert!(LinearRegression::multiple(&[&X[..], &x2[..]], &Y).is_err());
+        assert!(LinearRegression::multiple(&[&X[..3], &X[1..4]], &Y[..3]).is_err());
+    }
+
+    #[test]
+    fn test_simple() {
+        let fit = LinearRegression::simple(&X, &Y).unwrap();
+        assert_almost_eq!(fit.coefficients()[0], 0.078571428571428387449, 1e-14);
+        assert_almost_eq!(fit.coefficients()[1], 0.99642857142857147562, 1e-14);
+        assert_almost_eq!(fit.std_errors()[0], 0.20073737369328847165, 1e-14);
+        assert_almost_eq!(fit.std_errors()[1], 0.039751923469200015574, 1e-15);
+        assert_almost_eq!(fit.t_statistics()[0], 0.39141405073616031845, 1e-13);
+        assert_almost_eq!(fit.t_statistics()[1], 25.066172513654822229, 1e-12);
+        assert_almost_eq!(fit.p_values()[0], 0.70901378204860591662, 1e-13);
+        assert_almost_eq!(fit.p_values()[1], 2.6542356396342558391e-07, 1e-18);
+        assert_almost_eq!(fit.r_squared(), 0.99054094751710476011, 1e-14);
+        assert_almost_eq!(fit.adjusted_r_squared(), 0.98896443876995555346, 1e-14);
+        assert_almost_eq!(fit.f_statistic(), 628.31300448430450909, 1e-10);
+        assert_almost_eq!(fit.f_p_value(), 2.6542356396342558391e-07, 1e-18);
+        assert_eq!(fit.freedom(), 6.0);
+        assert_eq!(fit.residuals().len(), 8);
+        assert_almost_eq!(fit.residuals().iter().sum::<f64>(), 0.0, 1e-14);
+    }
+
+    #[test]
+    fn test_multiple() {
+        let x2 = [0.5, 0.1, 0.9, 0.3, 0.8, 0.2, 0.6, 0.4];
+        let fit = try_create(&[&X[..], &x2[..]], &Y);
+        assert_almost_eq!(fit.coefficients()[0], -0.23347639484978569919, 1e-14);
+        assert_almost_eq!(fit.coefficients()[1], 0.99482832618025755694, 1e-14);
+        assert_almost_eq!(fit.coefficients()[2], 0.67210300429184571502, 1e-14);
+        assert_almost_eq!(fit.std_errors()[0], 0.17145397941121511266, 1e-14);
+        assert_almost_eq!(fit.std_errors()[2], 0.23068970953215879935, 1e-14);
+        assert_almost_eq!(fit.t_statistics()[2], 2.9134503036779481977, 1e-13);
+        assert_almost_eq!(fit.p_values()[0], 0.23142362133538559452, 1e-13);
+        assert_almost_eq!(fit.p_values()[2], 0.033265973050492167587, 1e-14);
+        assert_almost_eq!(fit.r_squared(), 0.99649358045480385304, 1e-14);
+        assert_almost_eq!(fit.adjusted_r_squared(), 0.99509101263672539426, 1e-14);
+        assert_almost_eq!(fit.f_statistic(), 710.47800157001792395, 1e-10);
+        assert_almost_eq!(fit.f_p_value(), 7.2804746855564983962e-07, 1e-18);
+        assert_eq!(fit.freedom(), 5.0);
+    }
+
+    #[test]
+    fn test_perfect_fit() {
+        let y: Vec<f64> = X.iter().map(|x| 2.0 * x + 1.0).collect();
+        let fit = LinearRegression::simple(&X, &y).unwrap();
+        assert_almost_eq!(fit.coefficients()[0], 1.0, 1e-14);
+        assert_almost_eq!(fit.coefficients()[1], 2.0, 1e-15);
+        assert_almost_eq!(fit.r_squared(), 1.0, 1e-15);
+
+        let fit = LinearRegression::simple(&X, &[3.0; 8]).unwrap();
+        assert_eq!(fit.coefficients(), &[3.0, 0.0]);
+        assert!(fit.r_squared().is_nan());
+        assert!(fit.f_p_value().is_nan());
+        assert!(fit.p_values()[1].is_nan());
+    }
+
+    // certified values from the NIST StRD linear regression data sets
+    // https://www.itl.nist.gov/div898/strd/lls/lls.shtml
+
+    #[test]
+    fn test_nist_norris() {
+        let (y, x) = columns(&testing::load_table("nist/norris.txt"));
+        let fit = try_create(&x, &y);
+        let coefficients = [-0.262323073774029, 1.00211681802045];
+        let std_errors = [0.232818234301152, 0.429796848199937E-03];
+        for j in 0..2 {
+            assert_almost_eq!(fit.coefficients()[j], coefficients[j], 1e-12 * coefficients[j].abs());
+            assert_almost_eq!(fit.std_errors()[j], std_errors[j], 1e-13 * std_errors[j]);
+        }
+        assert_almost_eq!(fit.residual_std_error(), 0.884796396144373, 1e-13);
+        assert_almost_eq!(fit.r_squared(), 0.999993745883712, 1e-14);
+        assert_almost_eq!(fit.f_statistic(), 5436385.54079785, 1e-6);
+        assert_eq!(fit.freedom(), 34.0);
+    }
+
+    #[test]
+    fn test_nist_longley() {
+        let (y, x) = columns(&testing::load_table("nist/longley.txt"));
+        let fit = try_create(&x, &y);
+        let coefficients = [-3482258.63459582, 15.0618722713733, -0.358191792925910E-01,
+                            -2.02022980381683, -1.03322686717359, -0.511041056535807E-01,
+                            1829.15146461355];
+        let std_errors = [890420.383607373, 84.9149257747669, 0.334910077722432E-01,
+                          0.488399681651699, 0.214274163161675, 0.226073200069370,
+                          455.478499142212];
+        for j in 0..7 {
+            assert_almost_eq!(fit.coefficients()[j], coefficients[j], 1e-11 * coefficients[j].abs());
+            assert_almost_eq!(fit.std_errors()[j], std_errors[j], 1e-11 * std_errors[j]);
+        }
+        assert_almost_eq!(fit.residual_std_error(), 304.854073561965, 1e-9);
+        assert_almost_eq!(fit.r_squared(), 0.995479004577296, 1e-12);
+        assert_almost_eq!(fit.f_statistic(), 330.285339234588, 1e-8);
+        assert_eq!(fit.freedom(), 9.0);
+    }
+}
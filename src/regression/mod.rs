@@ -0,0 +1,6 @@
+//! Provides regression models for fitting and making inferences about the
+//! relationship between a response and predictor variables
+
+pub use self::linear_regression::LinearRegression;
+
+mod linear_regression;
@@ -0,0 +1,396 @@
+use std::f64;
+use rand::Rng;
+use error::StatsError;
+use function::erf;
+use result::Result;
+use super::{QuantileDefinition, SortedData, Statistics};
+
+/// Implements the nonparametric [bootstrap](https://en.wikipedia.org/wiki/Bootstrapping_(statistics))
+/// of an arbitrary statistic of a data set, providing its standard error and
+/// percentile, basic and bias-corrected and accelerated (BCa) confidence
+/// intervals
+///
+/// # Remarks
+///
+/// The interval bounds are quantiles of the bootstrap replicates, estimated
+/// with `QuantileDefinition::R7`. If the statistic returns `f64::NAN` for any
+/// replicate, the standard error and every interval are `f64::NAN`
+///
+/// # Examples
+///
+/// ```
+/// # extern crate rand;
+/// # extern crate statrs;
+/// use rand::{SeedableRng, StdRng};
+/// use statrs::statistics::{Bootstrap, Statistics};
+///
+/// # fn main() {
+/// let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+/// let x = [2.1, 3.5, 0.7, 1.9, 8.4, 2.2, 4.0, 3.1, 2.8, 5.6];
+/// let boot = Bootstrap::new(&x, |s| s.median_copy(), &mut r, 2000).unwrap();
+/// let (low, high) = boot.bca_interval(0.9).unwrap();
+/// assert!(low < boot.estimate() && boot.estimate() < high);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bootstrap {
+    estimate: f64,
+    std_error: f64,
+    bias_correction: f64,
+    acceleration: f64,
+    replicates: SortedData,
+}
+
+impl Bootstrap {
+    /// Computes `statistic` on `data` and on `replicates` resamples of
+    /// `data` drawn with replacement using `r` as the source of randomness.
+    /// `statistic` is additionally evaluated on the `N` leave-one-out
+    /// subsamples of `data` to estimate the acceleration of the BCa interval
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` contains fewer than two elements or
+    /// `replicates` is less than two
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate statrs;
+    /// use rand::{SeedableRng, StdRng};
+    /// use statrs::statistics::{Bootstrap, Statistics};
+    ///
+    /// # fn main() {
+    /// let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+    /// let result = Bootstrap::new(&[1.0, 2.0, 4.0], |s| s.mean(), &mut r, 100);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = Bootstrap::new(&[1.0], |s| s.mean(), &mut r, 100);
+    /// assert!(result.is_err());
+    /// # }
+    /// ```
+    pub fn new<F, R>(data: &[f64], mut statistic: F, r: &mut R, replicates: usize) -> Result<Bootstrap>
+        where F: FnMut(&[f64]) -> f64,
+              R: Rng
+    {
+        if data.len() < 2 || replicates < 2 {
+            return Err(StatsError::BadParams);
+        }
+        let estimate = statistic(data);
+
+        let mut resample = vec![0.0; data.len()];
+        let mut values = Vec::with_capacity(replicates);
+        for _ in 0..replicates {
+            for x in &mut resample {
+                *x = data[r.gen_range(0, data.len())];
+            }
+            values.push(statistic(&resample));
+        }
+        let std_error = values.std_dev();
+        let below = values.iter().filter(|&&x| x < estimate).count();
+        let bias_correction = normal_quantile(below as f64 / replicates as f64);
+
+        let mut subsample = data[1..].to_vec();
+        let mut jackknife = Vec::with_capacity(data.len());
+        for i in 0..data.len() {
+            // the subsample holds every entry but the i-th one
+            if i > 0 {
+                subsample[i - 1] = data[i - 1];
+            }
+            jackknife.push(statistic(&subsample));
+        }
+        let mean = jackknife.mean();
+        let (sum2, sum3) = jackknife.iter().fold((0.0, 0.0), |(sum2, sum3), &x| {
+            let d = mean - x;
+            (sum2 + d * d, sum3 + d * d * d)
+        });
+        let acceleration = if sum2 == 0.0 {
+            0.0
+        } else {
+            sum3 / (6.0 * sum2.powf(1.5))
+        };
+
+        Ok(Bootstrap {
+            estimate: estimate,
+            std_error: std_error,
+            bias_correction: bias_correction,
+            acceleration: acceleration,
+            replicates: SortedData::from(values),
+        })
+    }
+
+    /// Returns the statistic computed on the original data
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Returns the bootstrap estimate of the standard error of the
+    /// statistic, the sample standard deviation of the replicates
+    pub fn std_error(&self) -> f64 {
+        self.std_error
+    }
+
+    /// Returns the bias correction `z_0` of the BCa interval
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// z_0 = Φ^-1(#{b: θ*_b < θ} / B)
+    /// ```
+    ///
+    /// where `θ` is the estimate, `θ*_b` are the `B` replicates and `Φ^-1`
+    /// is the quantile function of the standard normal distribution
+    pub fn bias_correction(&self) -> f64 {
+        self.bias_correction
+    }
+
+    /// Returns the acceleration `a` of the BCa interval, estimated by the
+    /// jackknife
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0` if the statistic is the same on every leave-one-out
+    /// subsample
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a = Σ(θ_(.) - θ_(i))^3 / (6 * (Σ(θ_(.) - θ_(i))^2)^(3/2))
+    /// ```
+    ///
+    /// where `θ_(i)` is the statistic computed without the `i`-th entry of
+    /// the data and `θ_(.)` is their mean
+    pub fn acceleration(&self) -> f64 {
+        self.acceleration
+    }
+
+    /// Returns the bootstrap replicates of the statistic in ascending order
+    pub fn replicates(&self) -> &[f64] {
+        self.replicates.as_slice()
+    }
+
+    /// Returns the percentile confidence interval of the statistic at the
+    /// `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `confidence` is not in the interval `(0, 1)`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (q(α / 2), q(1 - α / 2))
+    /// ```
+    ///
+    /// where `q` is the quantile of the replicates and `α = 1 - confidence`
+    pub fn percentile_interval(&self, confidence: f64) -> Result<(f64, f64)> {
+        let (low, high) = tail_probabilities(confidence)?;
+        Ok((self.quantile(low), self.quantile(high)))
+    }
+
+    /// Returns the basic, or reverse percentile, confidence interval of the
+    /// statistic at the `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `confidence` is not in the interval `(0, 1)`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2θ - q(1 - α / 2), 2θ - q(α / 2))
+    /// ```
+    ///
+    /// where `θ` is the estimate, `q` is the quantile of the replicates and
+    /// `α = 1 - confidence`
+    pub fn basic_interval(&self, confidence: f64) -> Result<(f64, f64)> {
+        let (low, high) = self.percentile_interval(confidence)?;
+        Ok((2.0 * self.estimate - high, 2.0 * self.estimate - low))
+    }
+
+    /// Returns the bias-corrected and accelerated (BCa) confidence interval
+    /// of the statistic at the `confidence` level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `confidence` is not in the interval `(0, 1)`
+    ///
+    /// # Remarks
+    ///
+    /// The interval is `(f64::NAN, f64::NAN)` if all replicates lie on the
+    /// same side of the estimate, since the bias correction is then infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (q(α_1), q(α_2))
+    /// α_i = Φ(z_0 + (z_0 + z_i) / (1 - a * (z_0 + z_i)))
+    /// ```
+    ///
+    /// where `q` is the quantile of the replicates, `Φ` is the cdf of the
+    /// standard normal distribution, `z_1` and `z_2` are its `α / 2` and
+    /// `1 - α / 2` quantiles with `α = 1 - confidence`, and `z_0` and `a`
+    /// are the bias correction and acceleration
+    pub fn bca_interval(&self, confidence: f64) -> Result<(f64, f64)> {
+        let (low, high) = tail_probabilities(confidence)?;
+        let adjust = |p: f64| {
+            let z = self.bias_correction + normal_quantile(p);
+            normal_cdf(self.bias_correction + z / (1.0 - self.acceleration * z))
+        };
+        Ok((self.quantile(adjust(low)), self.quantile(adjust(high))))
+    }
+
+    fn quantile(&self, tau: f64) -> f64 {
+        self.replicates.quantile_custom(tau, QuantileDefinition::R7)
+    }
+}
+
+fn tail_probabilities(confidence: f64) -> Result<(f64, f64)> {
+    if confidence > 0.0 && confidence < 1.0 {
+        Ok(((1.0 - confidence) / 2.0, (1.0 + confidence) / 2.0))
+    } else {
+        Err(StatsError::ArgIntervalExcl("confidence", 0.0, 1.0))
+    }
+}
+
+fn normal_quantile(p: f64) -> f64 {
+    f64::consts::SQRT_2 * erf::erf_inv(2.0 * p - 1.0)
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * erf::erfc(-x / f64::consts::SQRT_2)
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::{SeedableRng, StdRng};
+    use function::erf;
+    use statistics::*;
+
+    const DATA: [f64; 12] = [2.1, 3.5, 0.7, 1.9, 8.4, 2.2, 4.0, 3.1, 2.8, 5.6, 1.2, 12.3];
+
+    fn try_create<F: FnMut(&[f64]) -> f64>(data: &[f64], statistic: F, replicates: usize) -> Bootstrap {
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        let boot = Bootstrap::new(data, statistic, &mut r, replicates);
+        assert!(boot.is_ok());
+        boot.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let boot = try_create(&DATA, |s| s.mean(), 500);
+        assert_eq!(boot.estimate(), DATA.mean());
+        assert_eq!(boot.replicates().len(), 500);
+        assert!(boot.replicates().windows(2).all(|w| w[0] <= w[1]));
+        assert!(boot.replicates().iter().all(|&x| x >= 0.7 && x <= 12.3));
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let mut r = StdRng::from_seed(&[1, 2, 3, 4]);
+        assert!(Bootstrap::new(&[], |s| s.mean(), &mut r, 100).is_err());
+        assert!(Bootstrap::new(&[1.0], |s| s.mean(), &mut r, 100).is_err());
+        assert!(Bootstrap::new(&DATA, |s| s.mean(), &mut r, 1).is_err());
+    }
+
+    #[test]
+    fn test_std_error() {
+        // the bootstrap standard error of the mean converges to the
+        // population standard deviation over sqrt(N)
+        let boot = try_create(&DATA, |s| s.mean(), 20000);
+        let expected = DATA.population_std_dev() / (DATA.len() as f64).sqrt();
+        assert_almost_eq!(boot.std_error(), expected, 0.02);
+    }
+
+    #[test]
+    fn test_acceleration() {
+        // for the mean the jackknife acceleration reduces to the sample skewness
+        let boot = try_create(&DATA, |s| s.mean(), 10);
+        let mean = DATA.mean();
+        let m2: f64 = DATA.iter().map(|x| (x - mean).powi(2)).sum();
+        let m3: f64 = DATA.iter().map(|x| (x - mean).powi(3)).sum();
+        assert_almost_eq!(boot.acceleration(), m3 / (6.0 * m2.powf(1.5)), 1e-14);
+        assert!(boot.acceleration() > 0.0);
+
+        let boot = try_create(&[-2.0, -1.0, 0.0, 1.0, 2.0], |s| s.mean(), 10);
+        assert_almost_eq!(boot.acceleration(), 0.0, 1e-15);
+        let boot = try_create(&DATA, |_| 1.0, 10);
+        assert_eq!(boot.acceleration(), 0.0);
+    }
+
+    #[test]
+    fn test_bias_correction() {
+        let boot = try_create(&DATA, |s| s.mean(), 1000);
+        let below = boot.replicates().iter().filter(|&&x| x < boot.estimate()).count();
+        let p = 0.5 * erf::erfc(-boot.bias_correction() / f64::consts::SQRT_2);
+        assert_almost_eq!(p, below as f64 / 1000.0, 1e-12);
+    }
+
+    #[test]
+    fn test_percentile_basic_interval() {
+        let boot = try_create(&DATA, |s| s.mean(), 2000);
+        let (low, high) = boot.percentile_interval(0.9).unwrap();
+        assert_eq!(low, boot.replicates().quantile_custom_copy(0.05, QuantileDefinition::R7));
+        assert_eq!(high, boot.replicates().quantile_custom_copy(0.95, QuantileDefinition::R7));
+        let (basic_low, basic_high) = boot.basic_interval(0.9).unwrap();
+        assert_almost_eq!(basic_low, 2.0 * boot.estimate() - high, 1e-14);
+        assert_almost_eq!(basic_high, 2.0 * boot.estimate() - low, 1e-14);
+
+        // close to the normal interval of the mean
+        let half_width = 1.6448536269514722 * boot.std_error();
+        assert_almost_eq!(low, boot.estimate() - half_width, 0.3);
+        assert_almost_eq!(high, boot.estimate() + half_width, 0.3);
+    }
+
+    #[test]
+    fn test_bca_interval() {
+        let boot = try_create(&DATA, |s| s.mean(), 2000);
+        let (low, high) = boot.bca_interval(0.9).unwrap();
+        let (p_low, p_high) = boot.percentile_interval(0.9).unwrap();
+        assert!(low < boot.estimate() && boot.estimate() < high);
+        // the data is right skewed so BCa shifts the interval to the right
+        assert!(low > p_low);
+        assert!(high > p_high);
+
+        let wide = boot.bca_interval(0.99).unwrap();
+        assert!(wide.0 < low && wide.1 > high);
+    }
+
+    #[test]
+    fn test_bca_interval_reduces_to_percentile() {
+        let boot = Bootstrap { bias_correction: 0.0, acceleration: 0.0, ..try_create(&DATA, |s| s.mean(), 2000) };
+        let (low, high) = boot.bca_interval(0.8).unwrap();
+        let (p_low, p_high) = boot.percentile_interval(0.8).unwrap();
+        assert_almost_eq!(low, p_low, 1e-12);
+        assert_almost_eq!(high, p_high, 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let boot = try_create(&[1.0, 1.0, 1.0], |s| s.mean(), 100);
+        assert_eq!(boot.std_error(), 0.0);
+        assert_eq!(boot.percentile_interval(0.95).unwrap(), (1.0, 1.0));
+        assert_eq!(boot.basic_interval(0.95).unwrap(), (1.0, 1.0));
+        let (low, high) = boot.bca_interval(0.95).unwrap();
+        assert!(low.is_nan() && high.is_nan());
+    }
+
+    #[test]
+    fn test_nan_statistic() {
+        let boot = try_create(&DATA, |_| f64::NAN, 100);
+        assert!(boot.std_error().is_nan());
+        assert!(boot.percentile_interval(0.95).unwrap().0.is_nan());
+    }
+
+    #[test]
+    fn test_interval_bad_confidence() {
+        let boot = try_create(&DATA, |s| s.mean(), 10);
+        for &confidence in &[0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert!(boot.percentile_interval(confidence).is_err());
+            assert!(boot.basic_interval(confidence).is_err());
+            assert!(boot.bca_interval(confidence).is_err());
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::f64;
+use error::StatsError;
+use super::{RankTieBreaker, Statistics};
+
+/// Evaluates the [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+/// of the paired samples `x` and `y`
+///
+/// # Panics
+///
+/// If `x.len() != y.len()`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if data has less than two entries, if either sample
+/// is constant or if any entry is `f64::NAN`
+///
+/// # Formula
+///
+/// ```ignore
+/// cov(x, y) / (σ_x * σ_y)
+/// ```
+///
+/// where `cov` is the sample covariance and `σ` the sample standard deviation
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::pearson;
+///
+/// assert_eq!(pearson(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]), 1.0);
+/// assert_eq!(pearson(&[1.0, 2.0, 3.0], &[3.0, 1.0, 2.0]), -0.5);
+/// assert!(pearson(&[1.0, 2.0, 3.0], &[1.0, 1.0, 1.0]).is_nan());
+/// ```
+pub fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+    let r = x.covariance(y) / (x.std_dev() * y.std_dev());
+    // guards against rounding pushing perfectly correlated data out of range
+    if r > 1.0 {
+        1.0
+    } else if r < -1.0 {
+        -1.0
+    } else {
+        r
+    }
+}
+
+/// Evaluates the [Spearman rank correlation coefficient](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient)
+/// of the paired samples `x` and `y`, the Pearson correlation coefficient
+/// of their ranks
+///
+/// # Panics
+///
+/// If `x.len() != y.len()`
+///
+/// # Remarks
+///
+/// Ties are assigned the average of their ranks.
+/// Returns `f64::NAN` if data has less than two entries, if either sample
+/// is constant or if any entry is `f64::NAN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::spearman;
+///
+/// assert_eq!(spearman(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0]), 1.0);
+/// assert_eq!(spearman(&[1.0, 2.0, 3.0], &[9.0, 4.0, 1.0]), -1.0);
+/// ```
+pub fn spearman(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+    if x.iter().chain(y.iter()).any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    pearson(&x.ranks_copy(RankTieBreaker::Average),
+            &y.ranks_copy(RankTieBreaker::Average))
+}
+
+/// Evaluates [Kendall's tau-b](https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient#Tau-b)
+/// rank correlation coefficient of the paired samples `x` and `y`
+///
+/// # Panics
+///
+/// If `x.len() != y.len()`
+///
+/// # Remarks
+///
+/// Computed in `O(n log(n))` time with the algorithm of Knight (1966),
+/// which counts the discordant pairs as the swaps made by a merge sort.
+/// Returns `f64::NAN` if data has less than two entries, if either sample
+/// is constant or if any entry is `f64::NAN`
+///
+/// # Formula
+///
+/// ```ignore
+/// (n_c - n_d) / sqrt((n_0 - n_1) * (n_0 - n_2))
+/// ```
+///
+/// where `n_c` and `n_d` are the numbers of concordant and discordant pairs,
+/// `n_0 = n * (n - 1) / 2` and `n_1` and `n_2` are the numbers of pairs tied
+/// in `x` and in `y` respectively
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::kendall;
+///
+/// assert_eq!(kendall(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0]), 1.0);
+/// assert_eq!(kendall(&[1.0, 2.0, 3.0, 4.0], &[1.0, 3.0, 2.0, 4.0]), 2.0 / 3.0);
+/// ```
+pub fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+    let n = x.len();
+    if n < 2 || x.iter().chain(y.iter()).any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+
+    let mut pairs: Vec<(f64, f64)> = x.iter().cloned().zip(y.iter().cloned()).collect();
+    // NaN was ruled out above so the comparison never fails
+    pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n0 = (n * (n - 1) / 2) as f64;
+    let n1 = tied_pairs(&pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+    let n3 = tied_pairs(&pairs);
+    let mut ys: Vec<f64> = pairs.iter().map(|p| p.1).collect();
+    let swaps = merge_sort_swaps(&mut ys);
+    let n2 = tied_pairs(&ys);
+
+    (n0 - n1 - n2 + n3 - 2.0 * swaps) / ((n0 - n1) * (n0 - n2)).sqrt()
+}
+
+// returns the number of pairs of equal elements in sorted data
+fn tied_pairs<T: PartialEq>(sorted: &[T]) -> f64 {
+    let mut pairs = 0.0;
+    let mut run = 1.0;
+    for i in 1..sorted.len() {
+        if sorted[i] == sorted[i - 1] {
+            run += 1.0;
+        } else {
+            pairs += run * (run - 1.0) / 2.0;
+            run = 1.0;
+        }
+    }
+    pairs + run * (run - 1.0) / 2.0
+}
+
+// sorts the data with a bottom-up merge sort and returns the number of swaps
+// of adjacent elements an exchange sort would need, i.e. the number of
+// strictly inverted pairs
+fn merge_sort_swaps(data: &mut [f64]) -> f64 {
+    let n = data.len();
+    let mut buffer = data.to_vec();
+    let mut swaps = 0.0;
+    let mut width = 1;
+    while width < n {
+        let mut start = 0;
+        while start < n {
+            let mid = (start + width).min(n);
+            let end = (start + 2 * width).min(n);
+            let (mut i, mut j) = (start, mid);
+            for slot in &mut buffer[start..end] {
+                if j < end && (i >= mid || data[j] < data[i]) {
+                    *slot = data[j];
+                    swaps += (mid - i) as f64;
+                    j += 1;
+                } else {
+                    *slot = data[i];
+                    i += 1;
+                }
+            }
+            start = end;
+        }
+        data.copy_from_slice(&buffer);
+        width *= 2;
+    }
+    swaps
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    const X: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    const Y: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+    const A: [f64; 12] = [1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0, 5.0, 6.0, 7.0, 7.0, 8.0];
+    const B: [f64; 12] = [2.0, 1.0, 3.0, 3.0, 5.0, 4.0, 4.0, 7.0, 6.0, 6.0, 9.0, 8.0];
+
+    // brute force O(n^2) tau-b for comparison with the merge sort
+    fn kendall_naive(x: &[f64], y: &[f64]) -> f64 {
+        let (mut s, mut tx, mut ty, mut n0) = (0.0, 0.0, 0.0, 0.0f64);
+        for i in 0..x.len() {
+            for j in i + 1..x.len() {
+                let dx = (x[i] - x[j]).signum() * if x[i] == x[j] { 0.0 } else { 1.0 };
+                let dy = (y[i] - y[j]).signum() * if y[i] == y[j] { 0.0 } else { 1.0 };
+                s += dx * dy;
+                n0 += 1.0;
+                if dx == 0.0 { tx += 1.0; }
+                if dy == 0.0 { ty += 1.0; }
+            }
+        }
+        s / ((n0 - tx) * (n0 - ty)).sqrt()
+    }
+
+    #[test]
+    fn test_pearson() {
+        assert_almost_eq!(pearson(&X, &Y), 0.79517020583357784031, 1e-15);
+        assert_almost_eq!(pearson(&Y, &X), 0.79517020583357784031, 1e-15);
+        assert_almost_eq!(pearson(&A, &B), 0.91310364738976981169, 1e-15);
+        assert_eq!(pearson(&X, &X), 1.0);
+        assert_eq!(pearson(&X, &X.iter().map(|x| -3.0 * x).collect::<Vec<_>>()), -1.0);
+        assert!(pearson(&[], &[]).is_nan());
+        assert!(pearson(&[1.0], &[2.0]).is_nan());
+        assert!(pearson(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0]).is_nan());
+    }
+
+    #[test]
+    fn test_spearman() {
+        assert_almost_eq!(spearman(&X, &Y), 0.78181818181818181818, 1e-15);
+        assert_almost_eq!(spearman(&A, &B), 0.93784635197303870496, 1e-15);
+        assert_eq!(spearman(&[1.0, 2.0, 3.0, 4.0], &[1.0, 8.0, 27.0, 64.0]), 1.0);
+        assert!(spearman(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_nan());
+        assert!(spearman(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0]).is_nan());
+    }
+
+    #[test]
+    fn test_kendall() {
+        assert_almost_eq!(kendall(&X, &Y), 0.6, 1e-15);
+        assert_almost_eq!(kendall(&A, &B), 0.82268766144290636642, 1e-15);
+        assert_eq!(kendall(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]), -1.0);
+        assert!(kendall(&[1.0], &[1.0]).is_nan());
+        assert!(kendall(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_nan());
+        assert!(kendall(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0]).is_nan());
+    }
+
+    #[test]
+    fn test_kendall_matches_naive() {
+        let x: Vec<f64> = (0..200).map(|i| ((i * 37) % 23) as f64).collect();
+        let y: Vec<f64> = (0..200).map(|i| ((i * 53) % 31) as f64 + (i % 7) as f64).collect();
+        assert_almost_eq!(kendall(&x, &y), kendall_naive(&x, &y), 1e-14);
+        assert_almost_eq!(kendall(&A, &B), kendall_naive(&A, &B), 1e-15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pearson_different_lengths() {
+        pearson(&X, &Y[..9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kendall_different_lengths() {
+        kendall(&X, &Y[..9]);
+    }
+}
@@ -0,0 +1,356 @@
+use std::f64;
+use error::StatsError;
+use result::Result;
+use super::Statistics;
+
+/// Enumeration of the rules that choose the number of bins of a `Histogram`
+/// from the data
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BinRule {
+    /// Sturges' rule, `ceil(log2(N)) + 1` bins. Assumes roughly normal data
+    /// and oversmooths large data sets
+    Sturges,
+    /// Scott's rule, bins of width `3.49 * σ * N^(-1 / 3)` where `σ` is the
+    /// sample standard deviation
+    Scott,
+    /// The Freedman-Diaconis rule, bins of width `2 * IQR * N^(-1 / 3)`
+    /// where `IQR` is the inter-quartile range. Robust to outliers
+    FreedmanDiaconis,
+}
+
+/// Counts the entries of a data set falling in each of a sequence of
+/// adjacent bins
+///
+/// # Remarks
+///
+/// Each bin includes its lower edge and excludes its upper edge, except the
+/// last bin, which includes both. Entries outside the edges are not counted
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::Histogram;
+///
+/// let hist = Histogram::new(&[1.0, 2.0, 2.5, 3.0, 5.0], 2).unwrap();
+/// assert_eq!(hist.edges(), &[1.0, 3.0, 5.0]);
+/// assert_eq!(hist.counts(), &[3, 2]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Constructs a new histogram of `data` with `bins` bins of equal width
+    /// spanning the range of the data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, an entry is `f64::NAN` or
+    /// infinite, `bins == 0`, or the range of the data is too narrow to
+    /// be split into `bins` bins of positive width
+    ///
+    /// # Remarks
+    ///
+    /// If every entry is equal the bins span a range of width `1` centered
+    /// on the entries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Histogram;
+    ///
+    /// let hist = Histogram::new(&[0.0, 1.0, 1.5, 4.0], 4).unwrap();
+    /// assert_eq!(hist.edges(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(hist.counts(), &[1, 2, 0, 1]);
+    ///
+    /// assert!(Histogram::new(&[], 4).is_err());
+    /// assert!(Histogram::new(&[1.0, 2.0], 0).is_err());
+    /// ```
+    pub fn new(data: &[f64], bins: usize) -> Result<Histogram> {
+        if bins == 0 {
+            return Err(StatsError::BadParams);
+        }
+        let (min, max) = range(data)?;
+        let (min, max) = if min == max { (min - 0.5, max + 0.5) } else { (min, max) };
+        let mut edges: Vec<f64> = (0..bins)
+            .map(|i| interpolate(min, max, i as f64 / bins as f64))
+            .collect();
+        edges.push(max);
+        if edges.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Histogram::count(data, edges))
+    }
+
+    /// Constructs a new histogram of `data` with the bins delimited by
+    /// `edges`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `edges` has less than two entries or is not
+    /// strictly increasing, or an entry of `data` or `edges` is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Histogram;
+    ///
+    /// let hist = Histogram::with_edges(&[0.5, 1.0, 3.0, 9.0, 12.0], &[0.0, 1.0, 10.0]).unwrap();
+    /// assert_eq!(hist.counts(), &[1, 3]);
+    /// assert_eq!(hist.total(), 4);
+    ///
+    /// assert!(Histogram::with_edges(&[1.0], &[1.0, 1.0]).is_err());
+    /// ```
+    pub fn with_edges(data: &[f64], edges: &[f64]) -> Result<Histogram> {
+        if edges.len() < 2 || edges.iter().any(|x| x.is_nan()) ||
+           edges.windows(2).any(|w| w[0] >= w[1]) || data.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Histogram::count(data, edges.to_vec()))
+    }
+
+    /// Constructs a new histogram of `data` with bins of equal width
+    /// spanning the range of the data, their number chosen by `rule`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, an entry is `f64::NAN` or
+    /// infinite, or the range of the data is too narrow to be split into
+    /// the chosen number of bins of positive width
+    ///
+    /// # Remarks
+    ///
+    /// A single bin is used if the rule yields a bin width of zero, e.g.
+    /// for `BinRule::FreedmanDiaconis` if most entries are equal. The number
+    /// of bins is capped at the number of entries, as outliers far from a
+    /// narrow bulk of the data can make the rules yield an excessive number
+    /// of bins
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{BinRule, Histogram};
+    ///
+    /// let data: Vec<f64> = (0..100).map(|x| x as f64).collect();
+    /// assert_eq!(Histogram::with_rule(&data, BinRule::Sturges).unwrap().num_bins(), 8);
+    /// assert_eq!(Histogram::with_rule(&data, BinRule::Scott).unwrap().num_bins(), 5);
+    /// assert_eq!(Histogram::with_rule(&data, BinRule::FreedmanDiaconis).unwrap().num_bins(), 5);
+    /// ```
+    pub fn with_rule(data: &[f64], rule: BinRule) -> Result<Histogram> {
+        let (min, max) = range(data)?;
+        let n = data.len() as f64;
+        let width = match rule {
+            BinRule::Sturges => return Histogram::new(data, n.log2().ceil() as usize + 1),
+            BinRule::Scott => 3.49 * data.std_dev() * n.powf(-1.0 / 3.0),
+            BinRule::FreedmanDiaconis => 2.0 * data.interquartile_range_copy() * n.powf(-1.0 / 3.0),
+        };
+        if width.is_nan() || width <= 0.0 {
+            return Histogram::new(data, 1);
+        }
+        let span = max - min;
+        let bins = if span.is_finite() { span / width } else { max / width - min / width };
+        Histogram::new(data, bins.ceil().max(1.0).min(n) as usize)
+    }
+
+    /// Returns the edges of the bins in increasing order, one more than the
+    /// number of bins
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Returns the number of entries in each bin
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of bins
+    pub fn num_bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns the number of entries counted in any bin
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the index of the bin that `x` falls in, or `None` if `x` is
+    /// outside the edges or `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Histogram;
+    ///
+    /// let hist = Histogram::with_edges(&[], &[0.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!(hist.bin_index(0.0), Some(0));
+    /// assert_eq!(hist.bin_index(1.0), Some(1));
+    /// assert_eq!(hist.bin_index(2.0), Some(1));
+    /// assert_eq!(hist.bin_index(2.5), None);
+    /// ```
+    pub fn bin_index(&self, x: f64) -> Option<usize> {
+        let last = self.edges.len() - 1;
+        if !(x >= self.edges[0] && x <= self.edges[last]) {
+            return None;
+        }
+        // counts the inner edges less than or equal to x by binary search
+        let (mut low, mut high) = (1, last);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.edges[mid] <= x {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Some(low - 1)
+    }
+
+    /// Returns the density of each bin, its count divided by the total
+    /// count and the width of the bin, so that the histogram integrates
+    /// to one
+    ///
+    /// # Remarks
+    ///
+    /// Every density is `f64::NAN` if no entry has been counted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Histogram;
+    ///
+    /// let hist = Histogram::with_edges(&[0.5, 1.5, 3.0], &[0.0, 1.0, 2.0, 4.0]).unwrap();
+    /// assert_eq!(hist.densities(), vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0]);
+    /// ```
+    pub fn densities(&self) -> Vec<f64> {
+        let total = self.total() as f64;
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&count, w)| count as f64 / (total * (w[1] - w[0])))
+            .collect()
+    }
+
+    fn count(data: &[f64], edges: Vec<f64>) -> Histogram {
+        let mut hist = Histogram {
+            counts: vec![0; edges.len() - 1],
+            edges: edges,
+        };
+        for &x in data {
+            if let Some(i) = hist.bin_index(x) {
+                hist.counts[i] += 1;
+            }
+        }
+        hist
+    }
+}
+
+// returns the minimum and maximum of the data, which must be non-empty and finite
+fn range(data: &[f64]) -> Result<(f64, f64)> {
+    if data.is_empty() || data.iter().any(|x| !x.is_finite()) {
+        return Err(StatsError::BadParams);
+    }
+    Ok((data.min(), data.max()))
+}
+
+// returns the point at the fraction `t` of the way from `a` to `b`, without
+// overflowing if `b - a` does
+fn interpolate(a: f64, b: f64, t: f64) -> f64 {
+    let span = b - a;
+    if span.is_finite() { a + span * t } else { a * (1.0 - t) + b * t }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    const DATA: [f64; 12] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0, 2.0, 2.0];
+
+    #[test]
+    fn test_new() {
+        let hist = Histogram::new(&DATA, 3).unwrap();
+        assert_eq!(hist.edges(), &[-3.0, 1.333333333333333, 5.666666666666666, 10.0]);
+        assert_eq!(hist.counts(), &[6, 4, 2]);
+        assert_eq!(hist.total(), 12);
+        assert_eq!(hist.num_bins(), 3);
+
+        let hist = Histogram::new(&[2.0, 2.0], 2).unwrap();
+        assert_eq!(hist.edges(), &[1.5, 2.0, 2.5]);
+        assert_eq!(hist.counts(), &[0, 2]);
+    }
+
+    #[test]
+    fn test_bad_new() {
+        assert!(Histogram::new(&[], 1).is_err());
+        assert!(Histogram::new(&DATA, 0).is_err());
+        assert!(Histogram::new(&[1.0, f64::NAN], 2).is_err());
+        assert!(Histogram::new(&[1.0, f64::INFINITY], 2).is_err());
+        assert!(Histogram::with_rule(&[f64::NAN], BinRule::Scott).is_err());
+        assert!(Histogram::new(&[1.0, 1.0 + 1e-15], 100).is_err());
+    }
+
+    #[test]
+    fn test_new_wide_range() {
+        let hist = Histogram::new(&[-1e308, 1e308], 2).unwrap();
+        assert_eq!(hist.edges(), &[-1e308, 0.0, 1e308]);
+        assert_eq!(hist.counts(), &[1, 1]);
+
+        let hist = Histogram::new(&[-f64::MAX, 0.0, f64::MAX], 4).unwrap();
+        assert!(hist.edges().windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(hist.edges()[2], 0.0);
+        assert_eq!(hist.counts(), &[1, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_with_edges() {
+        let hist = Histogram::with_edges(&DATA, &[-1.0, 0.0, 2.0, 6.0]).unwrap();
+        assert_eq!(hist.counts(), &[2, 3, 5]);
+        assert_eq!(hist.total(), 10);
+        assert_eq!(hist.bin_index(-1.5), None);
+        assert_eq!(hist.bin_index(f64::NAN), None);
+        assert_eq!(hist.bin_index(1.99), Some(1));
+        assert_eq!(hist.bin_index(6.0), Some(2));
+        assert!(Histogram::with_edges(&DATA, &[1.0]).is_err());
+        assert!(Histogram::with_edges(&DATA, &[1.0, 0.0]).is_err());
+        assert!(Histogram::with_edges(&DATA, &[0.0, f64::NAN]).is_err());
+        assert!(Histogram::with_edges(&[f64::NAN], &[0.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_with_rule() {
+        let hist = Histogram::with_rule(&DATA, BinRule::Sturges).unwrap();
+        assert_eq!(hist.num_bins(), 5);
+        assert_eq!(hist.counts(), &[3, 5, 1, 2, 1]);
+        // width 3.49 * 3.5834 * 12^(-1 / 3) = 5.4625
+        assert_eq!(Histogram::with_rule(&DATA, BinRule::Scott).unwrap().num_bins(), 3);
+        // width 2 * 4.875 * 12^(-1 / 3) = 4.2587
+        assert_eq!(Histogram::with_rule(&DATA, BinRule::FreedmanDiaconis).unwrap().num_bins(), 4);
+        let constant = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 5.0];
+        assert_eq!(Histogram::with_rule(&constant, BinRule::FreedmanDiaconis).unwrap().num_bins(), 1);
+        assert_eq!(Histogram::with_rule(&[1.0], BinRule::Scott).unwrap().num_bins(), 1);
+    }
+
+    #[test]
+    fn test_with_rule_outliers() {
+        // a narrow bulk with an inter-quartile range of 5e-11 and a far
+        // outlier would otherwise need about 1e16 bins
+        let mut data: Vec<f64> = (0..100).map(|i| i as f64 * 1e-12).collect();
+        data.push(1e6);
+        let hist = Histogram::with_rule(&data, BinRule::FreedmanDiaconis).unwrap();
+        assert_eq!(hist.num_bins(), data.len());
+        assert_eq!(hist.total(), 101);
+        assert_eq!(hist.counts()[0], 100);
+    }
+
+    #[test]
+    fn test_densities() {
+        let hist = Histogram::new(&DATA, 4).unwrap();
+        let width = 13.0 / 4.0;
+        let integral: f64 = hist.densities().iter().map(|d| d * width).sum();
+        assert_almost_eq!(integral, 1.0, 1e-15);
+        assert!(Histogram::with_edges(&[], &[0.0, 1.0]).unwrap().densities()[0].is_nan());
+    }
+}
@@ -0,0 +1,351 @@
+use std::borrow::Borrow;
+use std::f64;
+use error::StatsError;
+use super::*;
+
+impl<I> IterStatistics for I
+    where I: Iterator,
+          I::Item: Borrow<f64>
+{
+    fn min_value(mut self) -> f64 {
+        match self.next() {
+            None => f64::NAN,
+            Some(x) => {
+                self.map(|x| *x.borrow())
+                    .fold(*x.borrow(),
+                          |acc, x| if x < acc || x.is_nan() { x } else { acc })
+            }
+        }
+    }
+
+    fn max_value(mut self) -> f64 {
+        match self.next() {
+            None => f64::NAN,
+            Some(x) => {
+                self.map(|x| *x.borrow())
+                    .fold(*x.borrow(),
+                          |acc, x| if x > acc || x.is_nan() { x } else { acc })
+            }
+        }
+    }
+
+    fn abs_min(mut self) -> f64 {
+        match self.next() {
+            None => f64::NAN,
+            Some(x) => {
+                self.map(|x| x.borrow().abs())
+                    .fold(x.borrow().abs(),
+                          |acc, x| if x < acc || x.is_nan() { x } else { acc })
+            }
+        }
+    }
+
+    fn abs_max(mut self) -> f64 {
+        match self.next() {
+            None => f64::NAN,
+            Some(x) => {
+                self.map(|x| x.borrow().abs())
+                    .fold(x.borrow().abs(),
+                          |acc, x| if x > acc || x.is_nan() { x } else { acc })
+            }
+        }
+    }
+
+    fn mean(self) -> f64 {
+        let mut i = 0.0;
+        let mut mean = 0.0;
+        for x in self {
+            i += 1.0;
+            mean += (*x.borrow() - mean) / i;
+        }
+        if i > 0.0 { mean } else { f64::NAN }
+    }
+
+    fn geometric_mean(self) -> f64 {
+        let mut i = 0.0;
+        let mut sum = 0.0;
+        for x in self {
+            let x = *x.borrow();
+            i += 1.0;
+            sum += if x < 0.0 { f64::NAN } else { x.ln() };
+        }
+        if i > 0.0 { (sum / i).exp() } else { f64::NAN }
+    }
+
+    fn harmonic_mean(self) -> f64 {
+        let mut i = 0.0;
+        let mut sum = 0.0;
+        for x in self {
+            let x = *x.borrow();
+            i += 1.0;
+            sum += if x < 0.0 { f64::NAN } else { 1.0 / x };
+        }
+        if i > 0.0 { i / sum } else { f64::NAN }
+    }
+
+    fn variance(self) -> f64 {
+        let (n, var) = sum_squares(self);
+        if n > 1 { var / (n - 1) as f64 } else { f64::NAN }
+    }
+
+    fn population_variance(self) -> f64 {
+        let (n, var) = sum_squares(self);
+        if n > 0 { var / n as f64 } else { f64::NAN }
+    }
+
+    fn std_dev(self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn population_std_dev(self) -> f64 {
+        self.population_variance().sqrt()
+    }
+
+    fn covariance<J>(self, other: J) -> f64
+        where J: IntoIterator,
+              J::Item: Borrow<f64>
+    {
+        let (n, comoment) = sum_products(self, other);
+        if n > 1 {
+            comoment / (n - 1) as f64
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn population_covariance<J>(self, other: J) -> f64
+        where J: IntoIterator,
+              J::Item: Borrow<f64>
+    {
+        let (n, comoment) = sum_products(self, other);
+        if n > 0 { comoment / n as f64 } else { f64::NAN }
+    }
+
+    fn quadratic_mean(self) -> f64 {
+        let mut i = 0.0;
+        let mut mean = 0.0;
+        for x in self {
+            let x = *x.borrow();
+            i += 1.0;
+            mean += (x * x - mean) / i;
+        }
+        if i > 0.0 { mean.sqrt() } else { f64::NAN }
+    }
+
+    fn skewness(self) -> f64
+        where Self: Clone
+    {
+        let (n, m2, m3, _) = sum_powers(self);
+        if n < 3 {
+            return f64::NAN;
+        }
+        let n = n as f64;
+        (n * (n - 1.0)).sqrt() / (n - 2.0) * n.sqrt() * m3 / m2.powf(1.5)
+    }
+
+    fn population_skewness(self) -> f64
+        where Self: Clone
+    {
+        let (n, m2, m3, _) = sum_powers(self);
+        if n < 2 {
+            return f64::NAN;
+        }
+        (n as f64).sqrt() * m3 / m2.powf(1.5)
+    }
+
+    fn kurtosis(self) -> f64
+        where Self: Clone
+    {
+        let (n, m2, _, m4) = sum_powers(self);
+        if n < 4 {
+            return f64::NAN;
+        }
+        let n = n as f64;
+        let g2 = n * m4 / (m2 * m2) - 3.0;
+        (n * n - 1.0) / ((n - 2.0) * (n - 3.0)) * (g2 + 6.0 / (n + 1.0))
+    }
+
+    fn population_kurtosis(self) -> f64
+        where Self: Clone
+    {
+        let (n, m2, _, m4) = sum_powers(self);
+        if n < 2 {
+            return f64::NAN;
+        }
+        n as f64 * m4 / (m2 * m2) - 3.0
+    }
+
+    fn central_moment(self, order: i32) -> f64
+        where Self: Clone
+    {
+        let mean = self.clone().mean();
+        let mut i = 0.0;
+        let mut moment = 0.0;
+        for x in self {
+            i += 1.0;
+            moment += ((*x.borrow() - mean).powi(order) - moment) / i;
+        }
+        if i > 0.0 { moment } else { f64::NAN }
+    }
+
+    fn raw_moment(self, order: i32) -> f64 {
+        let mut i = 0.0;
+        let mut moment = 0.0;
+        for x in self {
+            i += 1.0;
+            moment += (x.borrow().powi(order) - moment) / i;
+        }
+        if i > 0.0 { moment } else { f64::NAN }
+    }
+}
+
+// returns the number of entries and the sum of squared deviations from the
+// mean of the data in a single pass
+fn sum_squares<I>(data: I) -> (u64, f64)
+    where I: IntoIterator,
+          I::Item: Borrow<f64>
+{
+    let mut iter = data.into_iter();
+    let mut sum = match iter.next() {
+        None => return (0, 0.0),
+        Some(x) => *x.borrow(),
+    };
+    let mut n = 1;
+    let mut var = 0.0;
+    for x in iter {
+        let x = *x.borrow();
+        n += 1;
+        sum += x;
+        let diff = n as f64 * x - sum;
+        var += diff * diff / (n * (n - 1)) as f64;
+    }
+    (n, var)
+}
+
+// returns the number of entries and the sums of the second, third and fourth
+// powers of the deviations from the mean of the data. Makes one pass for the
+// mean and a second pass for the deviations, correcting the sums for the
+// rounding error in the mean
+fn sum_powers<I>(data: I) -> (u64, f64, f64, f64)
+    where I: Iterator + Clone,
+          I::Item: Borrow<f64>
+{
+    let mean = data.clone().mean();
+    let mut n = 0;
+    let mut sum = 0.0;
+    let mut m2 = 0.0;
+    let mut m3 = 0.0;
+    let mut m4 = 0.0;
+    for x in data {
+        let d = *x.borrow() - mean;
+        let d2 = d * d;
+        n += 1;
+        sum += d;
+        m2 += d2;
+        m3 += d2 * d;
+        m4 += d2 * d2;
+    }
+    if n > 0 {
+        // shifts the deviations by the mean of the deviations, which is the
+        // rounding error of the first pass
+        let k = n as f64;
+        let c = sum / k;
+        m4 += c * (-4.0 * m3 + c * (6.0 * m2 - 3.0 * k * c * c));
+        m3 += c * (-3.0 * m2 + 2.0 * k * c * c);
+        m2 -= k * c * c;
+    }
+    (n, m2, m3, m4)
+}
+
+// returns the number of entries and the sum of the products of the deviations
+// of the two data sets from their means in a single pass. The update is
+// symmetric in the two data sets so that swapping them gives the same result
+fn sum_products<I, J>(first: I, second: J) -> (u64, f64)
+    where I: IntoIterator,
+          I::Item: Borrow<f64>,
+          J: IntoIterator,
+          J::Item: Borrow<f64>
+{
+    let mut second = second.into_iter();
+    let mut n = 0;
+    let mut mean1 = 0.0;
+    let mut mean2 = 0.0;
+    let mut comoment = 0.0;
+    for x in first {
+        let y = match second.next() {
+            Some(y) => *y.borrow(),
+            None => panic!("{}", StatsError::ContainersMustBeSameLength),
+        };
+        let x = *x.borrow();
+        n += 1;
+        let d1 = x - mean1;
+        let d2 = y - mean2;
+        let k = n as f64;
+        comoment += d1 * d2 * (k - 1.0) / k;
+        mean1 += d1 / k;
+        mean2 += d2 / k;
+    }
+    assert!(second.next().is_none(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+    (n, comoment)
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::iter;
+    use statistics::*;
+    use testing;
+
+    #[test]
+    #[should_panic]
+    fn test_covariance_different_lengths() {
+        [1.0, 2.0].iter().covariance([1.0, 2.0, 3.0].iter());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let data = testing::load_data("nist/lottery.txt");
+        assert_eq!(data.iter().mean(), data.mean());
+        assert_eq!(data.iter().map(|x| x * 2.0).variance(), 4.0 * data.variance());
+        assert_eq!(data.iter().min_value(), data.min());
+        assert_eq!(data.iter().cloned().max_value(), data.max());
+        assert_eq!(iter::repeat(2.0).take(10).std_dev(), 0.0);
+        assert!(iter::empty::<f64>().mean().is_nan());
+    }
+
+    #[test]
+    fn test_iterators_of_fields() {
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        let points = [Point { x: 0.0, y: -5.0 }, Point { x: 3.0, y: 4.0 }, Point { x: -2.0, y: 10.0 }];
+        let x: Vec<f64> = points.iter().map(|p| p.x).collect();
+        let y: Vec<f64> = points.iter().map(|p| p.y).collect();
+        assert_eq!(points.iter().map(|p| p.x).mean(), x.mean());
+        assert_eq!(points.iter().map(|p| p.x).covariance(points.iter().map(|p| p.y)), x.covariance(&y));
+        assert_eq!(points.iter().map(|p| p.y).population_covariance(&x), y.population_covariance(&x));
+        assert_eq!(points.iter().map(|p| p.y).min_value(), -5.0);
+    }
+
+    #[test]
+    fn test_covariance_of_different_containers() {
+        let x = vec![0.0, 3.0, -2.0];
+        let y = [-5.0f64, 4.0, 10.0];
+        assert_eq!(x.covariance(y.iter()), -5.5);
+        assert_eq!(x.covariance(vec![-5.0, 4.0, 10.0]), -5.5);
+        assert_eq!(x.iter().covariance(&y), -5.5);
+        assert_eq!(x.iter().cloned().population_covariance(y.iter().map(|v| *v)), -11.0 / 3.0);
+    }
+
+    #[test]
+    fn test_higher_moments_iterators() {
+        let data = [1.0, 2.0, 3.0, 4.0, 10.0];
+        assert_eq!(data.iter().skewness(), data.skewness());
+        assert_eq!(data.iter().map(|x| x * 2.0).central_moment(2), 40.0);
+        assert_eq!((1..6).map(|x| x as f64).raw_moment(3), 45.0);
+    }
+}
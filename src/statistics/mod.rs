@@ -1,6 +1,25 @@
 //! Provides statistical computation utilities for data sets
 
+use std::borrow::{Borrow, Cow};
+use result::Result;
+
+pub use self::bootstrap::Bootstrap;
+pub use self::correlation::{kendall, pearson, spearman};
+pub use self::histogram::{BinRule, Histogram};
+pub use self::running_statistics::RunningStatistics;
+pub use self::sorted_data::SortedData;
+pub use self::summary::{describe, Summary};
+
+mod bootstrap;
+mod correlation;
+mod histogram;
+mod iter_statistics;
+mod nan_statistics;
+mod running_statistics;
 mod slice_statistics;
+mod sorted_data;
+mod summary;
+mod weighted_statistics;
 
 /// Enumeration of possible tie-breaking strategies
 /// when computing ranks
@@ -16,8 +35,91 @@ pub enum RankTieBreaker {
     First,
 }
 
+/// Enumeration of the sample quantile definitions of
+/// [Hyndman and Fan](https://doi.org/10.2307/2684934), numbered as the `type`
+/// argument of R's `quantile` function
+///
+/// # Remarks
+///
+/// The first three definitions are discontinuous and return one of the data
+/// values or the average of two data values. The others interpolate linearly
+/// between adjacent order statistics and differ in the plotting position
+/// `h` they assign to the tau-th quantile, where the data is sorted and
+/// one-based
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QuantileDefinition {
+    /// Inverse of the empirical distribution function (SAS definition 3)
+    R1,
+    /// Inverse of the empirical distribution function, averaging at
+    /// discontinuities (SAS definition 5)
+    R2,
+    /// Observation closest to `N * tau`, ties broken towards the even order
+    /// statistic (SAS definition 2)
+    R3,
+    /// Linear interpolation of the empirical distribution function with
+    /// `h = N * tau` (SAS definition 1)
+    R4,
+    /// Piecewise linear function with the knots at the midpoints of the
+    /// steps of the empirical distribution function, `h = N * tau + 1 / 2`
+    R5,
+    /// Linear interpolation of the expectations of the order statistics of
+    /// the uniform distribution, `h = (N + 1) * tau`. Used by Minitab, SPSS
+    /// and NIST (SAS definition 4)
+    R6,
+    /// Linear interpolation of the modes of the order statistics of the
+    /// uniform distribution, `h = (N - 1) * tau + 1`. The default of R,
+    /// Excel's `PERCENTILE` and numpy
+    R7,
+    /// Linear interpolation of the approximate medians of the order
+    /// statistics, `h = (N + 1 / 3) * tau + 1 / 3`. Approximately
+    /// median-unbiased regardless of the distribution and the definition
+    /// used by `Statistics::quantile`
+    R8,
+    /// Linear interpolation of the approximate expectations of the order
+    /// statistics of the normal distribution, `h = (N + 1 / 4) * tau + 3 / 8`
+    R9,
+}
+
+/// Enumeration of the ways `NanStatistics` treats entries that are `f64::NAN`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Keeps the entries, so that statistics of the data are `f64::NAN`
+    Propagate,
+    /// Skips the entries, computing statistics from the remaining data
+    Omit,
+    /// Fails if there is any such entry
+    Error,
+}
+
+/// Enumeration of the interpretations of the weights of weighted
+/// statistics, which determine the correction applied to unbiased estimates
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WeightType {
+    /// Each weight counts the number of times its value was observed,
+    /// so the sum of the weights is the sample size
+    Frequency,
+    /// Each weight expresses the relative importance or precision of its
+    /// value, e.g. the inverse of its variance, so only their ratios matter
+    Reliability,
+}
+
 /// The statistics trait provides a host of statistical utilities for analzying
 /// data sets
+///
+/// # Remarks
+///
+/// Implemented for slices of any numeric type that converts into `f64`, such
+/// as `[f64]`, `[f32]` or `[u32]`. The order statistics select values in place
+/// and may reorder the data, while their `_copy` variants work on a copy and
+/// leave the data untouched. Use `SortedData` to answer many queries on the
+/// same data without repeated selection.
+///
+/// Every order statistic is `f64::NAN` if an entry is `f64::NAN`. Use
+/// `NanStatistics` to skip such entries instead. Use `IterStatistics` for data
+/// that is only available through an iterator.
+///
+/// As `min` and `max` are also methods of `Ord`, they must be called as
+/// `Statistics::min(&data[..])` on containers of integers
 pub trait Statistics {
     /// Returns the minimum value in the data
     ///
@@ -31,7 +133,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.min().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -54,7 +156,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.max().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -77,7 +179,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.abs_min().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -100,7 +202,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.abs_max().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -128,7 +230,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -158,7 +260,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.geometric_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -195,7 +297,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.harmonic_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -228,7 +330,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.variance().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -254,7 +356,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.population_variance().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -279,7 +381,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.std_dev().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -305,7 +407,7 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.population_std_dev().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -334,8 +436,8 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
-    /// assert!(x.covariance(&[]).is_nan());
+    /// let x: [f64; 0] = [];
+    /// assert!(x.covariance(&x).is_nan());
     ///
     /// let y1 = [0.0, f64::NAN, 3.0, -2.0];
     /// let y2 = [-5.0, 4.0, 10.0, f64::NAN];
@@ -345,7 +447,9 @@ pub trait Statistics {
     /// let z2 = [-5.0, 4.0, 10.0];
     /// assert_eq!(z1.covariance(&z2), -5.5);
     /// ```
-    fn covariance(&self, other: &Self) -> f64;
+    fn covariance<I>(&self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>;
 
     /// Evaluates the population covariance between the two provider populations
     ///
@@ -366,8 +470,8 @@ pub trait Statistics {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
-    /// assert!(x.population_covariance(&[]).is_nan());
+    /// let x: [f64; 0] = [];
+    /// assert!(x.population_covariance(&x).is_nan());
     ///
     /// let y1 = [0.0, f64::NAN, 3.0, -2.0];
     /// let y2 = [-5.0, 4.0, 10.0, f64::NAN];
@@ -377,7 +481,9 @@ pub trait Statistics {
     /// let z2 = [-5.0, 4.0, 10.0];
     /// assert_eq!(z1.population_covariance(&z2), -11.0 / 3.0);
     /// ```
-    fn population_covariance(&self, other: &Self) -> f64;
+    fn population_covariance<I>(&self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>;
 
     /// Estimates the quadratic mean (Root Mean Square) of the data
     ///
@@ -395,7 +501,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.quadratic_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -408,6 +514,179 @@ pub trait Statistics {
     /// ```
     fn quadratic_mean(&self) -> f64;
 
+    /// Estimates the skewness of the population from the provided samples
+    /// using the adjusted Fisher-Pearson standardized moment coefficient
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data has less than three entries or if any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(N * (N - 1)) / (N - 2) * g_1
+    /// ```
+    ///
+    /// where `g_1` is the population skewness
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [1.0, 2.0];
+    /// assert!(x.skewness().is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_almost_eq!(y.skewness(), 1.2 * 2f64.sqrt(), 1e-15);
+    /// # }
+    /// ```
+    fn skewness(&self) -> f64;
+
+    /// Evaluates the skewness of a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data has less than two entries or if any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// g_1 = m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`-th central moment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [1.0];
+    /// assert!(x.population_skewness().is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_almost_eq!(y.population_skewness(), 1.1384199576606166, 1e-15);
+    /// # }
+    /// ```
+    fn population_skewness(&self) -> f64;
+
+    /// Estimates the excess kurtosis of the population from the provided
+    /// samples, correcting for the bias of the sample moments
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data has less than four entries or if any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (N^2 - 1) / ((N - 2) * (N - 3)) * (g_2 + 6 / (N + 1))
+    /// ```
+    ///
+    /// where `g_2` is the population excess kurtosis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [1.0, 2.0, 3.0];
+    /// assert!(x.kurtosis().is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_almost_eq!(y.kurtosis(), 3.152, 1e-14);
+    /// # }
+    /// ```
+    fn kurtosis(&self) -> f64;
+
+    /// Evaluates the excess kurtosis of a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data has less than two entries or if any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// g_2 = m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`-th central moment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [1.0];
+    /// assert!(x.population_kurtosis().is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_almost_eq!(y.population_kurtosis(), -0.212, 1e-15);
+    /// # }
+    /// ```
+    fn population_kurtosis(&self) -> f64;
+
+    /// Evaluates the `order`-th central moment of the data, i.e. the mean
+    /// of the `order`-th powers of the deviations from the mean
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or if any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: [f64; 0] = [];
+    /// assert!(x.central_moment(2).is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_eq!(y.central_moment(1), 0.0);
+    /// assert_eq!(y.central_moment(2), 10.0);
+    /// assert_eq!(y.central_moment(3), 36.0);
+    /// ```
+    fn central_moment(&self, order: i32) -> f64;
+
+    /// Evaluates the `order`-th raw moment of the data, i.e. the mean of the
+    /// `order`-th powers of the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or if any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: [f64; 0] = [];
+    /// assert!(x.raw_moment(2).is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 10.0];
+    /// assert_eq!(y.raw_moment(1), 4.0);
+    /// assert_eq!(y.raw_moment(2), 26.0);
+    /// ```
+    fn raw_moment(&self, order: i32) -> f64;
+
     /// Returns the order statistic `(order 1..N)` from the data
     ///
     /// # Remarks
@@ -420,7 +699,7 @@ pub trait Statistics {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.order_statistic(1).is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
@@ -442,12 +721,13 @@ pub trait Statistics {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.median().is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
     /// assert_eq!(y.median(), 0.0);
     /// assert!(y != [0.0, 3.0, -2.0]);
+    /// ```
     fn median(&mut self) -> f64;
 
     /// Estimates the tau-th quantile from the data. The tau-th quantile
@@ -463,7 +743,7 @@ pub trait Statistics {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.quantile(0.5).is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
@@ -474,6 +754,31 @@ pub trait Statistics {
     /// ```
     fn quantile(&mut self, tau: f64) -> f64;
 
+    /// Estimates the tau-th quantile from the data using the given
+    /// quantile definition
+    ///
+    /// # Remarks
+    ///
+    /// No sorting is assumed. Tau must be between `0` and `1` inclusive.
+    /// Returns `f64::NAN` if data is empty or tau is outside the inclusive range.
+    /// `quantile` is equivalent to using `QuantileDefinition::R8`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Statistics, QuantileDefinition};
+    ///
+    /// let mut x: [f64; 0] = [];
+    /// assert!(x.quantile_custom(0.5, QuantileDefinition::R7).is_nan());
+    ///
+    /// let mut y = [2.0, 1.0, 3.0, 4.0];
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R1), 1.0);
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R2), 1.5);
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R6), 1.25);
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R7), 1.75);
+    /// ```
+    fn quantile_custom(&mut self, tau: f64, definition: QuantileDefinition) -> f64;
+
     /// Estimates the p-Percentile value from the data.
     ///
     /// # Remarks
@@ -484,9 +789,9 @@ pub trait Statistics {
     /// # Examples
     ///
     /// ```
-    /// use statrs::Statistics;
+    /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.percentile(0).is_nan());
     ///
     /// let mut y = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
@@ -513,7 +818,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.lower_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -538,7 +843,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.lower_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -563,7 +868,7 @@ pub trait Statistics {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.interquartile_range().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -575,12 +880,16 @@ pub trait Statistics {
 
     /// Evaluates the rank of each entry of the data.
     ///
+    /// # Remarks
+    ///
+    /// Every rank is `f64::NAN` if an entry is `f64::NAN`
+    ///
     /// # Examples
     ///
     /// ```
     /// use statrs::statistics::{Statistics, RankTieBreaker};
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert_eq!(x.ranks(RankTieBreaker::Average).len(), 0);
     ///
     /// let y = [1.0, 3.0, 2.0, 2.0];
@@ -588,4 +897,573 @@ pub trait Statistics {
     /// assert_eq!((&mut y.clone()).ranks(RankTieBreaker::Min), [1.0, 4.0, 2.0, 2.0]);
     /// ```
     fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<f64>;
+
+    /// Returns the order statistic `(order 1..N)` from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `order_statistic` but leaves the data untouched. Prefer
+    /// `SortedData` when querying the same data more than once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let y = [0.0, 3.0, -2.0];
+    /// assert_eq!(y.order_statistic_copy(2), 0.0);
+    /// assert_eq!(y, [0.0, 3.0, -2.0]);
+    /// ```
+    fn order_statistic_copy(&self, order: usize) -> f64;
+
+    /// Returns the median value from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `median` but leaves the data untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let y = [0.0, 3.0, -2.0];
+    /// assert_eq!(y.median_copy(), 0.0);
+    /// assert_eq!(y, [0.0, 3.0, -2.0]);
+    /// ```
+    fn median_copy(&self) -> f64;
+
+    /// Estimates the tau-th quantile from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `quantile` but leaves the data untouched. Prefer
+    /// `SortedData` when querying the same data more than once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let y = [0.0, 3.0, -2.0];
+    /// assert_eq!(y.quantile_copy(0.5), 0.0);
+    /// assert_eq!(y, [0.0, 3.0, -2.0]);
+    /// ```
+    fn quantile_copy(&self, tau: f64) -> f64;
+
+    /// Estimates the tau-th quantile from a copy of the data using the
+    /// given quantile definition
+    ///
+    /// # Remarks
+    ///
+    /// Same as `quantile_custom` but leaves the data untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Statistics, QuantileDefinition};
+    ///
+    /// let y = [2.0, 1.0, 3.0, 4.0];
+    /// assert_eq!(y.quantile_custom_copy(0.25, QuantileDefinition::R7), 1.75);
+    /// assert_eq!(y, [2.0, 1.0, 3.0, 4.0]);
+    /// ```
+    fn quantile_custom_copy(&self, tau: f64, definition: QuantileDefinition) -> f64;
+
+    /// Estimates the p-Percentile value from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `percentile` but leaves the data untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let y = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
+    /// assert_eq!(y.percentile_copy(50), 5.5);
+    /// assert_eq!(y, [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0]);
+    /// ```
+    fn percentile_copy(&self, p: usize) -> f64;
+
+    /// Estimates the first quartile value from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `lower_quartile` but leaves the data untouched
+    fn lower_quartile_copy(&self) -> f64;
+
+    /// Estimates the third quartile value from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `upper_quartile` but leaves the data untouched
+    fn upper_quartile_copy(&self) -> f64;
+
+    /// Estimates the inter-quartile range from a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `interquartile_range` but leaves the data untouched
+    fn interquartile_range_copy(&self) -> f64;
+
+    /// Evaluates the rank of each entry of a copy of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `ranks` but leaves the data untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Statistics, RankTieBreaker};
+    ///
+    /// let y = [1.0, 3.0, 2.0, 2.0];
+    /// assert_eq!(y.ranks_copy(RankTieBreaker::Average), [1.0, 4.0, 2.5, 2.5]);
+    /// assert_eq!(y, [1.0, 3.0, 2.0, 2.0]);
+    /// ```
+    fn ranks_copy(&self, tie_breaker: RankTieBreaker) -> Vec<f64>;
+}
+
+/// The `IterStatistics` trait provides the statistical utilities of
+/// `Statistics` that need only one or two passes over the data, for data that
+/// is only available through an iterator
+///
+/// # Remarks
+///
+/// Implemented for any iterator over values that borrow as `f64`, such as
+/// `slice.iter()`, a chain of `map` adaptors or a generator. Every method
+/// consumes the iterator and returns the same result as the method of the
+/// same name of `Statistics` on the collected data, except for `min_value`
+/// and `max_value`, which are named so as not to clash with `Iterator::min`
+/// and `Iterator::max`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::IterStatistics;
+///
+/// struct Sample {
+///     weight: f64,
+/// }
+///
+/// let samples = [Sample { weight: 1.0 }, Sample { weight: 3.0 }];
+/// assert_eq!(samples.iter().map(|s| s.weight).mean(), 2.0);
+/// assert_eq!(samples.iter().map(|s| s.weight).max_value(), 3.0);
+/// assert_eq!((1..5).map(|x| x as f64).variance(), 5.0 / 3.0);
+/// ```
+pub trait IterStatistics {
+    /// Returns the minimum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::min`
+    fn min_value(self) -> f64;
+
+    /// Returns the maximum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::max`
+    fn max_value(self) -> f64;
+
+    /// Returns the minimum absolute value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::abs_min`
+    fn abs_min(self) -> f64;
+
+    /// Returns the maximum absolute value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::abs_max`
+    fn abs_max(self) -> f64;
+
+    /// Evaluates the sample mean, an estimate of the population
+    /// mean.
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::mean`
+    fn mean(self) -> f64;
+
+    /// Evaluates the geometric mean of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::geometric_mean`
+    fn geometric_mean(self) -> f64;
+
+    /// Evaluates the harmonic mean of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::harmonic_mean`
+    fn harmonic_mean(self) -> f64;
+
+    /// Estimates the unbiased population variance from the provided samples
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::variance`
+    fn variance(self) -> f64;
+
+    /// Evaluates the population variance from a full population.
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::population_variance`
+    fn population_variance(self) -> f64;
+
+    /// Estimates the unbiased population standard deviation from the provided samples
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::std_dev`
+    fn std_dev(self) -> f64;
+
+    /// Evaluates the population standard deviation from a full population.
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::population_std_dev`
+    fn population_std_dev(self) -> f64;
+
+    /// Estimates the unbiased population covariance between the two provided samples
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::covariance`
+    ///
+    /// # Panics
+    ///
+    /// If the two samples do not contain the same number of elements
+    fn covariance<I>(self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>;
+
+    /// Evaluates the population covariance between the two provider populations
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::population_covariance`
+    ///
+    /// # Panics
+    ///
+    /// If the two samples do not contain the same number of elements
+    fn population_covariance<I>(self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>;
+
+    /// Estimates the quadratic mean (Root Mean Square) of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::quadratic_mean`
+    fn quadratic_mean(self) -> f64;
+
+    /// Estimates the skewness of the population from the provided samples
+    /// using the adjusted Fisher-Pearson standardized moment coefficient
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::skewness`. Makes two passes over the data, first
+    /// for the mean and then for the deviations from it, so `Self` must be
+    /// `Clone`
+    fn skewness(self) -> f64 where Self: Clone;
+
+    /// Evaluates the skewness of a full population
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::population_skewness`. Makes two passes over the
+    /// data, so `Self` must be `Clone`
+    fn population_skewness(self) -> f64 where Self: Clone;
+
+    /// Estimates the excess kurtosis of the population from the provided
+    /// samples, correcting for the bias of the sample moments
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::kurtosis`. Makes two passes over the data, so
+    /// `Self` must be `Clone`
+    fn kurtosis(self) -> f64 where Self: Clone;
+
+    /// Evaluates the excess kurtosis of a full population
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::population_kurtosis`. Makes two passes over the
+    /// data, so `Self` must be `Clone`
+    fn population_kurtosis(self) -> f64 where Self: Clone;
+
+    /// Evaluates the `order`-th central moment of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::central_moment`. Makes two passes over the data,
+    /// so `Self` must be `Clone`
+    fn central_moment(self, order: i32) -> f64 where Self: Clone;
+
+    /// Evaluates the `order`-th raw moment of the data
+    ///
+    /// # Remarks
+    ///
+    /// Same as `Statistics::raw_moment`
+    fn raw_moment(self, order: i32) -> f64;
+}
+
+
+/// The `WeightedStatistics` trait provides statistical utilities for data
+/// sets where each entry carries a non-negative weight
+///
+/// # Remarks
+///
+/// Implemented for slices of `f64`. The weights are passed as a slice of the
+/// same length as the data and the methods panic with
+/// `StatsError::ContainersMustBeSameLength` otherwise. Methods return
+/// `f64::NAN` if any weight is negative or `f64::NAN` or the weights sum
+/// to zero
+pub trait WeightedStatistics {
+    /// Evaluates the weighted mean of the data
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(w_i * x_i) / Σw_i
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// assert_eq!(x.weighted_mean(&[1.0, 1.0, 2.0]), 2.75);
+    /// assert!(x.weighted_mean(&[0.0, 0.0, 0.0]).is_nan());
+    /// ```
+    fn weighted_mean(&self, weights: &[f64]) -> f64;
+
+    /// Estimates the unbiased population variance from weighted samples
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// For frequency weights the sum of squared deviations is normalized by
+    /// `V_1 - 1`, and for reliability weights by `V_1 - V_2 / V_1`, where
+    /// `V_1` is the sum of the weights and `V_2` the sum of their squares.
+    /// Both reduce to Bessel's correction for unit weights.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{WeightedStatistics, WeightType};
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// assert_eq!(x.weighted_variance(&[1.0, 1.0, 2.0], WeightType::Frequency), 2.25);
+    /// assert_eq!(x.weighted_variance(&[1.0, 1.0, 2.0], WeightType::Reliability), 2.7);
+    /// ```
+    fn weighted_variance(&self, weights: &[f64], weight_type: WeightType) -> f64;
+
+    /// Evaluates the weighted population variance of the data, the weighted
+    /// mean of the squared deviations from the weighted mean
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// assert_eq!(x.weighted_population_variance(&[1.0, 1.0, 2.0]), 1.6875);
+    /// ```
+    fn weighted_population_variance(&self, weights: &[f64]) -> f64;
+
+    /// Estimates the unbiased standard deviation of the population from
+    /// weighted samples
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// The square root of `weighted_variance`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{WeightedStatistics, WeightType};
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// assert_eq!(x.weighted_std_dev(&[1.0, 1.0, 2.0], WeightType::Frequency), 1.5);
+    /// ```
+    fn weighted_std_dev(&self, weights: &[f64], weight_type: WeightType) -> f64;
+
+    /// Estimates the unbiased covariance between two weighted samples
+    /// that share the same weights
+    ///
+    /// # Panics
+    ///
+    /// If `other.len() != self.len()` or `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// Uses the same normalizers as `weighted_variance`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{WeightedStatistics, WeightType};
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// let y = [1.0, 1.0, 4.0];
+    /// let w = [1.0, 1.0, 2.0];
+    /// assert_eq!(x.weighted_covariance(&y, &w, WeightType::Frequency), 2.5);
+    /// assert_eq!(x.weighted_covariance(&x, &w, WeightType::Frequency),
+    ///            x.weighted_variance(&w, WeightType::Frequency));
+    /// ```
+    fn weighted_covariance(&self, other: &[f64], weights: &[f64], weight_type: WeightType) -> f64;
+
+    /// Estimates the tau-th quantile of weighted data
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// Each entry with a positive weight `w_i` is placed at the plotting
+    /// position `(S_i - w_i / 2) / S_n` of the middle of its step in the
+    /// weighted empirical distribution function, where `S_i` is the
+    /// cumulative weight of the sorted data, and the quantile is linearly
+    /// interpolated between these positions. For equal weights this matches
+    /// `QuantileDefinition::R5`. Entries with zero weight are ignored and
+    /// the data is left untouched.
+    ///
+    /// Returns `f64::NAN` if tau is outside `[0, 1]` or any entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [4.0, 1.0, 2.0, 3.0];
+    /// assert_eq!(x.weighted_quantile(0.5, &[1.0, 1.0, 1.0, 1.0]), 2.5);
+    /// assert_eq!(x.weighted_quantile(0.5, &[5.0, 1.0, 1.0, 1.0]), 3.5);
+    /// assert_eq!(x.weighted_quantile(0.5, &[0.0, 1.0, 1.0, 1.0]), 2.0);
+    /// ```
+    fn weighted_quantile(&self, tau: f64, weights: &[f64]) -> f64;
+
+    /// Estimates the median of weighted data
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len() != self.len()`
+    ///
+    /// # Remarks
+    ///
+    /// Equivalent to `weighted_quantile(0.5, weights)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [4.0, 1.0, 2.0, 3.0];
+    /// assert_eq!(x.weighted_median(&[1.0, 2.0, 2.0, 1.0]), 2.0);
+    /// ```
+    fn weighted_median(&self, weights: &[f64]) -> f64;
+}
+
+/// The `NanStatistics` trait applies a `NanPolicy` to data with missing
+/// values encoded as `f64::NAN`, such as sensor data with gaps
+///
+/// # Remarks
+///
+/// Implemented for slices of `f64`. `with_nan_policy` returns the data to
+/// compute statistics from, so every method of `Statistics` follows the
+/// policy. Ranks are computed by
+/// `ranks_with_nan_policy` instead, which keeps them aligned with the data
+pub trait NanStatistics {
+    /// Returns the data with the given policy applied to its entries that
+    /// are `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If `policy` is `NanPolicy::Error` and an entry is `f64::NAN`
+    ///
+    /// # Remarks
+    ///
+    /// Only copies the data if `policy` is `NanPolicy::Omit` and an entry
+    /// is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::{NanPolicy, NanStatistics, Statistics};
+    ///
+    /// let x = [1.0, f64::NAN, 3.0, 8.0];
+    /// assert!(x.with_nan_policy(NanPolicy::Propagate).unwrap().mean().is_nan());
+    /// assert_eq!(x.with_nan_policy(NanPolicy::Omit).unwrap().mean(), 4.0);
+    /// assert_eq!(x.with_nan_policy(NanPolicy::Omit).unwrap().median_copy(), 3.0);
+    /// assert!(x.with_nan_policy(NanPolicy::Error).is_err());
+    /// ```
+    fn with_nan_policy(&self, policy: NanPolicy) -> Result<Cow<'_, [f64]>>;
+
+    /// Evaluates the rank of each entry of the data with the given policy
+    /// applied to its entries that are `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If `policy` is `NanPolicy::Error` and an entry is `f64::NAN`
+    ///
+    /// # Remarks
+    ///
+    /// With `NanPolicy::Omit` the other entries are ranked among themselves
+    /// and the entries that are `f64::NAN` are ranked `f64::NAN`, so that
+    /// each rank stays at the position of its entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::{NanPolicy, NanStatistics, RankTieBreaker};
+    ///
+    /// let x = [3.0, f64::NAN, 1.0, 3.0];
+    /// let ranks = x.ranks_with_nan_policy(RankTieBreaker::Average, NanPolicy::Omit).unwrap();
+    /// assert_eq!(ranks[0], 2.5);
+    /// assert!(ranks[1].is_nan());
+    /// assert_eq!(ranks[2], 1.0);
+    /// assert_eq!(ranks[3], 2.5);
+    /// ```
+    fn ranks_with_nan_policy(&self,
+                             tie_breaker: RankTieBreaker,
+                             policy: NanPolicy)
+                             -> Result<Vec<f64>>;
 }
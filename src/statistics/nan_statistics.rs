@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use std::f64;
+use error::StatsError;
+use result::Result;
+use super::*;
+
+impl NanStatistics for [f64] {
+    fn with_nan_policy(&self, policy: NanPolicy) -> Result<Cow<'_, [f64]>> {
+        if policy == NanPolicy::Propagate || !self.iter().any(|x| x.is_nan()) {
+            return Ok(Cow::Borrowed(self));
+        }
+        match policy {
+            NanPolicy::Omit => Ok(Cow::Owned(self.iter().cloned().filter(|x| !x.is_nan()).collect())),
+            _ => Err(StatsError::BadParams),
+        }
+    }
+
+    fn ranks_with_nan_policy(&self,
+                             tie_breaker: RankTieBreaker,
+                             policy: NanPolicy)
+                             -> Result<Vec<f64>> {
+        let data = self.with_nan_policy(policy)?;
+        if data.len() == self.len() {
+            return Ok(data.ranks_copy(tie_breaker));
+        }
+        // scatters the ranks of the remaining entries back to their positions
+        let mut ranks = vec![f64::NAN; self.len()];
+        let positions = self.iter().enumerate().filter(|&(_, x)| !x.is_nan()).map(|(i, _)| i);
+        for (i, rank) in positions.zip(data.ranks_copy(tie_breaker)) {
+            ranks[i] = rank;
+        }
+        Ok(ranks)
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    const DATA: [f64; 8] = [-1.0, 5.0, f64::NAN, 0.0, -3.0, f64::NAN, 10.0, 5.0];
+    const CLEAN: [f64; 6] = [-1.0, 5.0, 0.0, -3.0, 10.0, 5.0];
+
+    #[test]
+    fn test_with_nan_policy() {
+        assert_eq!(&*DATA.with_nan_policy(NanPolicy::Omit).unwrap(), &CLEAN[..]);
+        assert_eq!(DATA.with_nan_policy(NanPolicy::Propagate).unwrap().len(), 8);
+        assert!(DATA.with_nan_policy(NanPolicy::Error).is_err());
+        assert_eq!(&*CLEAN.with_nan_policy(NanPolicy::Error).unwrap(), &CLEAN[..]);
+        assert!([f64::NAN].with_nan_policy(NanPolicy::Omit).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_statistics_omit() {
+        let data = DATA.with_nan_policy(NanPolicy::Omit).unwrap();
+        assert_eq!(data.min(), -3.0);
+        assert_eq!(data.max(), 10.0);
+        assert_eq!(data.abs_max(), 10.0);
+        assert_almost_eq!(data.mean(), (&CLEAN).mean(), 1e-15);
+        assert_almost_eq!(data.variance(), (&CLEAN).variance(), 1e-15);
+        assert_almost_eq!(data.skewness(), (&CLEAN).skewness(), 1e-15);
+        assert_eq!(data.median_copy(), 2.5);
+        assert_eq!(data.order_statistic_copy(1), -3.0);
+        assert_eq!(data.quantile_copy(0.5), 2.5);
+        assert_eq!(data.interquartile_range_copy(), CLEAN.interquartile_range_copy());
+        assert_eq!(data.into_owned().median(), 2.5);
+    }
+
+    #[test]
+    fn test_statistics_propagate() {
+        let data = DATA.with_nan_policy(NanPolicy::Propagate).unwrap();
+        assert!(data.min().is_nan());
+        assert!(data.mean().is_nan());
+        assert!(data.variance().is_nan());
+        assert!(data.median_copy().is_nan());
+        assert!(data.quantile_copy(0.9).is_nan());
+        assert!(data.ranks_copy(RankTieBreaker::Average).iter().all(|r| r.is_nan()));
+    }
+
+    #[test]
+    fn test_ranks_with_nan_policy() {
+        let ranks = DATA.ranks_with_nan_policy(RankTieBreaker::Average, NanPolicy::Omit).unwrap();
+        let expected = [2.0, 4.5, f64::NAN, 3.0, 1.0, f64::NAN, 6.0, 4.5];
+        for (&rank, &expected) in ranks.iter().zip(expected.iter()) {
+            assert!(rank == expected || rank.is_nan() && expected.is_nan());
+        }
+        let ranks = DATA.ranks_with_nan_policy(RankTieBreaker::First, NanPolicy::Omit).unwrap();
+        assert_eq!(ranks[1], 4.0);
+        assert_eq!(ranks[7], 5.0);
+        assert_eq!(CLEAN.ranks_with_nan_policy(RankTieBreaker::Min, NanPolicy::Error).unwrap(),
+                   CLEAN.ranks_copy(RankTieBreaker::Min));
+        assert!(DATA.ranks_with_nan_policy(RankTieBreaker::Min, NanPolicy::Error).is_err());
+        assert_eq!(DATA.ranks_with_nan_policy(RankTieBreaker::Max, NanPolicy::Propagate).unwrap().len(), 8);
+    }
+}
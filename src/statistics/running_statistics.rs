@@ -0,0 +1,464 @@
+use std::f64;
+use std::iter::FromIterator;
+
+/// Accumulates statistics of a data stream one value at a time, without
+/// storing the values. The mean and the central moments used for the
+/// variance, skewness and kurtosis are updated in a numerically stable
+/// way following Welford's method, and two accumulators can be merged
+/// so that a data set can be processed in parallel.
+///
+/// # Remarks
+///
+/// Like `Statistics`, every statistic is `f64::NAN` if there are not
+/// enough values to compute it or a `f64::NAN` has been pushed
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::RunningStatistics;
+///
+/// let mut stats = RunningStatistics::new();
+/// stats.push(1.0);
+/// stats.push(2.0);
+/// stats.push(6.0);
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!(stats.mean(), 3.0);
+/// assert_eq!(stats.variance(), 7.0);
+///
+/// let mut other: RunningStatistics = vec![-1.0, 7.0].into_iter().collect();
+/// other.merge(&stats);
+/// assert_eq!(other.min(), -1.0);
+/// assert_eq!(other.mean(), 3.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RunningStatistics {
+    count: u64,
+    min: f64,
+    max: f64,
+    m1: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl RunningStatistics {
+    /// Constructs a new, empty accumulator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::RunningStatistics;
+    ///
+    /// let stats = RunningStatistics::new();
+    /// assert_eq!(stats.count(), 0);
+    /// assert!(stats.mean().is_nan());
+    /// ```
+    pub fn new() -> RunningStatistics {
+        RunningStatistics {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            m1: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+        }
+    }
+
+    /// Updates the accumulated statistics with the value `x`
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let d = x - self.m1;
+        let s = d / n;
+        let s2 = s * s;
+        let t = d * s * (n - 1.0);
+
+        self.m1 += s;
+        self.m4 += t * s2 * (n * n - 3.0 * n + 3.0) + 6.0 * s2 * self.m2 - 4.0 * s * self.m3;
+        self.m3 += t * s * (n - 2.0) - 3.0 * s * self.m2;
+        self.m2 += t;
+
+        if x < self.min || x.is_nan() {
+            self.min = x;
+        }
+        if x > self.max || x.is_nan() {
+            self.max = x;
+        }
+    }
+
+    /// Merges the statistics accumulated by `other` into `self` so that
+    /// the result is as if every value pushed to `other` had been pushed
+    /// to `self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::RunningStatistics;
+    ///
+    /// let mut a: RunningStatistics = vec![1.0, 2.0].into_iter().collect();
+    /// let b: RunningStatistics = vec![3.0, 4.0, 5.0].into_iter().collect();
+    /// a.merge(&b);
+    /// assert_eq!(a.count(), 5);
+    /// assert_eq!(a.mean(), 3.0);
+    /// assert_eq!(a.max(), 5.0);
+    /// ```
+    pub fn merge(&mut self, other: &RunningStatistics) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let na = self.count as f64;
+        let nb = other.count as f64;
+        let n = na + nb;
+        let d = other.m1 - self.m1;
+        let d2 = d * d;
+        let d3 = d2 * d;
+        let d4 = d2 * d2;
+
+        let m1 = (na * self.m1 + nb * other.m1) / n;
+        let m2 = self.m2 + other.m2 + d2 * na * nb / n;
+        let m3 = self.m3 + other.m3 + d3 * na * nb * (na - nb) / (n * n) +
+                 3.0 * d * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4 + other.m4 +
+                 d4 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n) +
+                 6.0 * d2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n) +
+                 4.0 * d * (na * other.m3 - nb * self.m3) / n;
+
+        self.count += other.count;
+        self.m1 = m1;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+        if other.min < self.min || other.min.is_nan() {
+            self.min = other.min;
+        }
+        if other.max > self.max || other.max.is_nan() {
+            self.max = other.max;
+        }
+    }
+
+    /// Returns the number of values pushed
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the minimum value pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been pushed or a `f64::NAN`
+    /// has been pushed
+    pub fn min(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.min }
+    }
+
+    /// Returns the maximum value pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been pushed or a `f64::NAN`
+    /// has been pushed
+    pub fn max(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.max }
+    }
+
+    /// Returns the sample mean of the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been pushed
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.m1 }
+    }
+
+    /// Returns the unbiased estimate of the population variance from
+    /// the values pushed, using `N - 1` as a normalizer
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values have been pushed
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            f64::NAN
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    /// Returns the population variance of the values pushed, using `N`
+    /// as a normalizer
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been pushed
+    pub fn population_variance(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Returns the sample standard deviation of the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values have been pushed
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the population standard deviation of the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been pushed
+    pub fn population_std_dev(&self) -> f64 {
+        self.population_variance().sqrt()
+    }
+
+    /// Returns the adjusted Fisher-Pearson estimate of the population
+    /// skewness from the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than three values have been pushed
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(N * (N - 1)) / (N - 2) * g_1
+    /// ```
+    ///
+    /// where `g_1` is the population skewness
+    pub fn skewness(&self) -> f64 {
+        if self.count < 3 {
+            return f64::NAN;
+        }
+        let n = self.count as f64;
+        (n * (n - 1.0)).sqrt() / (n - 2.0) * self.population_skewness()
+    }
+
+    /// Returns the population skewness of the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values have been pushed
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// g_1 = sqrt(N) * Σ(x_i - x̄)^3 / (Σ(x_i - x̄)^2)^(3 / 2)
+    /// ```
+    pub fn population_skewness(&self) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        (self.count as f64).sqrt() * self.m3 / self.m2.powf(1.5)
+    }
+
+    /// Returns the unbiased estimate of the population excess kurtosis
+    /// from the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than four values have been pushed
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (N^2 - 1) / ((N - 2) * (N - 3)) * (g_2 + 6 / (N + 1))
+    /// ```
+    ///
+    /// where `g_2` is the population excess kurtosis
+    pub fn kurtosis(&self) -> f64 {
+        if self.count < 4 {
+            return f64::NAN;
+        }
+        let n = self.count as f64;
+        (n * n - 1.0) / ((n - 2.0) * (n - 3.0)) * (self.population_kurtosis() + 6.0 / (n + 1.0))
+    }
+
+    /// Returns the population excess kurtosis of the values pushed
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values have been pushed
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// g_2 = N * Σ(x_i - x̄)^4 / (Σ(x_i - x̄)^2)^2 - 3
+    /// ```
+    pub fn population_kurtosis(&self) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        self.count as f64 * self.m4 / (self.m2 * self.m2) - 3.0
+    }
+}
+
+impl Default for RunningStatistics {
+    fn default() -> RunningStatistics {
+        RunningStatistics::new()
+    }
+}
+
+impl Extend<f64> for RunningStatistics {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<'a> Extend<&'a f64> for RunningStatistics {
+    fn extend<I: IntoIterator<Item = &'a f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(*x);
+        }
+    }
+}
+
+impl FromIterator<f64> for RunningStatistics {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> RunningStatistics {
+        let mut stats = RunningStatistics::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl<'a> FromIterator<&'a f64> for RunningStatistics {
+    fn from_iter<I: IntoIterator<Item = &'a f64>>(iter: I) -> RunningStatistics {
+        let mut stats = RunningStatistics::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use testing;
+
+    const DATA: [f64; 10] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+
+    #[test]
+    fn test_empty() {
+        let stats = RunningStatistics::new();
+        assert_eq!(stats.count(), 0);
+        assert!(stats.min().is_nan());
+        assert!(stats.max().is_nan());
+        assert!(stats.mean().is_nan());
+        assert!(stats.variance().is_nan());
+        assert!(stats.population_variance().is_nan());
+        assert!(stats.skewness().is_nan());
+        assert!(stats.kurtosis().is_nan());
+    }
+
+    #[test]
+    fn test_short() {
+        let stats: RunningStatistics = DATA.iter().collect();
+        assert_eq!(stats.count(), 10);
+        assert_eq!(stats.min(), -3.0);
+        assert_eq!(stats.max(), 10.0);
+        assert_almost_eq!(stats.mean(), 2.17, 1e-15);
+        assert_almost_eq!(stats.variance(), 15.689, 1e-13);
+        assert_almost_eq!(stats.population_variance(), 14.1201, 1e-13);
+        assert_almost_eq!(stats.skewness(), 0.79673048564745452128, 1e-14);
+        assert_almost_eq!(stats.population_skewness(), 0.67186213758347973746, 1e-14);
+        assert_almost_eq!(stats.kurtosis(), 0.053960011211298349499, 1e-14);
+        assert_almost_eq!(stats.population_kurtosis(), -0.51493171082997264969, 1e-14);
+    }
+
+    #[test]
+    fn test_too_few_values() {
+        let stats: RunningStatistics = [1.0, 2.0].iter().collect();
+        assert_eq!(stats.variance(), 0.5);
+        assert!(stats.skewness().is_nan());
+        assert_eq!(stats.population_skewness(), 0.0);
+        assert!(stats.kurtosis().is_nan());
+
+        let stats: RunningStatistics = [1.0].iter().collect();
+        assert_eq!(stats.mean(), 1.0);
+        assert!(stats.variance().is_nan());
+        assert_eq!(stats.population_variance(), 0.0);
+    }
+
+    #[test]
+    fn test_nan() {
+        let stats: RunningStatistics = [1.0, f64::NAN, 3.0].iter().collect();
+        assert!(stats.min().is_nan());
+        assert!(stats.max().is_nan());
+        assert!(stats.mean().is_nan());
+        assert!(stats.variance().is_nan());
+    }
+
+    #[test]
+    fn test_consistent_with_statistics() {
+        for file in &["nist/lottery.txt", "nist/lew.txt", "nist/mavro.txt", "nist/michaelso.txt",
+                      "nist/numacc1.txt", "nist/numacc2.txt", "nist/numacc3.txt", "nist/numacc4.txt"] {
+            let data = &testing::load_data(file);
+            let stats: RunningStatistics = data.iter().collect();
+            assert_eq!(stats.count(), data.len() as u64);
+            assert_eq!(stats.min(), data.min());
+            assert_eq!(stats.max(), data.max());
+            assert_almost_eq!(stats.mean(), data.mean(), 1e-15 * data.mean().abs());
+            assert_almost_eq!(stats.std_dev(), data.std_dev(), 1e-9 * data.std_dev());
+        }
+    }
+
+    #[test]
+    fn test_nist() {
+        let stats: RunningStatistics = testing::load_data("nist/lottery.txt").iter().collect();
+        assert_almost_eq!(stats.mean(), 518.958715596330, 1e-12);
+        assert_almost_eq!(stats.std_dev(), 291.699727470969, 1e-13);
+
+        let stats: RunningStatistics = testing::load_data("nist/mavro.txt").iter().collect();
+        assert_almost_eq!(stats.mean(), 2.00185600000000, 1e-15);
+        assert_almost_eq!(stats.std_dev(), 0.000429123454003053, 1e-15);
+
+        let stats: RunningStatistics = testing::load_data("nist/numacc4.txt").iter().collect();
+        assert_almost_eq!(stats.mean(), 10000000.2, 1e-8);
+        assert_almost_eq!(stats.std_dev(), 0.1, 1e-9);
+    }
+
+    #[test]
+    fn test_merge() {
+        let all: RunningStatistics = DATA.iter().collect();
+        for split in 0..DATA.len() + 1 {
+            let mut a: RunningStatistics = DATA[..split].iter().collect();
+            let b: RunningStatistics = DATA[split..].iter().collect();
+            a.merge(&b);
+            assert_eq!(a.count(), all.count());
+            assert_eq!(a.min(), all.min());
+            assert_eq!(a.max(), all.max());
+            assert_almost_eq!(a.mean(), all.mean(), 1e-14);
+            assert_almost_eq!(a.variance(), all.variance(), 1e-12);
+            assert_almost_eq!(a.skewness(), all.skewness(), 1e-13);
+            assert_almost_eq!(a.kurtosis(), all.kurtosis(), 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_merge_nist() {
+        let data = testing::load_data("nist/lottery.txt");
+        let all: RunningStatistics = data.iter().collect();
+        let mut merged = RunningStatistics::new();
+        for chunk in data.chunks(37) {
+            merged.merge(&chunk.iter().collect());
+        }
+        assert_eq!(merged.count(), all.count());
+        assert_almost_eq!(merged.mean(), all.mean(), 1e-11);
+        assert_almost_eq!(merged.std_dev(), all.std_dev(), 1e-11);
+        assert_almost_eq!(merged.skewness(), all.skewness(), 1e-13);
+        assert_almost_eq!(merged.kurtosis(), all.kurtosis(), 1e-13);
+    }
+}
@@ -1,115 +1,45 @@
+use std::borrow::Borrow;
 use std::f64;
 use error::StatsError;
 use super::*;
 
-impl Statistics for [f64] {
+impl<T> Statistics for [T]
+    where T: Copy + PartialOrd + Into<f64>
+{
     fn min(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        self.iter().fold(f64::INFINITY,
-                         |acc, &x| if x < acc || x.is_nan() { x } else { acc })
+        values(self).min_value()
     }
 
     fn max(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        self.iter().fold(f64::NEG_INFINITY,
-                         |acc, &x| if x > acc || x.is_nan() { x } else { acc })
+        values(self).max_value()
     }
 
     fn abs_min(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        self.iter()
-            .map(|x| x.abs())
-            .fold(f64::INFINITY,
-                  |acc, x| if x < acc || x.is_nan() { x } else { acc })
+        values(self).abs_min()
     }
 
     fn abs_max(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        self.iter()
-            .map(|x| x.abs())
-            .fold(f64::NEG_INFINITY,
-                  |acc, x| if x > acc || x.is_nan() { x } else { acc })
+        values(self).abs_max()
     }
 
     fn mean(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        let mut m = 0.0;
-        self.iter()
-            .fold(0.0, |acc, &x| {
-                m += 1.0;
-                acc + (x - acc) / m
-            })
+        values(self).mean()
     }
 
     fn geometric_mean(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        (self.iter().fold(0.0, |acc, &x| if x < 0.0 { f64::NAN } else { acc + x.ln() }) /
-         self.len() as f64)
-            .exp()
+        values(self).geometric_mean()
     }
 
     fn harmonic_mean(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        self.len() as f64 /
-        self.iter().fold(0.0,
-                         |acc, &x| if x < 0.0 { f64::NAN } else { acc + 1.0 / x })
+        values(self).harmonic_mean()
     }
 
     fn variance(&self) -> f64 {
-        if self.len() <= 1 {
-            return f64::NAN;
-        }
-
-        unsafe {
-            let mut var = 0.0;
-            let mut t = *self.get_unchecked(0);
-            for i in 1..self.len() {
-                let x = *self.get_unchecked(i);
-                t += x;
-                let diff = (i as f64 + 1.0) * x - t;
-                var += (diff * diff) / ((i + 1) * i) as f64;
-            }
-            var / (self.len() - 1) as f64
-        }
+        values(self).variance()
     }
 
     fn population_variance(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
-
-        unsafe {
-            let mut var = 0.0;
-            let mut t = *self.get_unchecked(0);
-            for i in 1..self.len() {
-                let x = *self.get_unchecked(i);
-                t += x;
-                let diff = (i as f64 + 1.0) * x - t;
-                var += (diff * diff) / ((i + 1) * i) as f64
-            }
-            var / self.len() as f64
-        }
+        values(self).population_variance()
     }
 
     fn std_dev(&self) -> f64 {
@@ -120,50 +50,46 @@ impl Statistics for [f64] {
         self.population_variance().sqrt()
     }
 
-    fn covariance(&self, other: &[f64]) -> f64 {
-        let n1 = self.len();
-        let n2 = other.len();
-        assert!(n1 == n2,
-                format!("{}", StatsError::ContainersMustBeSameLength));
-        if n1 <= 1 {
-            return f64::NAN;
-        }
+    fn covariance<I>(&self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>
+    {
+        values(self).covariance(other)
+    }
 
-        let mean1 = self.mean();
-        let mean2 = other.mean();
-        self.iter()
-            .zip(other.iter())
-            .fold(0.0, |acc, x| acc + (x.0 - mean1) * (x.1 - mean2)) / (n1 - 1) as f64
+    fn population_covariance<I>(&self, other: I) -> f64
+        where I: IntoIterator,
+              I::Item: Borrow<f64>
+    {
+        values(self).population_covariance(other)
     }
 
-    fn population_covariance(&self, other: &[f64]) -> f64 {
-        let n1 = self.len();
-        let n2 = other.len();
-        assert!(n1 == n2,
-                format!("{}", StatsError::ContainersMustBeSameLength));
-        if n1 == 0 {
-            return f64::NAN;
-        }
+    fn quadratic_mean(&self) -> f64 {
+        values(self).quadratic_mean()
+    }
 
-        let mean1 = self.mean();
-        let mean2 = other.mean();
-        self.iter()
-            .zip(other.iter())
-            .fold(0.0, |acc, x| acc + (x.0 - mean1) * (x.1 - mean2)) / n1 as f64
+    fn skewness(&self) -> f64 {
+        values(self).skewness()
     }
 
-    fn quadratic_mean(&self) -> f64 {
-        if self.len() == 0 {
-            return f64::NAN;
-        }
+    fn population_skewness(&self) -> f64 {
+        values(self).population_skewness()
+    }
+
+    fn kurtosis(&self) -> f64 {
+        values(self).kurtosis()
+    }
 
-        let mut m = 0.0;
-        self.iter()
-            .fold(0.0, |acc, &x| {
-                m += 1.0;
-                acc + (x * x - acc) / m
-            })
-            .sqrt()
+    fn population_kurtosis(&self) -> f64 {
+        values(self).population_kurtosis()
+    }
+
+    fn central_moment(&self, order: i32) -> f64 {
+        values(self).central_moment(order)
+    }
+
+    fn raw_moment(&self, order: i32) -> f64 {
+        values(self).raw_moment(order)
     }
 
     /// Returns the order statistic `(order 1..N)` from the data
@@ -180,7 +106,7 @@ impl Statistics for [f64] {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.order_statistic(1).is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
@@ -212,7 +138,7 @@ impl Statistics for [f64] {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.median().is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
@@ -243,7 +169,7 @@ impl Statistics for [f64] {
     /// ```
     /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.quantile(0.5).is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
@@ -253,23 +179,34 @@ impl Statistics for [f64] {
     /// assert!(y != [0.0, 3.0, -2.0]);
     /// ```
     fn quantile(&mut self, tau: f64) -> f64 {
-        if tau < 0.0 || tau > 1.0 || self.len() == 0 {
-            return f64::NAN;
-        }
-
-        let h = (self.len() as f64 + 1.0 / 3.0) * tau + 1.0 / 3.0;
-        let hf = h as i64;
-
-        if hf <= 0 || tau == 0.0 {
-            return self.min();
-        }
-        if hf >= self.len() as i64 || tau == 1.0 {
-            return self.max();
-        }
+        self.quantile_custom(tau, QuantileDefinition::R8)
+    }
 
-        let a = select_inplace(self, (hf as usize).saturating_sub(1));
-        let b = select_inplace(self, hf as usize);
-        a + (h - hf as f64) * (b - a)
+    /// Estimates the tau-th quantile from the data using the given
+    /// quantile definition
+    ///
+    /// # Remarks
+    ///
+    /// No sorting is assumed. Tau must be between `0` and `1` inclusive.
+    /// Returns `f64::NAN` if data is empty or tau is outside the inclusive range.
+    ///
+    /// **NOTE:** This method works inplace for arrays and may cause the array to be reordered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Statistics, QuantileDefinition};
+    ///
+    /// let mut x: [f64; 0] = [];
+    /// assert!(x.quantile_custom(0.5, QuantileDefinition::R7).is_nan());
+    ///
+    /// let mut y = [2.0, 1.0, 3.0, 4.0];
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R7), 1.75);
+    /// assert!(y.quantile_custom(1.5, QuantileDefinition::R7).is_nan());
+    /// ```
+    fn quantile_custom(&mut self, tau: f64, definition: QuantileDefinition) -> f64 {
+        let n = self.len();
+        estimate_quantile(n, tau, definition, |k| select_inplace(self, k))
     }
 
     /// Estimates the p-Percentile value from the data.
@@ -284,9 +221,9 @@ impl Statistics for [f64] {
     /// # Examples
     ///
     /// ```
-    /// use statrs::Statistics;
+    /// use statrs::statistics::Statistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.percentile(0).is_nan());
     ///
     /// let mut y = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
@@ -317,7 +254,7 @@ impl Statistics for [f64] {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.lower_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -346,7 +283,7 @@ impl Statistics for [f64] {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.lower_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -375,7 +312,7 @@ impl Statistics for [f64] {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.interquartile_range().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -398,7 +335,7 @@ impl Statistics for [f64] {
     /// ```
     /// use statrs::statistics::{Statistics, RankTieBreaker};
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert_eq!(x.ranks(RankTieBreaker::Average).len(), 0);
     ///
     /// let y = [1.0, 3.0, 2.0, 2.0];
@@ -407,47 +344,150 @@ impl Statistics for [f64] {
     /// ```
     fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<f64> {
         let n = self.len();
-        let mut ranks: Vec<f64> = vec![0.0; n];
         let mut index: Vec<usize> = (0..n).collect();
-
         match tie_breaker {
-            RankTieBreaker::First => {
-                quick_sort_all(self, &mut *index, 0, n - 1);
-                unsafe {
-                    for i in 0..ranks.len() {
-                        ranks[*index.get_unchecked(i)] = (i + 1) as f64;
-                    }
+            RankTieBreaker::First if n > 1 => quick_sort_all(self, &mut index, 0, n - 1),
+            _ => sort(self, &mut index),
+        }
+        sorted_ranks(self, &index, tie_breaker)
+    }
+
+    fn order_statistic_copy(&self, order: usize) -> f64 {
+        self.to_vec().order_statistic(order)
+    }
+
+    fn median_copy(&self) -> f64 {
+        self.to_vec().median()
+    }
+
+    fn quantile_copy(&self, tau: f64) -> f64 {
+        self.to_vec().quantile(tau)
+    }
+
+    fn quantile_custom_copy(&self, tau: f64, definition: QuantileDefinition) -> f64 {
+        self.to_vec().quantile_custom(tau, definition)
+    }
+
+    fn percentile_copy(&self, p: usize) -> f64 {
+        self.to_vec().percentile(p)
+    }
+
+    fn lower_quartile_copy(&self) -> f64 {
+        self.to_vec().lower_quartile()
+    }
+
+    fn upper_quartile_copy(&self) -> f64 {
+        self.to_vec().upper_quartile()
+    }
+
+    fn interquartile_range_copy(&self) -> f64 {
+        self.to_vec().interquartile_range()
+    }
+
+    fn ranks_copy(&self, tie_breaker: RankTieBreaker) -> Vec<f64> {
+        self.to_vec().ranks(tie_breaker)
+    }
+}
+
+// returns an iterator over the entries of the data converted to `f64`
+fn values<'a, T>(data: &'a [T]) -> impl Iterator<Item = f64> + Clone + 'a
+    where T: Copy + Into<f64>
+{
+    data.iter().map(|&x| x.into())
+}
+
+// Estimates the tau-th quantile of `n` data points using the given quantile
+// definition, where `order_statistic` returns the zero-based order statistic
+pub fn estimate_quantile<F>(n: usize,
+                            tau: f64,
+                            definition: QuantileDefinition,
+                            mut order_statistic: F)
+                            -> f64
+    where F: FnMut(usize) -> f64
+{
+    if tau < 0.0 || tau > 1.0 || tau.is_nan() || n == 0 {
+        return f64::NAN;
+    }
+
+    let n = n as f64;
+    // returns the order statistic at the one-based position k, clamped to the data
+    let mut x = |k: f64| order_statistic(k.max(1.0).min(n) as usize - 1);
+    let h = match definition {
+        QuantileDefinition::R1 => return x((n * tau).ceil()),
+        QuantileDefinition::R2 => {
+            let h = n * tau + 0.5;
+            return (x((h - 0.5).ceil()) + x((h + 0.5).floor())) / 2.0;
+        }
+        QuantileDefinition::R3 => {
+            let h = n * tau;
+            let k = h.round();
+            // rounds half way cases to even
+            return if (k - h).abs() == 0.5 && k % 2.0 != 0.0 { x(k - 1.0) } else { x(k) };
+        }
+        QuantileDefinition::R4 => n * tau,
+        QuantileDefinition::R5 => n * tau + 0.5,
+        QuantileDefinition::R6 => (n + 1.0) * tau,
+        QuantileDefinition::R7 => (n - 1.0) * tau + 1.0,
+        QuantileDefinition::R8 => (n + 1.0 / 3.0) * tau + 1.0 / 3.0,
+        QuantileDefinition::R9 => (n + 0.25) * tau + 0.375,
+    };
+
+    let hf = h.floor();
+    let a = x(hf);
+    let b = x(hf + 1.0);
+    // avoids interpolating between equal or infinite order statistics
+    if h == hf || a == b { a } else { a + (h - hf) * (b - a) }
+}
+
+// Evaluates the ranks of the entries of the original data given the sorted
+// data and the original index of each sorted entry. `RankTieBreaker::First`
+// assumes ties are ordered by their original index
+pub fn sorted_ranks<T>(sorted: &[T], index: &[usize], tie_breaker: RankTieBreaker) -> Vec<f64>
+    where T: Copy + Into<f64>
+{
+    let n = sorted.len();
+    if values(sorted).any(|x| x.is_nan()) {
+        return vec![f64::NAN; n];
+    }
+    let mut ranks: Vec<f64> = vec![0.0; n];
+
+    match tie_breaker {
+        RankTieBreaker::First => {
+            unsafe {
+                for i in 0..ranks.len() {
+                    ranks[*index.get_unchecked(i)] = (i + 1) as f64;
                 }
-                ranks
             }
-            _ => {
-                sort(self, &mut *index);
-                let mut prev_idx = 0;
-                unsafe {
-                    for i in 1..n {
-                        if (*self.get_unchecked(i) - *self.get_unchecked(prev_idx)).abs() <= 0.0 {
-                            continue;
-                        }
-                        if i == prev_idx + 1 {
-                            ranks[*index.get_unchecked(prev_idx)] = i as f64;
-                        } else {
-                            handle_rank_ties(&mut *ranks,
-                                             &*index,
-                                             prev_idx as isize,
-                                             i as isize,
-                                             tie_breaker);
-                        }
-                        prev_idx = i;
+            ranks
+        }
+        _ => {
+            let mut prev_idx = 0;
+            unsafe {
+                for i in 1..n {
+                    let x: f64 = (*sorted.get_unchecked(i)).into();
+                    let prev: f64 = (*sorted.get_unchecked(prev_idx)).into();
+                    if (x - prev).abs() <= 0.0 {
+                        continue;
+                    }
+                    if i == prev_idx + 1 {
+                        ranks[*index.get_unchecked(prev_idx)] = i as f64;
+                    } else {
+                        handle_rank_ties(&mut ranks,
+                                         index,
+                                         prev_idx as isize,
+                                         i as isize,
+                                         tie_breaker);
                     }
+                    prev_idx = i;
                 }
-
-                handle_rank_ties(&mut *ranks,
-                                 &*index,
-                                 prev_idx as isize,
-                                 n as isize,
-                                 tie_breaker);
-                ranks
             }
+
+            handle_rank_ties(&mut ranks,
+                             index,
+                             prev_idx as isize,
+                             n as isize,
+                             tie_breaker);
+            ranks
         }
     }
 }
@@ -473,7 +513,13 @@ fn handle_rank_ties(ranks: &mut [f64],
 
 // Selection algorithm from Numerical Recipes
 // See: https://en.wikipedia.org/wiki/Selection_algorithm
-fn select_inplace(arr: &mut [f64], rank: usize) -> f64 {
+fn select_inplace<T>(arr: &mut [T], rank: usize) -> f64
+    where T: Copy + PartialOrd + Into<f64>
+{
+    // the partitioning below relies on the data being totally ordered
+    if values(arr).any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
     if rank == 0 {
         return arr.min();
     }
@@ -489,7 +535,7 @@ fn select_inplace(arr: &mut [f64], rank: usize) -> f64 {
                 if high == low + 1 && *arr.get_unchecked(high) < *arr.get_unchecked(low) {
                     arr.swap(low, high)
                 }
-                return *arr.get_unchecked(rank);
+                return (*arr.get_unchecked(rank)).into();
             }
 
             let middle = (low + high) >> 1;
@@ -542,7 +588,9 @@ fn select_inplace(arr: &mut [f64], rank: usize) -> f64 {
 
 // sorts a primary slice and re-orders the secondary slice automatically. Uses insertion sort on small
 // containers and quick sorts for larger ones
-fn sort(primary: &mut [f64], secondary: &mut [usize]) {
+fn sort<T>(primary: &mut [T], secondary: &mut [usize])
+    where T: Copy + PartialOrd
+{
     assert!(primary.len() == secondary.len(),
             format!("{}", StatsError::ContainersMustBeSameLength));
 
@@ -572,8 +620,8 @@ fn sort(primary: &mut [f64], secondary: &mut [usize]) {
                     secondary[j as usize + 1] = *secondary.get_unchecked(j as usize);
                     j -= 1;
                 }
-                primary[j as usize + 1] = key;
-                secondary[j as usize + 1] = item;
+                primary[(j + 1) as usize] = key;
+                secondary[(j + 1) as usize] = item;
             }
             return;
         }
@@ -583,7 +631,9 @@ fn sort(primary: &mut [f64], secondary: &mut [usize]) {
 }
 
 // quick sorts a primary slice and re-orders the secondary slice automatically
-fn quick_sort(primary: &mut [f64], secondary: &mut [usize], left: usize, right: usize) {
+fn quick_sort<T>(primary: &mut [T], secondary: &mut [usize], left: usize, right: usize)
+    where T: Copy + PartialOrd
+{
     assert!(primary.len() == secondary.len(),
             format!("{}", StatsError::ContainersMustBeSameLength));
 
@@ -630,7 +680,8 @@ fn quick_sort(primary: &mut [f64], secondary: &mut [usize], left: usize, right:
                 }
 
                 a += 1;
-                b -= 1;
+                // b can only reach zero once a has passed it
+                b = b.saturating_sub(1);
 
                 if a > b {
                     break;
@@ -663,7 +714,9 @@ fn quick_sort(primary: &mut [f64], secondary: &mut [usize], left: usize, right:
 
 // quick sorts a primary slice and re-orders the secondary slice automatically.
 // Sorts secondarily by the secondary slice on primary key duplicates
-fn quick_sort_all(primary: &mut [f64], secondary: &mut [usize], left: usize, right: usize) {
+pub fn quick_sort_all<T>(primary: &mut [T], secondary: &mut [usize], left: usize, right: usize)
+    where T: Copy + PartialOrd
+{
     assert!(primary.len() == secondary.len(),
             format!("{}", StatsError::ContainersMustBeSameLength));
 
@@ -722,7 +775,8 @@ fn quick_sort_all(primary: &mut [f64], secondary: &mut [usize], left: usize, rig
                 }
 
                 a += 1;
-                b -= 1;
+                // b can only reach zero once a has passed it
+                b = b.saturating_sub(1);
 
                 if a > b {
                     break;
@@ -756,6 +810,7 @@ fn quick_sort_all(primary: &mut [f64], secondary: &mut [usize], left: usize, rig
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
+    use std::f32;
     use std::f64::{self, consts};
     use generate;
     use statistics::*;
@@ -893,6 +948,59 @@ mod test {
         assert_eq!(2.0, odd.median());
     }
 
+    #[test]
+    fn test_copy_variants_leave_data_untouched() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        assert_eq!(data.median_copy(), 0.6);
+        assert_eq!(data.order_statistic_copy(3), -0.5);
+        assert_almost_eq!(data.quantile_copy(0.2), -4.0 / 5.0, 1e-15);
+        assert_eq!(data.ranks_copy(RankTieBreaker::Average), [2.0, 8.0, 4.0, 1.0, 10.0, 3.0, 7.0, 5.0, 6.0, 9.0]);
+        assert_eq!(data, [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0]);
+    }
+
+    // expected values from R's quantile(x, tau, type = n)
+    #[test]
+    fn test_quantile_custom_short() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        let taus = [0.0, 0.1, 0.25, 0.325, 0.5, 0.52, 0.7, 0.99, 1.0];
+        let expected = [
+            (QuantileDefinition::R1, [-3.0, -3.0, -0.5, 0.0, 0.2, 1.0, 4.0, 10.0, 10.0]),
+            (QuantileDefinition::R2, [-3.0, -2.0, -0.5, 0.0, 0.6, 1.0, 4.5, 10.0, 10.0]),
+            (QuantileDefinition::R3, [-3.0, -3.0, -1.0, -0.5, 0.2, 0.2, 4.0, 10.0, 10.0]),
+            (QuantileDefinition::R4, [-3.0, -3.0, -0.75, -0.375, 0.2, 0.36000000000000015, 4.0, 9.600000000000001, 10.0]),
+            (QuantileDefinition::R5, [-3.0, -2.0, -0.5, -0.125, 0.6, 0.7600000000000001, 4.5, 10.0, 10.0]),
+            (QuantileDefinition::R6, [-3.0, -2.8, -0.625, -0.2124999999999999, 0.6, 0.7760000000000005, 4.699999999999999, 10.0, 10.0]),
+            (QuantileDefinition::R7, [-3.0, -1.2000000000000002, -0.375, -0.03749999999999987, 0.6, 0.7439999999999998, 4.3, 9.64, 10.0]),
+            (QuantileDefinition::R8, [-3.0, -2.266666666666667, -0.5416666666666667, -0.15416666666666679, 0.5999999999999993, 0.7653333333333329, 4.566666666666665, 10.0, 10.0]),
+            (QuantileDefinition::R9, [-3.0, -2.1999999999999997, -0.53125, -0.14687499999999987, 0.6, 0.764, 4.55, 10.0, 10.0]),
+        ];
+        for &(definition, values) in &expected {
+            for (&tau, &value) in taus.iter().zip(values.iter()) {
+                assert_almost_eq!(data.clone().quantile_custom(tau, definition), value, 1e-14);
+            }
+        }
+        let mut short = [2.0, 1.0, 3.0, 4.0];
+        assert_eq!(short.quantile_custom(0.25, QuantileDefinition::R5), 1.5);
+        assert_almost_eq!(short.quantile_custom(0.25, QuantileDefinition::R9), 1.4375, 1e-15);
+        assert_eq!(short.quantile_custom(0.5, QuantileDefinition::R3), 2.0);
+        assert_eq!(short.quantile_custom(0.75, QuantileDefinition::R3), 3.0);
+        assert!(short.quantile_custom(-0.1, QuantileDefinition::R1).is_nan());
+        assert!(short.quantile_custom(f64::NAN, QuantileDefinition::R7).is_nan());
+    }
+
+    #[test]
+    fn test_nan() {
+        let data = [5.0, 1.0, f64::NAN, 4.0, 2.0, 3.0, 8.0, 0.5, 7.0, 6.0, 9.0, -1.0];
+        for order in 1..data.len() + 1 {
+            assert!(data.order_statistic_copy(order).is_nan());
+        }
+        assert!(data.median_copy().is_nan());
+        assert!(data.quantile_copy(0.9).is_nan());
+        assert!(data.interquartile_range_copy().is_nan());
+        assert!(data.ranks_copy(RankTieBreaker::Average).iter().all(|r| r.is_nan()));
+        assert!(data.ranks_copy(RankTieBreaker::First).iter().all(|r| r.is_nan()));
+    }
+
     #[test]
     fn test_mean_variance_stability() {
         // TODO: Implement tests. Depends on Mersenne Twister RNG implementation.
@@ -988,4 +1096,105 @@ mod test {
         let data = generate::sinusoidal(128, 64.0, 16.0, 2.0);
         assert_almost_eq!(data.quadratic_mean(), 2.0 / consts::SQRT_2, 1e-15);
     }
+
+    #[test]
+    fn test_higher_moments_short() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        assert_almost_eq!(data.population_skewness(), 0.67186213758347973713, 1e-15);
+        assert_almost_eq!(data.skewness(), 0.79673048564745452089, 1e-15);
+        assert_almost_eq!(data.population_kurtosis(), -0.51493171082997264912, 1e-15);
+        assert_almost_eq!(data.kurtosis(), 0.053960011211298352453, 1e-14);
+        assert_almost_eq!(data.central_moment(2), data.population_variance(), 1e-14);
+        assert_almost_eq!(data.central_moment(3), 35.648136, 1e-13);
+        assert_almost_eq!(data.central_moment(4), 495.46601697, 1e-12);
+        assert_almost_eq!(data.raw_moment(2), 18.829, 1e-14);
+        assert_eq!(data.raw_moment(0), 1.0);
+    }
+
+    #[test]
+    fn test_higher_moments_too_few_values() {
+        let data = [1.0, 2.0, 3.0];
+        assert!(data[..1].population_skewness().is_nan());
+        assert!(data[..1].population_kurtosis().is_nan());
+        assert!(data[..2].skewness().is_nan());
+        assert_eq!(data.skewness(), 0.0);
+        assert!(data.kurtosis().is_nan());
+        assert_eq!(data.population_kurtosis(), -1.5);
+        assert!((&[1.0, f64::NAN, 3.0, 4.0]).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn test_higher_moments_consistent_with_running_statistics() {
+        for file in &["nist/lottery.txt", "nist/lew.txt", "nist/mavro.txt", "nist/michaelso.txt"] {
+            let data = &testing::load_data(file);
+            let stats: RunningStatistics = data.iter().collect();
+            assert_almost_eq!(data.skewness(), stats.skewness(), 1e-11);
+            assert_almost_eq!(data.kurtosis(), stats.kurtosis(), 1e-11);
+        }
+    }
+
+    // reference values evaluated with 50 digits of precision from the data
+    // as parsed into f64
+    #[test]
+    fn test_higher_moments_nist() {
+        let data = &testing::load_data("nist/lottery.txt");
+        assert_almost_eq!(data.population_skewness(), -0.092688231450355492523, 1e-15);
+        assert_almost_eq!(data.skewness(), -0.093331653107793549831, 1e-15);
+        assert_almost_eq!(data.population_kurtosis(), -1.1927809417579536415, 1e-14);
+        assert_almost_eq!(data.kurtosis(), -1.1925609107485622503, 1e-14);
+
+        let data = &testing::load_data("nist/lew.txt");
+        assert_almost_eq!(data.skewness(), -0.050606638756334015695, 1e-15);
+        assert_almost_eq!(data.kurtosis(), -1.4960497921444712186, 1e-14);
+
+        let data = &testing::load_data("nist/mavro.txt");
+        assert_almost_eq!(data.skewness(), 0.64492948110915655253, 1e-14);
+        assert_almost_eq!(data.kurtosis(), -0.82052379677318275857, 1e-14);
+
+        let data = &testing::load_data("nist/michaelso.txt");
+        assert_almost_eq!(data.skewness(), -0.018538863775196161468, 1e-14);
+        assert_almost_eq!(data.kurtosis(), 0.33968459842020478293, 1e-14);
+
+        let data = &testing::load_data("nist/numacc1.txt");
+        assert_almost_eq!(data.skewness(), 0.0, 1e-15);
+        assert_almost_eq!(data.population_kurtosis(), -1.5, 1e-15);
+
+        for file in &["nist/numacc2.txt", "nist/numacc3.txt", "nist/numacc4.txt"] {
+            let data = &testing::load_data(file);
+            assert_almost_eq!(data.skewness(), 0.0, 1e-10);
+            assert_almost_eq!(data.population_kurtosis(), -1.999, 1e-12);
+            assert_almost_eq!(data.kurtosis(), -2.003003003003003003, 1e-12);
+            assert_almost_eq!(data.central_moment(2), 0.00999000999000999001, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_numeric_slices() {
+        let counts: Vec<u32> = vec![3, 1, 4, 1, 5];
+        assert_eq!(counts.mean(), 2.8);
+        assert_eq!(Statistics::min(&counts[..]), 1.0);
+        assert_eq!(counts.population_variance(), [3.0, 1.0, 4.0, 1.0, 5.0].population_variance());
+        assert_eq!(counts.median_copy(), 3.0);
+        assert_eq!(counts.ranks_copy(RankTieBreaker::Average), [3.0, 1.5, 4.0, 1.5, 5.0]);
+
+        let mut data: Vec<f32> = vec![0.5, -1.5, 2.0];
+        assert_eq!(data.abs_max(), 2.0);
+        assert_eq!(data.covariance(&[0.5, -1.5, 2.0]), data.variance());
+        assert_eq!(data.order_statistic(1), -1.5);
+        assert_eq!(data.median(), 0.5);
+        assert!([0.0f32, f32::NAN].max().is_nan());
+
+        let signed: [i16; 4] = [-3, 0, 3, 0];
+        assert_eq!(signed.mean(), 0.0);
+        assert_eq!(signed.skewness(), 0.0);
+        assert_eq!(signed.quantile_custom_copy(0.75, QuantileDefinition::R7), 0.75);
+    }
+
+    #[test]
+    fn test_vec_is_not_consumed() {
+        let data = vec![1.0, 2.0, 4.0];
+        assert_eq!(data.mean(), 7.0 / 3.0);
+        assert_eq!(data.covariance(&data), data.variance());
+        assert_eq!(data.len(), 3);
+    }
 }
@@ -0,0 +1,559 @@
+use std::f64;
+use super::{QuantileDefinition, RankTieBreaker};
+use super::slice_statistics::{estimate_quantile, quick_sort_all, sorted_ranks};
+
+/// A sorted copy of a data set that answers order statistic, quantile
+/// and rank queries without reordering or re-scanning the data
+///
+/// # Remarks
+///
+/// The data is sorted once on construction in `O(n log(n))` time. Order
+/// statistics and quantiles are then computed in `O(1)` time and the rank
+/// of a single value in `O(log(n))` time. The results agree with the
+/// corresponding methods of `Statistics`, so entries that are
+/// `f64::NAN` are sorted last and make every order statistic, quantile and
+/// rank `f64::NAN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::SortedData;
+///
+/// let data = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
+/// let sorted = SortedData::new(&data);
+/// assert_eq!(sorted.median(), 5.5);
+/// assert_eq!(sorted.percentile(0), 1.0);
+/// assert_eq!(sorted.percentile(100), 10.0);
+/// assert_eq!(data, [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortedData {
+    data: Vec<f64>,
+    index: Vec<usize>,
+}
+
+impl SortedData {
+    /// Constructs a new sorted data set from a copy of `data`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::new(&[3.0, -1.0, 2.0]);
+    /// assert_eq!(sorted.as_slice(), &[-1.0, 2.0, 3.0]);
+    /// ```
+    pub fn new(data: &[f64]) -> SortedData {
+        SortedData::from(data.to_vec())
+    }
+
+    /// Returns the data in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::new(&[3.0, -1.0, 2.0]);
+    /// assert_eq!(sorted.as_slice(), &[-1.0, 2.0, 3.0]);
+    /// ```
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the number of elements in the data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// assert_eq!(SortedData::new(&[3.0, -1.0, 2.0]).len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// assert!(SortedData::new(&[]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the minimum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// assert!(SortedData::new(&[]).min().is_nan());
+    /// assert_eq!(SortedData::new(&[3.0, -1.0, 2.0]).min(), -1.0);
+    /// ```
+    pub fn min(&self) -> f64 {
+        if self.has_nan() {
+            return f64::NAN;
+        }
+        self.data.first().cloned().unwrap_or(f64::NAN)
+    }
+
+    /// Returns the maximum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// assert!(SortedData::new(&[]).max().is_nan());
+    /// assert_eq!(SortedData::new(&[3.0, -1.0, 2.0]).max(), 3.0);
+    /// ```
+    pub fn max(&self) -> f64 {
+        self.data.last().cloned().unwrap_or(f64::NAN)
+    }
+
+    /// Returns the order statistic `(order 1..N)` from the data
+    ///
+    /// # Remarks
+    ///
+    /// Order must be one-based (between `1` and `N` inclusive).
+    /// Returns `f64::NAN` if order is outside the viable range or data is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::new(&[0.0, 3.0, -2.0]);
+    /// assert!(sorted.order_statistic(0).is_nan());
+    /// assert!(sorted.order_statistic(4).is_nan());
+    /// assert_eq!(sorted.order_statistic(2), 0.0);
+    /// ```
+    pub fn order_statistic(&self, order: usize) -> f64 {
+        if order < 1 || order > self.len() || self.has_nan() {
+            f64::NAN
+        } else {
+            self.data[order - 1]
+        }
+    }
+
+    /// Returns the median value from the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// assert!(SortedData::new(&[]).median().is_nan());
+    /// assert_eq!(SortedData::new(&[0.0, 3.0, -2.0]).median(), 0.0);
+    /// assert_eq!(SortedData::new(&[0.0, 3.0, -2.0, 1.0]).median(), 0.5);
+    /// ```
+    pub fn median(&self) -> f64 {
+        let n = self.len();
+        if n == 0 || self.has_nan() {
+            f64::NAN
+        } else if n % 2 != 0 {
+            self.data[n / 2]
+        } else {
+            (self.data[n / 2 - 1] + self.data[n / 2]) / 2.0
+        }
+    }
+
+    /// Estimates the tau-th quantile from the data. The tau-th quantile
+    /// is the data value where the cumulative distribution function crosses tau.
+    ///
+    /// # Remarks
+    ///
+    /// Tau must be between `0` and `1` inclusive.
+    /// Returns `f64::NAN` if data is empty or tau is outside the inclusive range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::new(&[0.0, 3.0, -2.0]);
+    /// assert!(sorted.quantile(-1.0).is_nan());
+    /// assert!(sorted.quantile(2.0).is_nan());
+    /// assert_eq!(sorted.quantile(0.5), 0.0);
+    /// ```
+    pub fn quantile(&self, tau: f64) -> f64 {
+        self.quantile_custom(tau, QuantileDefinition::R8)
+    }
+
+    /// Estimates the tau-th quantile from the data using the given
+    /// quantile definition
+    ///
+    /// # Remarks
+    ///
+    /// Tau must be between `0` and `1` inclusive.
+    /// Returns `f64::NAN` if data is empty or tau is outside the inclusive range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{SortedData, QuantileDefinition};
+    ///
+    /// let sorted = SortedData::new(&[2.0, 1.0, 3.0, 4.0]);
+    /// assert_eq!(sorted.quantile_custom(0.25, QuantileDefinition::R1), 1.0);
+    /// assert_eq!(sorted.quantile_custom(0.25, QuantileDefinition::R7), 1.75);
+    /// ```
+    pub fn quantile_custom(&self, tau: f64, definition: QuantileDefinition) -> f64 {
+        if self.has_nan() {
+            return f64::NAN;
+        }
+        estimate_quantile(self.len(), tau, definition, |k| self.data[k])
+    }
+
+    /// Estimates the p-Percentile value from the data.
+    ///
+    /// # Remarks
+    ///
+    /// Use quantile for non-integer percentiles. `p` must be between `0` and `100` inclusive.
+    /// Returns `f64::NAN` if data is empty or `p` is outside the inclusive range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::new(&[1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0]);
+    /// assert_eq!(sorted.percentile(0), 1.0);
+    /// assert_eq!(sorted.percentile(50), 5.5);
+    /// assert_eq!(sorted.percentile(100), 10.0);
+    /// assert!(sorted.percentile(105).is_nan());
+    /// ```
+    pub fn percentile(&self, p: usize) -> f64 {
+        self.quantile(p as f64 / 100.0)
+    }
+
+    /// Estimates the first quartile value from the data.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::SortedData;
+    ///
+    /// # fn main() {
+    /// let sorted = SortedData::new(&[2.0, 1.0, 3.0, 4.0]);
+    /// assert_almost_eq!(sorted.lower_quartile(), 1.416666666666666, 1e-15);
+    /// # }
+    /// ```
+    pub fn lower_quartile(&self) -> f64 {
+        self.quantile(0.25)
+    }
+
+    /// Estimates the third quartile value from the data.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::SortedData;
+    ///
+    /// # fn main() {
+    /// let sorted = SortedData::new(&[2.0, 1.0, 3.0, 4.0]);
+    /// assert_almost_eq!(sorted.upper_quartile(), 3.5833333333333333, 1e-15);
+    /// # }
+    /// ```
+    pub fn upper_quartile(&self) -> f64 {
+        self.quantile(0.75)
+    }
+
+    /// Estimates the inter-quartile range from the data.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::SortedData;
+    ///
+    /// # fn main() {
+    /// let sorted = SortedData::new(&[2.0, 1.0, 3.0, 4.0]);
+    /// assert_almost_eq!(sorted.interquartile_range(), 2.166666666666667, 1e-15);
+    /// # }
+    /// ```
+    pub fn interquartile_range(&self) -> f64 {
+        self.upper_quartile() - self.lower_quartile()
+    }
+
+    /// Evaluates the rank of each entry of the data in its original order
+    ///
+    /// # Remarks
+    ///
+    /// Every rank is `f64::NAN` if an entry is `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{SortedData, RankTieBreaker};
+    ///
+    /// let sorted = SortedData::new(&[1.0, 3.0, 2.0, 2.0]);
+    /// assert_eq!(sorted.ranks(RankTieBreaker::Average), [1.0, 4.0, 2.5, 2.5]);
+    /// assert_eq!(sorted.ranks(RankTieBreaker::First), [1.0, 4.0, 2.0, 3.0]);
+    /// ```
+    pub fn ranks(&self, tie_breaker: RankTieBreaker) -> Vec<f64> {
+        sorted_ranks(&self.data, &self.index, tie_breaker)
+    }
+
+    /// Evaluates the rank of the value `x` within the data
+    ///
+    /// # Remarks
+    ///
+    /// Ties are broken as in `ranks`, with `RankTieBreaker::First` giving
+    /// the rank of the first occurrence of `x`.
+    /// Returns `f64::NAN` if `x` does not occur in the data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{SortedData, RankTieBreaker};
+    ///
+    /// let sorted = SortedData::new(&[1.0, 3.0, 2.0, 2.0]);
+    /// assert_eq!(sorted.rank(3.0, RankTieBreaker::Average), 4.0);
+    /// assert_eq!(sorted.rank(2.0, RankTieBreaker::Average), 2.5);
+    /// assert_eq!(sorted.rank(2.0, RankTieBreaker::Max), 3.0);
+    /// assert!(sorted.rank(2.5, RankTieBreaker::Average).is_nan());
+    /// ```
+    pub fn rank(&self, x: f64, tie_breaker: RankTieBreaker) -> f64 {
+        if self.has_nan() {
+            return f64::NAN;
+        }
+        let lower = self.count_less(x);
+        let upper = self.count_less_or_equal(x);
+        if lower == upper {
+            return f64::NAN;
+        }
+        match tie_breaker {
+            RankTieBreaker::Average => (lower + 1 + upper) as f64 / 2.0,
+            RankTieBreaker::Min | RankTieBreaker::First => (lower + 1) as f64,
+            RankTieBreaker::Max => upper as f64,
+        }
+    }
+
+    // returns true if an entry is NaN, which is sorted last
+    fn has_nan(&self) -> bool {
+        !self.data.is_empty() && self.data[self.len() - 1].is_nan()
+    }
+
+    // returns the number of elements strictly less than x by binary search
+    fn count_less(&self, x: f64) -> usize {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.data[mid] < x {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    // returns the number of elements less than or equal to x by binary search
+    fn count_less_or_equal(&self, x: f64) -> usize {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.data[mid] <= x {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+}
+
+impl From<Vec<f64>> for SortedData {
+    /// Constructs a new sorted data set by sorting `data` in place,
+    /// without copying it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::SortedData;
+    ///
+    /// let sorted = SortedData::from(vec![3.0, -1.0, 2.0]);
+    /// assert_eq!(sorted.as_slice(), &[-1.0, 2.0, 3.0]);
+    /// ```
+    fn from(mut data: Vec<f64>) -> SortedData {
+        let n = data.len();
+        let mut index: Vec<usize> = (0..n).collect();
+        // moves NaN entries, which have no order, behind the others
+        let mut end = n;
+        let mut i = 0;
+        while i < end {
+            if data[i].is_nan() {
+                end -= 1;
+                data.swap(i, end);
+                index.swap(i, end);
+            } else {
+                i += 1;
+            }
+        }
+        if end > 1 {
+            quick_sort_all(&mut data, &mut index, 0, end - 1);
+        }
+        SortedData {
+            data: data,
+            index: index,
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    #[test]
+    fn test_empty() {
+        let sorted = SortedData::new(&[]);
+        assert!(sorted.is_empty());
+        assert!(sorted.min().is_nan());
+        assert!(sorted.max().is_nan());
+        assert!(sorted.order_statistic(1).is_nan());
+        assert!(sorted.median().is_nan());
+        assert!(sorted.quantile(0.5).is_nan());
+        assert_eq!(sorted.ranks(RankTieBreaker::First).len(), 0);
+        assert!(sorted.rank(1.0, RankTieBreaker::Average).is_nan());
+    }
+
+    #[test]
+    fn test_order_statistic_short() {
+        let sorted = SortedData::new(&[-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 1.0, 6.0]);
+        assert!(sorted.order_statistic(0).is_nan());
+        assert_eq!(sorted.order_statistic(1), -3.0);
+        assert_eq!(sorted.order_statistic(2), -1.0);
+        assert_eq!(sorted.order_statistic(3), -0.5);
+        assert_eq!(sorted.order_statistic(7), 5.0);
+        assert_eq!(sorted.order_statistic(8), 6.0);
+        assert_eq!(sorted.order_statistic(9), 10.0);
+        assert!(sorted.order_statistic(10).is_nan());
+    }
+
+    #[test]
+    fn test_quantile_short() {
+        let sorted = SortedData::new(&[-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0]);
+        assert_eq!(sorted.quantile(0.0), -3.0);
+        assert_eq!(sorted.quantile(1.0), 10.0);
+        assert_almost_eq!(sorted.quantile(0.5), 3.0 / 5.0, 1e-15);
+        assert_almost_eq!(sorted.quantile(0.2), -4.0 / 5.0, 1e-15);
+        assert_eq!(sorted.quantile(0.7), 137.0 / 30.0);
+        assert_eq!(sorted.quantile(0.01), -3.0);
+        assert_eq!(sorted.quantile(0.99), 10.0);
+        assert_almost_eq!(sorted.quantile(0.52), 287.0 / 375.0, 1e-15);
+        assert_almost_eq!(sorted.quantile(0.325), -37.0 / 240.0, 1e-15);
+    }
+
+    #[test]
+    fn test_matches_order_statistics() {
+        let data: Vec<f64> = (0..100).map(|i| ((i * 37) % 101) as f64 / 7.0 - 3.0).collect();
+        let sorted = SortedData::new(&data);
+        for order in 0..102 {
+            let expected = data.order_statistic_copy(order);
+            let actual = sorted.order_statistic(order);
+            assert!(expected == actual || expected.is_nan() && actual.is_nan());
+        }
+        for p in 0..101 {
+            assert_eq!(sorted.percentile(p), data.percentile_copy(p));
+        }
+        assert_eq!(sorted.median(), data.median_copy());
+        assert_eq!(sorted.interquartile_range(), data.interquartile_range_copy());
+        assert_eq!(sorted.min(), data.min());
+        assert_eq!(sorted.max(), data.max());
+    }
+
+    #[test]
+    fn test_ranks() {
+        let ties = [1.0, 9.0, 12.0, 7.0, 2.0, 9.0, 10.0, 2.0, 9.0, 1.0, 0.5, 12.0];
+        let sorted = SortedData::new(&ties);
+        for &tie_breaker in &[RankTieBreaker::Average, RankTieBreaker::Min, RankTieBreaker::Max, RankTieBreaker::First] {
+            assert_eq!(sorted.ranks(tie_breaker), ties.ranks_copy(tie_breaker));
+        }
+        assert_eq!(sorted.ranks(RankTieBreaker::First), [2.0, 7.0, 11.0, 6.0, 4.0, 8.0, 10.0, 5.0, 9.0, 3.0, 1.0, 12.0]);
+    }
+
+    #[test]
+    fn test_rank() {
+        let sorted = SortedData::new(&[1.0, 9.0, 12.0, 7.0, 2.0, 9.0, 10.0, 2.0, 9.0]);
+        assert_eq!(sorted.rank(1.0, RankTieBreaker::Average), 1.0);
+        assert_eq!(sorted.rank(12.0, RankTieBreaker::Average), 9.0);
+        assert_eq!(sorted.rank(9.0, RankTieBreaker::Average), 6.0);
+        assert_eq!(sorted.rank(9.0, RankTieBreaker::Min), 5.0);
+        assert_eq!(sorted.rank(9.0, RankTieBreaker::Max), 7.0);
+        assert_eq!(sorted.rank(9.0, RankTieBreaker::First), 5.0);
+        assert!(sorted.rank(0.0, RankTieBreaker::Average).is_nan());
+        assert!(sorted.rank(13.0, RankTieBreaker::Average).is_nan());
+        assert!(sorted.rank(f64::NAN, RankTieBreaker::Average).is_nan());
+    }
+
+    #[test]
+    fn test_quantile_custom_matches_slice() {
+        let data: Vec<f64> = (0..37).map(|i| ((i * 17) % 41) as f64 / 3.0 - 5.0).collect();
+        let sorted = SortedData::new(&data);
+        let definitions = [QuantileDefinition::R1, QuantileDefinition::R2, QuantileDefinition::R3,
+                           QuantileDefinition::R4, QuantileDefinition::R5, QuantileDefinition::R6,
+                           QuantileDefinition::R7, QuantileDefinition::R8, QuantileDefinition::R9];
+        for &definition in &definitions {
+            for i in 0..101 {
+                let tau = i as f64 / 100.0;
+                assert_eq!(sorted.quantile_custom(tau, definition), data.quantile_custom_copy(tau, definition));
+            }
+        }
+    }
+
+
+    #[test]
+    fn test_nan() {
+        let sorted = SortedData::new(&[2.0, f64::NAN, -1.0, 3.0, f64::NAN, 0.0]);
+        assert_eq!(&sorted.as_slice()[..4], &[-1.0, 0.0, 2.0, 3.0]);
+        assert!(sorted.as_slice()[4..].iter().all(|x| x.is_nan()));
+        assert!(sorted.min().is_nan());
+        assert!(sorted.max().is_nan());
+        assert!(sorted.order_statistic(2).is_nan());
+        assert!(sorted.median().is_nan());
+        assert!(sorted.quantile(0.25).is_nan());
+        assert!(sorted.rank(2.0, RankTieBreaker::Average).is_nan());
+        assert!(sorted.ranks(RankTieBreaker::First).iter().all(|r| r.is_nan()));
+    }
+}
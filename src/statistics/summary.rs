@@ -0,0 +1,307 @@
+use std::f64;
+use std::fmt;
+use super::{RunningStatistics, SortedData};
+
+/// Descriptive statistics of a data set, as computed by `describe`
+///
+/// # Remarks
+///
+/// Every statistic except the counts is computed from the entries that are
+/// not `f64::NAN` and is `f64::NAN` if there are not enough of them. The
+/// quartiles agree with `Statistics::lower_quartile`, `median` and
+/// `upper_quartile`.
+///
+/// The `Display` implementation renders the summary as a table with one
+/// statistic per line and honours the precision of the format string
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::describe;
+///
+/// let summary = describe(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+/// assert_eq!(format!("{:.2}", summary),
+///            "count    5\n\
+///             nan      0\n\
+///             mean     3.00\n\
+///             std_dev  1.58\n\
+///             variance 2.50\n\
+///             skewness 0.00\n\
+///             kurtosis -1.20\n\
+///             min      1.00\n\
+///             q1       1.67\n\
+///             median   3.00\n\
+///             q3       4.33\n\
+///             max      5.00\n\
+///             iqr      2.67");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    count: u64,
+    nan_count: u64,
+    mean: f64,
+    variance: f64,
+    skewness: f64,
+    kurtosis: f64,
+    min: f64,
+    lower_quartile: f64,
+    median: f64,
+    upper_quartile: f64,
+    max: f64,
+}
+
+/// Computes the descriptive statistics of `data` in a single pass
+/// followed by a sort of a copy of the entries that are not `f64::NAN`
+///
+/// # Remarks
+///
+/// The moments are accumulated with `RunningStatistics` and the order
+/// statistics are read off `SortedData`, so `data` is neither reordered
+/// nor scanned again for each statistic
+///
+/// # Examples
+///
+/// ```
+/// use std::f64;
+/// use statrs::statistics::describe;
+///
+/// let summary = describe(&[2.0, f64::NAN, 1.0, 4.0, 3.0]);
+/// assert_eq!(summary.count(), 4);
+/// assert_eq!(summary.nan_count(), 1);
+/// assert_eq!(summary.mean(), 2.5);
+/// assert_eq!(summary.min(), 1.0);
+/// assert_eq!(summary.median(), 2.5);
+/// assert_eq!(summary.max(), 4.0);
+/// ```
+pub fn describe(data: &[f64]) -> Summary {
+    let mut stats = RunningStatistics::new();
+    let mut values = Vec::with_capacity(data.len());
+    let mut nan_count = 0;
+    for &x in data {
+        if x.is_nan() {
+            nan_count += 1;
+        } else {
+            stats.push(x);
+            values.push(x);
+        }
+    }
+    let sorted = SortedData::from(values);
+    Summary {
+        count: stats.count(),
+        nan_count: nan_count,
+        mean: stats.mean(),
+        variance: stats.variance(),
+        skewness: stats.skewness(),
+        kurtosis: stats.kurtosis(),
+        min: sorted.min(),
+        lower_quartile: sorted.lower_quartile(),
+        median: sorted.median(),
+        upper_quartile: sorted.upper_quartile(),
+        max: sorted.max(),
+    }
+}
+
+impl Summary {
+    /// Returns the number of entries that are not `f64::NAN`
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of entries that are `f64::NAN`
+    pub fn nan_count(&self) -> u64 {
+        self.nan_count
+    }
+
+    /// Returns the sample mean
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the unbiased estimate of the population variance, using
+    /// `N - 1` as a normalizer
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are fewer than two entries
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Returns the sample standard deviation
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are fewer than two entries
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// Returns the adjusted Fisher-Pearson estimate of the population skewness
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are fewer than three entries
+    pub fn skewness(&self) -> f64 {
+        self.skewness
+    }
+
+    /// Returns the unbiased estimate of the population excess kurtosis
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are fewer than four entries
+    pub fn kurtosis(&self) -> f64 {
+        self.kurtosis
+    }
+
+    /// Returns the minimum entry
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the estimate of the first quartile
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn lower_quartile(&self) -> f64 {
+        self.lower_quartile
+    }
+
+    /// Returns the sample median
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// Returns the estimate of the third quartile
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn upper_quartile(&self) -> f64 {
+        self.upper_quartile
+    }
+
+    /// Returns the maximum entry
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the inter-quartile range
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if there are no entries
+    pub fn interquartile_range(&self) -> f64 {
+        self.upper_quartile - self.lower_quartile
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "count    {}", self.count)?;
+        writeln!(f, "nan      {}", self.nan_count)?;
+        let rows = [("mean", self.mean()),
+                    ("std_dev", self.std_dev()),
+                    ("variance", self.variance()),
+                    ("skewness", self.skewness()),
+                    ("kurtosis", self.kurtosis()),
+                    ("min", self.min()),
+                    ("q1", self.lower_quartile()),
+                    ("median", self.median()),
+                    ("q3", self.upper_quartile()),
+                    ("max", self.max()),
+                    ("iqr", self.interquartile_range())];
+        for (i, &(label, value)) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            match f.precision() {
+                Some(precision) => write!(f, "{:<8} {:.*}", label, precision, value)?,
+                None => write!(f, "{:<8} {}", label, value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    const DATA: [f64; 10] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+
+    #[test]
+    fn test_describe_matches_statistics() {
+        let summary = describe(&DATA);
+        assert_eq!(summary.count(), 10);
+        assert_eq!(summary.nan_count(), 0);
+        assert_almost_eq!(summary.mean(), (&DATA).mean(), 1e-15);
+        assert_almost_eq!(summary.variance(), (&DATA).variance(), 1e-14);
+        assert_almost_eq!(summary.std_dev(), (&DATA).std_dev(), 1e-14);
+        assert_almost_eq!(summary.skewness(), (&DATA).skewness(), 1e-14);
+        assert_almost_eq!(summary.kurtosis(), (&DATA).kurtosis(), 1e-14);
+        assert_eq!(summary.min(), -3.0);
+        assert_eq!(summary.max(), 10.0);
+        assert_eq!(summary.lower_quartile(), DATA.lower_quartile_copy());
+        assert_eq!(summary.median(), DATA.median_copy());
+        assert_eq!(summary.upper_quartile(), DATA.upper_quartile_copy());
+        assert_eq!(summary.interquartile_range(), DATA.interquartile_range_copy());
+    }
+
+    #[test]
+    fn test_describe_skips_nan() {
+        let mut data = DATA.to_vec();
+        data.insert(3, f64::NAN);
+        data.push(f64::NAN);
+        let summary = describe(&data);
+        assert_eq!(summary.count(), 10);
+        assert_eq!(summary.nan_count(), 2);
+        assert_eq!(summary, Summary { nan_count: 2, ..describe(&DATA) });
+    }
+
+    #[test]
+    fn test_describe_short() {
+        let summary = describe(&[]);
+        assert_eq!(summary.count(), 0);
+        assert!(summary.mean().is_nan());
+        assert!(summary.min().is_nan());
+        assert!(summary.median().is_nan());
+
+        let summary = describe(&[f64::NAN, 2.0, f64::NAN]);
+        assert_eq!(summary.count(), 1);
+        assert_eq!(summary.nan_count(), 2);
+        assert_eq!(summary.mean(), 2.0);
+        assert_eq!(summary.median(), 2.0);
+        assert!(summary.variance().is_nan());
+        assert!(summary.skewness().is_nan());
+        assert!(summary.kurtosis().is_nan());
+    }
+
+    #[test]
+    fn test_display() {
+        let summary = describe(&[2.0, f64::NAN]);
+        assert_eq!(format!("{}", summary),
+                   "count    1\nnan      1\nmean     2\nstd_dev  NaN\nvariance NaN\n\
+                    skewness NaN\nkurtosis NaN\nmin      2\nq1       2\nmedian   2\n\
+                    q3       2\nmax      2\niqr      0");
+    }
+}
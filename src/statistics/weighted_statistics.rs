@@ -0,0 +1,213 @@
+use std::f64;
+use error::StatsError;
+use super::*;
+
+impl WeightedStatistics for [f64] {
+    fn weighted_mean(&self, weights: &[f64]) -> f64 {
+        check_weights(self, weights);
+        if weights.iter().any(|&w| w < 0.0 || w.is_nan()) {
+            return f64::NAN;
+        }
+        let mut sum_weights = 0.0;
+        let mut mean = 0.0;
+        for (&x, &w) in self.iter().zip(weights.iter()) {
+            sum_weights += w;
+            if w > 0.0 {
+                mean += w / sum_weights * (x - mean);
+            } else if x.is_nan() {
+                mean = f64::NAN;
+            }
+        }
+        if sum_weights > 0.0 { mean } else { f64::NAN }
+    }
+
+    fn weighted_variance(&self, weights: &[f64], weight_type: WeightType) -> f64 {
+        self.weighted_covariance(self, weights, weight_type)
+    }
+
+    fn weighted_population_variance(&self, weights: &[f64]) -> f64 {
+        let (sum_weights, _, comoment) = weighted_comoment(self, self, weights);
+        comoment / sum_weights
+    }
+
+    fn weighted_std_dev(&self, weights: &[f64], weight_type: WeightType) -> f64 {
+        self.weighted_variance(weights, weight_type).sqrt()
+    }
+
+    fn weighted_covariance(&self, other: &[f64], weights: &[f64], weight_type: WeightType) -> f64 {
+        let (sum_weights, sum_squared_weights, comoment) = weighted_comoment(self, other, weights);
+        let normalizer = match weight_type {
+            WeightType::Frequency => sum_weights - 1.0,
+            WeightType::Reliability => sum_weights - sum_squared_weights / sum_weights,
+        };
+        if normalizer > 0.0 {
+            comoment / normalizer
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn weighted_quantile(&self, tau: f64, weights: &[f64]) -> f64 {
+        check_weights(self, weights);
+        if tau < 0.0 || tau > 1.0 || tau.is_nan() || self.iter().any(|x| x.is_nan()) ||
+           weights.iter().any(|&w| w < 0.0 || w.is_nan()) {
+            return f64::NAN;
+        }
+
+        let mut pairs: Vec<(f64, f64)> = self.iter()
+            .cloned()
+            .zip(weights.iter().cloned())
+            .filter(|&(_, w)| w > 0.0)
+            .collect();
+        if pairs.is_empty() {
+            return f64::NAN;
+        }
+        // NaN was ruled out above so the comparison never fails
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let total: f64 = pairs.iter().map(|p| p.1).sum();
+        let mut cumulative = 0.0;
+        let mut prev: Option<(f64, f64)> = None;
+        for &(x, w) in &pairs {
+            let position = (cumulative + w / 2.0) / total;
+            if tau <= position {
+                return match prev {
+                    Some((prev_position, prev_x)) if tau < position => {
+                        prev_x + (tau - prev_position) / (position - prev_position) * (x - prev_x)
+                    }
+                    _ => x,
+                };
+            }
+            prev = Some((position, x));
+            cumulative += w;
+        }
+        pairs[pairs.len() - 1].0
+    }
+
+    fn weighted_median(&self, weights: &[f64]) -> f64 {
+        self.weighted_quantile(0.5, weights)
+    }
+}
+
+fn check_weights(data: &[f64], weights: &[f64]) {
+    assert!(data.len() == weights.len(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+}
+
+// returns the sum of the weights, the sum of the squared weights and the
+// weighted sum of the products of the deviations of the two data sets from
+// their weighted means, correcting for the rounding error in the means
+fn weighted_comoment(x: &[f64], y: &[f64], weights: &[f64]) -> (f64, f64, f64) {
+    assert!(x.len() == y.len(),
+            "{}",
+            StatsError::ContainersMustBeSameLength);
+    let mean_x = x.weighted_mean(weights);
+    let mean_y = y.weighted_mean(weights);
+    let mut sum_weights = 0.0;
+    let mut sum_squared_weights = 0.0;
+    let mut sum_x = 0.0;
+    let mut sum_y = 0.0;
+    let mut comoment = 0.0;
+    for ((&x, &y), &w) in x.iter().zip(y.iter()).zip(weights.iter()) {
+        if w > 0.0 {
+            let dx = x - mean_x;
+            let dy = y - mean_y;
+            sum_x += w * dx;
+            sum_y += w * dy;
+            comoment += w * dx * dy;
+        }
+        sum_weights += w;
+        sum_squared_weights += w * w;
+    }
+    (sum_weights, sum_squared_weights, comoment - sum_x * sum_y / sum_weights)
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+
+    const X: [f64; 10] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+    const Y: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+    const W: [f64; 10] = [1.0, 2.0, 0.5, 3.0, 1.0, 1.5, 2.0, 0.0, 1.0, 4.0];
+
+    #[test]
+    fn test_weighted_mean() {
+        assert_almost_eq!(X.weighted_mean(&W), 2.640625, 1e-15);
+        assert_almost_eq!(X.weighted_mean(&[1.0; 10]), (&X).mean(), 1e-15);
+        assert_eq!([1.0, f64::NAN].weighted_mean(&[1.0, 0.0]).is_nan(), true);
+        assert!([1.0, 2.0].weighted_mean(&[1.0, -1.0]).is_nan());
+        assert!([1.0, 2.0].weighted_mean(&[1.0, f64::NAN]).is_nan());
+        assert!([0.0; 0].weighted_mean(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_weighted_variance() {
+        assert_almost_eq!(X.weighted_population_variance(&W), 15.238037109375, 1e-13);
+        assert_almost_eq!(X.weighted_variance(&W, WeightType::Frequency), 16.25390625, 1e-13);
+        assert_almost_eq!(X.weighted_variance(&W, WeightType::Reliability), 17.935344827586206897, 1e-13);
+        assert_almost_eq!(X.weighted_std_dev(&W, WeightType::Frequency), 16.25390625f64.sqrt(), 1e-14);
+        assert_almost_eq!(X.weighted_variance(&[1.0; 10], WeightType::Frequency), (&X).variance(), 1e-13);
+        assert_almost_eq!(X.weighted_variance(&[3.0; 10], WeightType::Reliability), (&X).variance(), 1e-13);
+        assert_almost_eq!(X.weighted_population_variance(&[3.0; 10]), (&X).population_variance(), 1e-13);
+        assert!([1.0, 2.0].weighted_variance(&[0.5, 0.5], WeightType::Frequency).is_nan());
+        assert!([1.0, 2.0].weighted_variance(&[0.0, 1.0], WeightType::Reliability).is_nan());
+    }
+
+    #[test]
+    fn test_weighted_variance_frequency_matches_repeated_data() {
+        let weights = [2.0, 1.0, 3.0, 1.0, 1.0, 2.0, 1.0, 1.0, 4.0, 1.0];
+        let repeated: Vec<f64> = X.iter()
+            .zip(weights.iter())
+            .flat_map(|(&x, &w)| vec![x; w as usize])
+            .collect();
+        assert_almost_eq!(X.weighted_variance(&weights, WeightType::Frequency), repeated.variance(), 1e-13);
+        assert_almost_eq!(X.weighted_mean(&weights), repeated.mean(), 1e-15);
+    }
+
+    #[test]
+    fn test_weighted_covariance() {
+        assert_almost_eq!(X.weighted_covariance(&Y, &W, WeightType::Frequency), 1.6895312499999998664, 1e-14);
+        assert_almost_eq!(X.weighted_covariance(&Y, &W, WeightType::Reliability), 1.8643103448275860595, 1e-14);
+        assert_almost_eq!(Y.weighted_covariance(&X, &W, WeightType::Reliability), 1.8643103448275860595, 1e-14);
+        assert_almost_eq!(X.weighted_covariance(&Y, &[1.0; 10], WeightType::Frequency), (&X).covariance(&Y), 1e-14);
+    }
+
+    #[test]
+    fn test_weighted_quantile() {
+        assert_eq!(X.weighted_quantile(0.0, &W), -3.0);
+        assert_almost_eq!(X.weighted_quantile(0.1, &W), -2.9, 1e-15);
+        assert_almost_eq!(X.weighted_quantile(0.25, &W), -0.8, 1e-15);
+        assert_eq!(X.weighted_quantile(0.5, &W), 4.0);
+        assert_eq!(X.weighted_median(&W), 4.0);
+        assert_almost_eq!(X.weighted_quantile(0.75, &W), 17.0 / 3.0, 1e-14);
+        assert_almost_eq!(X.weighted_quantile(0.9, &W), 8.24, 1e-14);
+        assert_eq!(X.weighted_quantile(1.0, &W), 10.0);
+        assert!(X.weighted_quantile(1.5, &W).is_nan());
+        assert!(X.weighted_quantile(f64::NAN, &W).is_nan());
+        assert!(X.weighted_quantile(0.5, &[0.0; 10]).is_nan());
+        assert!([1.0, f64::NAN].weighted_quantile(0.5, &[1.0, 1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_weighted_quantile_equal_weights() {
+        for i in 0..101 {
+            let tau = i as f64 / 100.0;
+            assert_almost_eq!(X.weighted_quantile(tau, &[2.0; 10]), X.quantile_custom_copy(tau, QuantileDefinition::R5), 1e-14);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weighted_mean_different_lengths() {
+        X.weighted_mean(&W[..9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weighted_covariance_different_lengths() {
+        X.weighted_covariance(&Y[..9], &W, WeightType::Frequency);
+    }
+}
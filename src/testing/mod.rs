@@ -29,3 +29,21 @@ pub fn load_data(path: &str) -> Vec<f64> {
     }
     data
 }
+
+/// Loads a test data file of whitespace separated columns into a vector
+/// of rows of `f64`'s. Path is relative to /data.
+///
+/// # Panics
+///
+/// Panics if the file does not exist or could not be opened, or
+/// there was an error reading the file.
+pub fn load_table(path: &str) -> Vec<Vec<f64>> {
+    let path_prefix = "./data/".to_string();
+    let true_path = path_prefix + path.trim().trim_left_matches('/');
+
+    let f = File::open(true_path).unwrap();
+    let reader = BufReader::new(f);
+    reader.lines()
+        .map(|line| line.unwrap().split_whitespace().map(|x| x.parse::<f64>().unwrap()).collect())
+        .collect()
+}